@@ -0,0 +1,469 @@
+use std::cmp::Ordering;
+use std::convert::TryFrom;
+use std::fmt;
+use std::ops::{Add, BitAnd, BitOr, BitXor, Mul, Neg, Shl, Shr, Sub};
+use std::str::FromStr;
+
+/// Arbitrary-precision integer of the interpreter with `--int-width=unbounded`, stored as its sign and magnitude.
+/// The magnitude is a sequence of 32-bit limbs, from the least significant one, without leading zeros
+/// (so 0 has no limbs, and it is never negative). Operations follow `java.math.BigInteger`: division rounds
+/// towards zero, while bitwise operations and shifts work on the infinite two's complement representation.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct BigInt {
+    negative: bool,
+    magnitude: Vec<u32>,
+}
+
+/// Limit of the number of bits of the absolute value of a shifted integer, which is the limit of `BigInteger`
+/// (it throws `ArithmeticException` for larger results), so that shifts fail in the same way on every backend
+pub const MAX_BIG_BITS: u64 = 1 << 31;
+
+fn trim(mut magnitude: Vec<u32>) -> Vec<u32> {
+    while magnitude.last() == Some(&0) {
+        magnitude.pop();
+    }
+    magnitude
+}
+
+fn limb(magnitude: &[u32], index: usize) -> u64 {
+    magnitude.get(index).copied().unwrap_or(0) as u64
+}
+
+fn compare_magnitudes(lhs: &[u32], rhs: &[u32]) -> Ordering {
+    lhs.len().cmp(&rhs.len()).then_with(|| lhs.iter().rev().cmp(rhs.iter().rev()))
+}
+
+fn add_magnitudes(lhs: &[u32], rhs: &[u32]) -> Vec<u32> {
+    let mut result = Vec::with_capacity(lhs.len().max(rhs.len()) + 1);
+    let mut carry = 0;
+    for index in 0..lhs.len().max(rhs.len()) {
+        let sum = limb(lhs, index) + limb(rhs, index) + carry;
+        result.push(sum as u32);
+        carry = sum >> 32;
+    }
+    result.push(carry as u32);
+    trim(result)
+}
+
+/// difference of magnitudes, the first one can't be smaller than the second one
+fn sub_magnitudes(lhs: &[u32], rhs: &[u32]) -> Vec<u32> {
+    let mut result = Vec::with_capacity(lhs.len());
+    let mut borrow = 0;
+    for (index, lhs_limb) in lhs.iter().enumerate() {
+        let difference = *lhs_limb as i64 - limb(rhs, index) as i64 - borrow;
+        result.push(difference as u32);
+        borrow = (difference < 0) as i64;
+    }
+    trim(result)
+}
+
+fn mul_magnitudes(lhs: &[u32], rhs: &[u32]) -> Vec<u32> {
+    let mut result = vec![0; lhs.len() + rhs.len()];
+    for (i, lhs_limb) in lhs.iter().enumerate() {
+        let mut carry = 0;
+        for (j, rhs_limb) in rhs.iter().enumerate() {
+            let product = *lhs_limb as u64 * *rhs_limb as u64 + result[i + j] as u64 + carry;
+            result[i + j] = product as u32;
+            carry = product >> 32;
+        }
+        result[i + rhs.len()] = carry as u32;
+    }
+    trim(result)
+}
+
+/// quotient and remainder of the division by a single limb
+fn div_rem_limb(magnitude: &[u32], divisor: u32) -> (Vec<u32>, u32) {
+    let mut quotient = vec![0; magnitude.len()];
+    let mut remainder = 0;
+    for (index, magnitude_limb) in magnitude.iter().enumerate().rev() {
+        let current = (remainder << 32) | *magnitude_limb as u64;
+        quotient[index] = (current / divisor as u64) as u32;
+        remainder = current % divisor as u64;
+    }
+    (trim(quotient), remainder as u32)
+}
+
+/// long division computing one bit of the quotient at a time, which is simple and fast enough
+/// for numbers of Instant programs - the divisor can't be 0
+fn div_rem_magnitudes(lhs: &[u32], rhs: &[u32]) -> (Vec<u32>, Vec<u32>) {
+    if rhs.len() == 1 {
+        let (quotient, remainder) = div_rem_limb(lhs, rhs[0]);
+        return (quotient, trim(vec![remainder]));
+    }
+    let mut quotient = vec![0; lhs.len()];
+    let mut remainder = vec![];
+    for bit in (0..lhs.len() * 32).rev() {
+        remainder = shift_left_magnitude(&remainder, 1);
+        if (lhs[bit / 32] >> (bit % 32)) & 1 == 1 {
+            remainder = add_magnitudes(&remainder, &[1]);
+        }
+        if compare_magnitudes(&remainder, rhs) != Ordering::Less {
+            remainder = sub_magnitudes(&remainder, rhs);
+            quotient[bit / 32] |= 1 << (bit % 32);
+        }
+    }
+    (trim(quotient), remainder)
+}
+
+fn shift_left_magnitude(magnitude: &[u32], bits: usize) -> Vec<u32> {
+    if magnitude.is_empty() {
+        return vec![];
+    }
+    let (limbs, bits) = (bits / 32, bits % 32);
+    let mut result = vec![0; limbs];
+    // every limb of the result consists of bits of two consecutive limbs of the magnitude
+    result.extend((0..=magnitude.len()).map(|index| {
+        let low = if index == 0 { 0 } else { limb(magnitude, index - 1) };
+        ((((limb(magnitude, index) << 32) | low) << bits) >> 32) as u32
+    }));
+    trim(result)
+}
+
+fn shift_right_magnitude(magnitude: &[u32], bits: usize) -> Vec<u32> {
+    let (limbs, bits) = (bits / 32, bits % 32);
+    if limbs >= magnitude.len() {
+        return vec![];
+    }
+    let shifted = &magnitude[limbs..];
+    let result = (0..shifted.len())
+        .map(|index| (((limb(shifted, index + 1) << 32) | limb(shifted, index)) >> bits) as u32)
+        .collect();
+    trim(result)
+}
+
+impl BigInt {
+    fn new(negative: bool, magnitude: Vec<u32>) -> BigInt {
+        let magnitude = trim(magnitude);
+        BigInt { negative: negative && !magnitude.is_empty(), magnitude }
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.magnitude.is_empty()
+    }
+
+    pub fn is_negative(&self) -> bool {
+        self.negative
+    }
+
+    /// limbs of the absolute value, from the least significant one
+    pub fn magnitude(&self) -> &[u32] {
+        &self.magnitude
+    }
+
+    /// number of bits of the absolute value, without leading zeros (0 for 0)
+    pub fn bit_length(&self) -> u64 {
+        match self.magnitude.last() {
+            Some(top) => self.magnitude.len() as u64 * 32 - top.leading_zeros() as u64,
+            None => 0,
+        }
+    }
+
+    /// lowest 64 bits of the absolute value
+    fn low_bits(&self) -> u64 {
+        (limb(&self.magnitude, 1) << 32) | limb(&self.magnitude, 0)
+    }
+
+    /// the value, if it fits in i64
+    pub fn to_i64(&self) -> Option<i64> {
+        match (self.magnitude.len(), self.negative, self.low_bits()) {
+            (0..=2, false, abs) => i64::try_from(abs).ok(),
+            (0..=2, true, abs) if abs <= 1 << 63 => Some((abs as i64).wrapping_neg()),
+            _ => None,
+        }
+    }
+
+    /// lowest 64 bits of the two's complement representation, as in `BigInteger.longValue()`
+    pub fn wrapping_to_i64(&self) -> i64 {
+        let low_bits = self.low_bits() as i64;
+        if self.negative { low_bits.wrapping_neg() } else { low_bits }
+    }
+
+    /// quotient rounded towards zero and the remainder (which has the sign of the dividend),
+    /// or None when dividing by zero
+    pub fn div_rem(&self, divisor: &BigInt) -> Option<(BigInt, BigInt)> {
+        if divisor.is_zero() {
+            return None;
+        }
+        let (quotient, remainder) = div_rem_magnitudes(&self.magnitude, &divisor.magnitude);
+        Some((BigInt::new(self.negative != divisor.negative, quotient), BigInt::new(self.negative, remainder)))
+    }
+
+    /// sum of the value and the number given by its sign and magnitude
+    fn plus(&self, negative: bool, magnitude: &[u32]) -> BigInt {
+        if self.negative == negative {
+            return BigInt::new(negative, add_magnitudes(&self.magnitude, magnitude));
+        }
+        match compare_magnitudes(&self.magnitude, magnitude) {
+            Ordering::Less => BigInt::new(negative, sub_magnitudes(magnitude, &self.magnitude)),
+            _ => BigInt::new(self.negative, sub_magnitudes(&self.magnitude, magnitude)),
+        }
+    }
+
+    fn shift_left(&self, bits: usize) -> BigInt {
+        BigInt::new(self.negative, shift_left_magnitude(&self.magnitude, bits))
+    }
+
+    /// arithmetic shift, which rounds towards negative infinity
+    fn shift_right(&self, bits: usize) -> BigInt {
+        if !self.negative {
+            return BigInt::new(false, shift_right_magnitude(&self.magnitude, bits));
+        }
+        // -m >> k == -(((m - 1) >> k) + 1)
+        let decremented = sub_magnitudes(&self.magnitude, &[1]);
+        BigInt::new(true, add_magnitudes(&shift_right_magnitude(&decremented, bits), &[1]))
+    }
+
+    /// two's complement representation in the given number of limbs, which have to fit the value and its sign bit
+    fn to_twos_complement(&self, len: usize) -> Vec<u32> {
+        let mut limbs = self.magnitude.clone();
+        limbs.resize(len, 0);
+        if self.negative {
+            // -m == !(m - 1)
+            let mut borrow = true;
+            for limb in limbs.iter_mut() {
+                let (decremented, overflow) = limb.overflowing_sub(borrow as u32);
+                *limb = !decremented;
+                borrow = overflow;
+            }
+        }
+        limbs
+    }
+
+    fn from_twos_complement(mut limbs: Vec<u32>) -> BigInt {
+        let negative = limbs.last().is_some_and(|limb| limb >> 31 == 1);
+        if negative {
+            // x == -(!x + 1)
+            let mut carry = true;
+            for limb in limbs.iter_mut() {
+                let (incremented, overflow) = (!*limb).overflowing_add(carry as u32);
+                *limb = incremented;
+                carry = overflow;
+            }
+        }
+        BigInt::new(negative, limbs)
+    }
+
+    fn bitwise(&self, other: &BigInt, op: fn(u32, u32) -> u32) -> BigInt {
+        let len = self.magnitude.len().max(other.magnitude.len()) + 1;
+        let limbs = self.to_twos_complement(len).into_iter()
+            .zip(other.to_twos_complement(len))
+            .map(|(lhs, rhs)| op(lhs, rhs))
+            .collect();
+        BigInt::from_twos_complement(limbs)
+    }
+}
+
+impl From<i64> for BigInt {
+    fn from(val: i64) -> BigInt {
+        let abs = val.unsigned_abs();
+        BigInt::new(val < 0, vec![abs as u32, (abs >> 32) as u32])
+    }
+}
+
+impl Neg for &BigInt {
+    type Output = BigInt;
+
+    fn neg(self) -> BigInt {
+        BigInt::new(!self.negative, self.magnitude.clone())
+    }
+}
+
+impl Add for &BigInt {
+    type Output = BigInt;
+
+    fn add(self, other: &BigInt) -> BigInt {
+        self.plus(other.negative, &other.magnitude)
+    }
+}
+
+impl Sub for &BigInt {
+    type Output = BigInt;
+
+    fn sub(self, other: &BigInt) -> BigInt {
+        self.plus(!other.negative, &other.magnitude)
+    }
+}
+
+impl Mul for &BigInt {
+    type Output = BigInt;
+
+    fn mul(self, other: &BigInt) -> BigInt {
+        BigInt::new(self.negative != other.negative, mul_magnitudes(&self.magnitude, &other.magnitude))
+    }
+}
+
+impl BitAnd for &BigInt {
+    type Output = BigInt;
+
+    fn bitand(self, other: &BigInt) -> BigInt {
+        self.bitwise(other, |lhs, rhs| lhs & rhs)
+    }
+}
+
+impl BitOr for &BigInt {
+    type Output = BigInt;
+
+    fn bitor(self, other: &BigInt) -> BigInt {
+        self.bitwise(other, |lhs, rhs| lhs | rhs)
+    }
+}
+
+impl BitXor for &BigInt {
+    type Output = BigInt;
+
+    fn bitxor(self, other: &BigInt) -> BigInt {
+        self.bitwise(other, |lhs, rhs| lhs ^ rhs)
+    }
+}
+
+/// shifts left by the number of bits, or right if it is negative
+impl Shl<i64> for &BigInt {
+    type Output = BigInt;
+
+    fn shl(self, bits: i64) -> BigInt {
+        if bits < 0 {
+            self.shift_right(bits.unsigned_abs() as usize)
+        } else {
+            self.shift_left(bits as usize)
+        }
+    }
+}
+
+/// arithmetic shift right by the number of bits, or left if it is negative
+impl Shr<i64> for &BigInt {
+    type Output = BigInt;
+
+    fn shr(self, bits: i64) -> BigInt {
+        if bits < 0 {
+            self.shift_left(bits.unsigned_abs() as usize)
+        } else {
+            self.shift_right(bits as usize)
+        }
+    }
+}
+
+impl Ord for BigInt {
+    fn cmp(&self, other: &BigInt) -> Ordering {
+        match (self.negative, other.negative) {
+            (false, true) => Ordering::Greater,
+            (true, false) => Ordering::Less,
+            (false, false) => compare_magnitudes(&self.magnitude, &other.magnitude),
+            (true, true) => compare_magnitudes(&other.magnitude, &self.magnitude),
+        }
+    }
+}
+
+impl PartialOrd for BigInt {
+    fn partial_cmp(&self, other: &BigInt) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl FromStr for BigInt {
+    type Err = String;
+
+    /// parses decimal digits with an optional sign
+    fn from_str(literal: &str) -> Result<BigInt, String> {
+        let (negative, digits) = match literal.strip_prefix('-') {
+            Some(digits) => (true, digits),
+            None => (false, literal.strip_prefix('+').unwrap_or(literal)),
+        };
+        if digits.is_empty() || !digits.bytes().all(|digit| digit.is_ascii_digit()) {
+            return Err(format!("invalid integer literal: {}", literal));
+        }
+        let mut magnitude = vec![];
+        for digit in digits.bytes() {
+            magnitude = add_magnitudes(&mul_magnitudes(&magnitude, &[10]), &[(digit - b'0') as u32]);
+        }
+        Ok(BigInt::new(negative, magnitude))
+    }
+}
+
+impl fmt::Display for BigInt {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        // digits are computed in chunks of 9, from the least significant one
+        let mut chunks = vec![];
+        let mut magnitude = self.magnitude.clone();
+        while !magnitude.is_empty() {
+            let (quotient, remainder) = div_rem_limb(&magnitude, 1_000_000_000);
+            chunks.push(remainder);
+            magnitude = quotient;
+        }
+        if self.negative {
+            write!(f, "-")?;
+        }
+        write!(f, "{}", chunks.last().copied().unwrap_or(0))?;
+        for chunk in chunks.iter().rev().skip(1) {
+            write!(f, "{:09}", chunk)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::BigInt;
+
+    const VALUES: &[i64] = &[
+        0, 1, -1, 2, 7, -7, 31, 32, 1 << 31, -(1 << 31), u32::MAX as i64, -(u32::MAX as i64), 1 << 32,
+        1_000_000_007, -98_765_432_123, i64::MAX, i64::MIN + 1, i64::MIN,
+    ];
+
+    fn big(val: i128) -> BigInt {
+        val.to_string().parse().unwrap()
+    }
+
+    #[test]
+    fn computes_the_same_results_as_i128() {
+        for lhs in VALUES.iter().map(|val| *val as i128) {
+            for rhs in VALUES.iter().map(|val| *val as i128) {
+                assert_eq!(&big(lhs) + &big(rhs), big(lhs + rhs), "{} + {}", lhs, rhs);
+                assert_eq!(&big(lhs) - &big(rhs), big(lhs - rhs), "{} - {}", lhs, rhs);
+                assert_eq!(&big(lhs) & &big(rhs), big(lhs & rhs), "{} & {}", lhs, rhs);
+                assert_eq!(&big(lhs) | &big(rhs), big(lhs | rhs), "{} | {}", lhs, rhs);
+                assert_eq!(&big(lhs) ^ &big(rhs), big(lhs ^ rhs), "{} ^ {}", lhs, rhs);
+                assert_eq!(big(lhs).cmp(&big(rhs)), lhs.cmp(&rhs), "{} <=> {}", lhs, rhs);
+                if rhs != 0 {
+                    let expected = (big(lhs / rhs), big(lhs % rhs));
+                    assert_eq!(big(lhs).div_rem(&big(rhs)), Some(expected), "{} / {}", lhs, rhs);
+                }
+            }
+            for bits in [0, 1, 5, 31, 32, 33, 63] {
+                assert_eq!(&big(lhs) << bits, big(lhs << bits), "{} << {}", lhs, bits);
+                assert_eq!(&big(lhs) >> bits, big(lhs >> bits), "{} >> {}", lhs, bits);
+                assert_eq!(&big(lhs) << -bits, big(lhs >> bits), "{} << -{}", lhs, bits);
+            }
+            assert_eq!(&big(lhs) >> 200, big(if lhs < 0 { -1 } else { 0 }));
+        }
+        assert!(big(1).div_rem(&big(0)).is_none());
+    }
+
+    #[test]
+    fn multiplies_and_divides_numbers_of_any_size() {
+        let factorial = (1..=30).fold(big(1), |product, factor| &product * &big(factor));
+        assert_eq!(factorial.to_string(), "265252859812191058636308480000000");
+        assert_eq!((-&factorial).to_string(), "-265252859812191058636308480000000");
+        let divisor: BigInt = "-1234567890123456789012".parse().unwrap();
+        let (quotient, remainder) = factorial.div_rem(&divisor).unwrap();
+        assert_eq!(quotient.to_string(), "-214854818381");
+        assert_eq!(&(&quotient * &divisor) + &remainder, factorial);
+        assert_eq!((&big(1) << 100).to_string(), "1267650600228229401496703205376");
+        assert_eq!((&big(-1) << 100).bit_length(), 101);
+        assert_eq!(big(-(1 << 32)).bit_length(), 33);
+        assert_eq!(big(0).bit_length(), 0);
+        assert_eq!("007".parse::<BigInt>(), Ok(big(7)));
+        assert!("1-2".parse::<BigInt>().is_err());
+    }
+
+    #[test]
+    fn converts_to_i64() {
+        for val in VALUES {
+            assert_eq!(BigInt::from(*val).to_i64(), Some(*val));
+            assert_eq!(BigInt::from(*val), big(*val as i128));
+        }
+        assert_eq!(big(i64::MAX as i128 + 1).to_i64(), None);
+        assert_eq!(big(i64::MIN as i128 - 1).to_i64(), None);
+        assert_eq!(big(i64::MAX as i128 + 1).wrapping_to_i64(), i64::MIN);
+        assert_eq!(big(-(1 << 64) - 5).wrapping_to_i64(), -5);
+    }
+}
@@ -0,0 +1,430 @@
+use instant_parser::ast;
+use crate::common::{CompilationError, IntWidth};
+use crate::ir::lower;
+
+use std::collections::HashSet;
+
+/// Helpers emitted at the top of every C file, after the definitions of `instant_int` (the type of Instant values)
+/// and related macros: arithmetic is done on unsigned values (where overflow is well defined) and converted back,
+/// which gives the same two's complement wrapping as the JVM and LLVM. Division follows JVM semantics:
+/// MIN / -1 == MIN, MIN % -1 == 0, and division by zero prints an error to stderr and exits with code 1.
+/// Right shift of negative values is implementation-defined in C, so it is expressed with shifts of non-negative
+/// values only. Reading an integer stops at the first character which can't be a part of it, which is pushed back.
+const C_PRELUDE: &[&str] = &[
+    "static instant_int instant_add(instant_int a, instant_int b) { return (instant_int)((instant_uint)a + (instant_uint)b); }",
+    "static instant_int instant_sub(instant_int a, instant_int b) { return (instant_int)((instant_uint)a - (instant_uint)b); }",
+    "static instant_int instant_mul(instant_int a, instant_int b) { return (instant_int)((instant_uint)a * (instant_uint)b); }",
+    "static instant_int instant_neg(instant_int a) { return (instant_int)(0u - (instant_uint)a); }",
+    "static instant_int instant_shl(instant_int a, instant_int b) { return (instant_int)((instant_uint)a << (b & INSTANT_SHIFT_MASK)); }",
+    "static instant_int instant_shr(instant_int a, instant_int b) {",
+    "    return a < 0 ? ~(~a >> (b & INSTANT_SHIFT_MASK)) : a >> (b & INSTANT_SHIFT_MASK);",
+    "}",
+    "static instant_int instant_and(instant_int a, instant_int b) { return a & b; }",
+    "static instant_int instant_or(instant_int a, instant_int b) { return a | b; }",
+    "static instant_int instant_xor(instant_int a, instant_int b) { return a ^ b; }",
+    "static instant_int instant_eq(instant_int a, instant_int b) { return a == b; }",
+    "static instant_int instant_ne(instant_int a, instant_int b) { return a != b; }",
+    "static instant_int instant_lt(instant_int a, instant_int b) { return a < b; }",
+    "static instant_int instant_le(instant_int a, instant_int b) { return a <= b; }",
+    "static instant_int instant_gt(instant_int a, instant_int b) { return a > b; }",
+    "static instant_int instant_ge(instant_int a, instant_int b) { return a >= b; }",
+    "static void instant_check_divisor(instant_int b) {",
+    "    if (b == 0) {",
+    "        fprintf(stderr, \"Exception: division by zero\\n\");",
+    "        exit(1);",
+    "    }",
+    "}",
+    "static instant_int instant_div(instant_int a, instant_int b) {",
+    "    instant_check_divisor(b);",
+    "    if (a == INSTANT_INT_MIN && b == -1) {",
+    "        return INSTANT_INT_MIN;",
+    "    }",
+    "    return a / b;",
+    "}",
+    "static instant_int instant_rem(instant_int a, instant_int b) {",
+    "    instant_check_divisor(b);",
+    "    if (b == -1) {",
+    "        return 0;",
+    "    }",
+    "    return a % b;",
+    "}",
+    "static void instant_print(instant_int val) { printf(\"%\" INSTANT_PRI \"\\n\", val); }",
+    "static instant_int instant_read(void) {",
+    "    int c = getchar();",
+    "    while (c == ' ' || (c >= '\\t' && c <= '\\r')) {",
+    "        c = getchar();",
+    "    }",
+    "    int negative = c == '-';",
+    "    if (c == '-' || c == '+') {",
+    "        c = getchar();",
+    "    }",
+    "    instant_uint result = 0;",
+    "    while (c >= '0' && c <= '9') {",
+    "        result = result * 10u + (instant_uint)(c - '0');",
+    "        c = getchar();",
+    "    }",
+    "    ungetc(c, stdin);",
+    "    return (instant_int)(negative ? 0u - result : result);",
+    "}",
+    "",
+];
+
+/// includes and definitions of the type of Instant values, which are used by the prelude and the compiled code
+fn c_header(int_width: IntWidth) -> Vec<String> {
+    let bits = int_width.bits();
+    vec![
+        String::from("#include <inttypes.h>"),
+        String::from("#include <stdint.h>"),
+        String::from("#include <stdio.h>"),
+        String::from("#include <stdlib.h>"),
+        String::new(),
+        format!("typedef int{}_t instant_int;", bits),
+        format!("typedef uint{}_t instant_uint;", bits),
+        format!("#define INSTANT_INT_MIN INT{}_MIN", bits),
+        format!("#define INSTANT_SHIFT_MASK {}", bits - 1),
+        format!("#define INSTANT_PRI PRId{}", bits),
+        String::new(),
+    ]
+}
+
+pub trait FormatC {
+    fn format_c(&self) -> String;
+}
+
+pub trait CompileC {
+    fn compile_c(&self, variables: &mut HashSet<String>, temps_count: &mut u32) -> Result<CompiledCode, CompilationError>;
+}
+
+/// Compiled expression: `instructions` are assignments of temporaries (holding results of function calls
+/// and reads, and the values evaluated before them), which have to be executed in order before `result` is evaluated.
+/// Order of evaluation of function arguments is unspecified in C, so calls, which may print values,
+/// are never nested in other calls. Compiled statements consist only of instructions.
+pub struct CompiledCode {
+    instructions: Vec<String>,
+    result: String,
+}
+
+/// Instant identifiers are prefixed to avoid collisions with C keywords and the prelude
+fn variable_name(var: &str) -> String {
+    format!("v_{}", var)
+}
+
+fn function_name(function: &str) -> String {
+    format!("f_{}", function)
+}
+
+fn new_temp(temps_count: &mut u32) -> String {
+    *temps_count += 1;
+    format!("t_{}", *temps_count - 1)
+}
+
+/// stores the result in a new temporary, so that it is evaluated before the instructions that follow
+fn store_in_temp(code: CompiledCode, temps_count: &mut u32) -> CompiledCode {
+    let temp = new_temp(temps_count);
+    let mut instructions = code.instructions;
+    instructions.push(format!("{} = {}", temp, code.result));
+    CompiledCode { instructions, result: temp }
+}
+
+/// stores the result in a temporary only if it is followed by a call, variables, constants and temporaries
+/// can't be changed by calls, so they don't need to be stored again
+fn evaluate_before_calls(code: CompiledCode, calls_follow: bool, temps_count: &mut u32) -> CompiledCode {
+    let is_simple = code.result.chars().all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-');
+    if !calls_follow || is_simple {
+        code
+    } else {
+        store_in_temp(code, temps_count)
+    }
+}
+
+/// single C expression, which executes the instructions with the comma operator
+fn sequence(code: CompiledCode) -> String {
+    if code.instructions.is_empty() {
+        code.result
+    } else {
+        format!("({}, {})", code.instructions.join(", "), code.result)
+    }
+}
+
+/// compiles the expression to statements executing its instructions, and the result,
+/// with declarations of temporaries created since `first_temp`
+fn compile_statement_expr(
+    expr: &ast::Expr, variables: &mut HashSet<String>, temps_count: &mut u32
+) -> Result<(Vec<String>, String), CompilationError> {
+    let first_temp = *temps_count;
+    let compiled_expr = expr.compile_c(variables, temps_count)?;
+    let mut instructions = declare_temps(first_temp, *temps_count);
+    instructions.extend(compiled_expr.instructions.into_iter().map(|instr| format!("{};", instr)));
+    Ok((instructions, compiled_expr.result))
+}
+
+fn declare_temps(first_temp: u32, temps_count: u32) -> Vec<String> {
+    if first_temp == temps_count {
+        return vec![];
+    }
+    let temps: Vec<String> = (first_temp..temps_count).map(|temp| format!("t_{}", temp)).collect();
+    vec![format!("instant_int {};", temps.join(", "))]
+}
+
+/// compiles statements of a block, variables declared in the block go out of scope after it (as in C)
+fn compile_block(
+    stmts: &[Box<ast::Stmt>], variables: &HashSet<String>, temps_count: &mut u32
+) -> Result<Vec<String>, CompilationError> {
+    let mut block_variables = variables.clone();
+    let mut instructions: Vec<String> = vec![];
+    for stmt in stmts.iter() {
+        let compiled_stmt = stmt.compile_c(&mut block_variables, temps_count)?;
+        instructions.extend(compiled_stmt.instructions.into_iter().map(|instr| format!("    {}", instr)));
+    }
+    instructions.push(String::from("}"));
+    Ok(instructions)
+}
+
+/// signature of the C function implementing an Instant function
+fn function_signature(function: &ast::Function) -> String {
+    let params: Vec<String> = function.params.iter().map(|param| format!("instant_int {}", variable_name(param))).collect();
+    // empty parentheses would declare a function with unspecified parameters
+    let params = if params.is_empty() { String::from("void") } else { params.join(", ") };
+    format!("static instant_int {}({})", function_name(&function.name), params)
+}
+
+impl CompileC for ast::Function {
+    fn compile_c(&self, _variables: &mut HashSet<String>, temps_count: &mut u32) -> Result<CompiledCode, CompilationError> {
+        // functions see only their parameters, and return 0 if the end of the body is reached
+        let params: HashSet<String> = self.params.iter().cloned().collect();
+        let mut instructions = vec![format!("{} {{", function_signature(self))];
+        instructions.append(&mut compile_block(&self.body, &params, temps_count)?);
+        if !matches!(self.body.last().map(|stmt| stmt.as_ref()), Some(ast::Stmt::Return { .. })) {
+            instructions.insert(instructions.len() - 1, String::from("    return 0;"));
+        }
+        Ok(CompiledCode { instructions, result: String::new() })
+    }
+}
+
+impl CompileC for ast::Prog {
+    fn compile_c(&self, variables: &mut HashSet<String>, temps_count: &mut u32) -> Result<CompiledCode, CompilationError> {
+        let mut instructions: Vec<String> = vec![];
+        for stmt in self.stmts.iter() {
+            let mut compiled_stmt = stmt.compile_c(variables, temps_count)?;
+            instructions.append(&mut compiled_stmt.instructions);
+        }
+        let compiled_program = CompiledCode { instructions, result: String::new() };
+        Ok(compiled_program)
+    }
+}
+
+impl CompileC for ast::Stmt {
+    fn compile_c(&self, variables: &mut HashSet<String>, temps_count: &mut u32) -> Result<CompiledCode, CompilationError> {
+        let instructions = match self {
+            // the value is discarded, but it is still evaluated, as it may divide by zero
+            ast::Stmt::Expr { expr } => {
+                let (mut instructions, result) = compile_statement_expr(expr, variables, temps_count)?;
+                instructions.push(format!("(void){};", result));
+                instructions
+            },
+            ast::Stmt::Decl { var, expr } => {
+                let (mut instructions, result) = compile_statement_expr(expr, variables, temps_count)?;
+                if variables.insert(var.clone()) {
+                    // first assignment also declares the variable
+                    instructions.push(format!("instant_int {} = {};", variable_name(var), result));
+                } else {
+                    instructions.push(format!("{} = {};", variable_name(var), result));
+                }
+                instructions
+            },
+            ast::Stmt::Block { stmts } => {
+                let mut instructions = vec![String::from("{")];
+                instructions.append(&mut compile_block(stmts, variables, temps_count)?);
+                instructions
+            },
+            ast::Stmt::While { cond, body } => {
+                // the condition is evaluated again in every iteration, together with its calls
+                let first_temp = *temps_count;
+                let compiled_cond = cond.compile_c(variables, temps_count)?;
+                let mut instructions = declare_temps(first_temp, *temps_count);
+                instructions.push(format!("while ({}) {{", sequence(compiled_cond)));
+                instructions.append(&mut compile_block(body, variables, temps_count)?);
+                instructions
+            },
+            ast::Stmt::Return { expr } => {
+                let (mut instructions, result) = compile_statement_expr(expr, variables, temps_count)?;
+                instructions.push(format!("return {};", result));
+                instructions
+            },
+            // all values are evaluated (in order, as arguments of a call) before they are printed by a single printf
+            ast::Stmt::Print { exprs } if exprs.len() > 1 => {
+                let first_temp = *temps_count;
+                let mut compiled_exprs = vec![];
+                for expr in exprs.iter() {
+                    compiled_exprs.push(expr.compile_c(variables, temps_count)?);
+                }
+                let has_calls: Vec<bool> = compiled_exprs.iter().map(|expr| !expr.instructions.is_empty()).collect();
+                let mut expr_instructions = vec![];
+                let mut results = vec![];
+                for (index, expr) in compiled_exprs.into_iter().enumerate() {
+                    let expr = evaluate_before_calls(expr, has_calls[index + 1..].contains(&true), temps_count);
+                    expr_instructions.extend(expr.instructions.into_iter().map(|instr| format!("{};", instr)));
+                    results.push(expr.result);
+                }
+                let mut instructions = declare_temps(first_temp, *temps_count);
+                instructions.append(&mut expr_instructions);
+                let format = vec!["%\" INSTANT_PRI \""; exprs.len()].join(" ");
+                // arguments of printf are not converted to the type of the format, and literals are only `int`s
+                let args: Vec<String> = results.iter().map(|result| format!("(instant_int)({})", result)).collect();
+                instructions.push(format!("printf(\"{}\\n\", {});", format, args.join(", ")));
+                instructions
+            },
+            ast::Stmt::Print { exprs } => {
+                let (mut instructions, result) = compile_statement_expr(&exprs[0], variables, temps_count)?;
+                instructions.push(format!("instant_print({});", result));
+                instructions
+            },
+        };
+        let compiled_stmt = CompiledCode { instructions, result: String::new() };
+        Ok(compiled_stmt)
+    }
+}
+
+impl CompileC for ast::Expr {
+    fn compile_c(&self, variables: &mut HashSet<String>, temps_count: &mut u32) -> Result<CompiledCode, CompilationError> {
+        let compiled_expr = match self {
+            ast::Expr::Binary { left, op, right } => {
+                let lhs = left.compile_c(variables, temps_count)?;
+                let rhs = right.compile_c(variables, temps_count)?;
+                let lhs = evaluate_before_calls(lhs, !rhs.instructions.is_empty(), temps_count);
+                let mut instructions = lhs.instructions;
+                instructions.extend(rhs.instructions);
+                CompiledCode { instructions, result: format!("{}({}, {})", op.format_c(), lhs.result, rhs.result) }
+            },
+            ast::Expr::If { cond, then, otherwise } => {
+                // only one of the branches is evaluated, as in the conditional operator in C
+                let cond = cond.compile_c(variables, temps_count)?;
+                let then = then.compile_c(variables, temps_count)?;
+                let otherwise = otherwise.compile_c(variables, temps_count)?;
+                if then.instructions.is_empty() && otherwise.instructions.is_empty() {
+                    let result = format!("({} ? {} : {})", cond.result, then.result, otherwise.result);
+                    CompiledCode { instructions: cond.instructions, result }
+                } else {
+                    // calls in the branches are executed together with the whole conditional
+                    let code = CompiledCode {
+                        instructions: cond.instructions,
+                        result: format!("({} ? {} : {})", cond.result, sequence(then), sequence(otherwise)),
+                    };
+                    store_in_temp(code, temps_count)
+                }
+            },
+            ast::Expr::Neg { expr } => {
+                let compiled_expr = expr.compile_c(variables, temps_count)?;
+                let result = format!("instant_neg({})", compiled_expr.result);
+                CompiledCode { instructions: compiled_expr.instructions, result }
+            },
+            ast::Expr::Number { val } => {
+                let result = if *val == i32::MIN as i64 || *val == i64::MIN {
+                    // -2147483648 is parsed by C as negation of a literal too large for int (and similarly
+                    // for the minimal 64-bit value), so it is written as a difference of values in range
+                    format!("({} - 1)", val + 1)
+                } else {
+                    val.to_string()
+                };
+                CompiledCode { instructions: vec![], result }
+            },
+            // literals out of the range of the integer width are rejected when the program is lowered
+            ast::Expr::BigNumber { .. } => unreachable!(),
+            ast::Expr::Variable { var } => {
+                if !variables.contains(var) {
+                    return Err(CompilationError::UnidentifiedVariable { identifier: var.clone() });
+                }
+                CompiledCode { instructions: vec![], result: variable_name(var) }
+            },
+            ast::Expr::Call { function, args } => {
+                let mut compiled_args = vec![];
+                for arg in args.iter() {
+                    compiled_args.push(arg.compile_c(variables, temps_count)?);
+                }
+                // arguments are evaluated from left to right, before calls in the next arguments
+                let has_calls: Vec<bool> = compiled_args.iter().map(|arg| !arg.instructions.is_empty()).collect();
+                let mut instructions = vec![];
+                let mut arg_results = vec![];
+                for (index, arg) in compiled_args.into_iter().enumerate() {
+                    let arg = evaluate_before_calls(arg, has_calls[index + 1..].contains(&true), temps_count);
+                    instructions.extend(arg.instructions);
+                    arg_results.push(arg.result);
+                }
+                let code = CompiledCode {
+                    instructions,
+                    result: format!("{}({})", function_name(function), arg_results.join(", ")),
+                };
+                store_in_temp(code, temps_count)
+            },
+            ast::Expr::Read => {
+                // reads are sequenced in the same way as calls
+                let code = CompiledCode { instructions: vec![], result: String::from("instant_read()") };
+                store_in_temp(code, temps_count)
+            },
+            // programs with doubles are rejected, so only integers are converted (to integers)
+            ast::Expr::Convert { expr, .. } => expr.compile_c(variables, temps_count)?,
+            ast::Expr::Double { .. } => unreachable!(),
+        };
+        Ok(compiled_expr)
+    }
+}
+
+impl FormatC for ast::Opcode {
+    fn format_c(&self) -> String {
+        let fn_name = match self {
+            ast::Opcode::Add => "instant_add",
+            ast::Opcode::Sub => "instant_sub",
+            ast::Opcode::Mul => "instant_mul",
+            ast::Opcode::Div => "instant_div",
+            ast::Opcode::Rem => "instant_rem",
+            ast::Opcode::And => "instant_and",
+            ast::Opcode::Or => "instant_or",
+            ast::Opcode::Xor => "instant_xor",
+            ast::Opcode::Shl => "instant_shl",
+            ast::Opcode::Shr => "instant_shr",
+            ast::Opcode::Eq => "instant_eq",
+            ast::Opcode::Ne => "instant_ne",
+            ast::Opcode::Lt => "instant_lt",
+            ast::Opcode::Le => "instant_le",
+            ast::Opcode::Gt => "instant_gt",
+            ast::Opcode::Ge => "instant_ge",
+        };
+        String::from(fn_name)
+    }
+}
+
+/// compiles the program to a self-contained C99 source file working on integers of the given width
+/// (unbounded integers and doubles are not supported), functions, calls, literals and types are checked by lowering
+/// the program to IR first (the C code is generated directly from the abstract syntax tree)
+pub fn compile_c(program: &ast::Prog, int_width: IntWidth) -> Result<Vec<String>, CompilationError> {
+    if int_width == IntWidth::Unbounded {
+        return Err(CompilationError::UnsupportedIntWidth { width: int_width });
+    }
+    if lower(program, int_width)?.uses_doubles() {
+        return Err(CompilationError::UnsupportedDoubles);
+    }
+    let mut instructions = c_header(int_width);
+    instructions.extend(C_PRELUDE.iter().map(|line| String::from(*line)));
+    let mut temps_count = 0;
+
+    // functions are declared first, as they may be called before their definitions
+    for function in program.functions.iter() {
+        instructions.push(format!("{};", function_signature(function)));
+    }
+    for function in program.functions.iter() {
+        instructions.append(&mut function.compile_c(&mut HashSet::new(), &mut temps_count)?.instructions);
+    }
+
+    instructions.push(String::from("int main(void) {"));
+    let mut used_variables: HashSet<String> = HashSet::new();
+    let compilation_result = program.compile_c(&mut used_variables, &mut temps_count)?;
+    for instr in compilation_result.instructions {
+        instructions.push(format!("    {}", instr));
+    }
+
+    instructions.append(&mut vec![
+        String::from("    return 0;"),
+        String::from("}"),
+    ]);
+    Ok(instructions)
+}
\ No newline at end of file
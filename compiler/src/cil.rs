@@ -0,0 +1,421 @@
+use crate::common::{CompilationError, IntWidth};
+use crate::stack::{CompiledCode, CompiledProgram, Condition, Instruction};
+
+/// Names of the helper methods used for division, remainder and reading the input, see `translate`
+const DIVIDE_METHOD: &str = "Divide";
+const REMAINDER_METHOD: &str = "Remainder";
+const READ_METHOD: &str = "ReadInt";
+
+/// CIL type of Instant values
+fn int_type(int_width: IntWidth) -> &'static str {
+    match int_width {
+        IntWidth::I32 => "int32",
+        IntWidth::I64 => "int64",
+        // rejected by `translate`
+        IntWidth::Unbounded => unreachable!(),
+    }
+}
+
+/// Instant functions are prefixed, so that they don't collide with the helper methods
+fn method_name(function: &str) -> String {
+    format!("'instant.{}'", function)
+}
+
+/// signature of the static method implementing an Instant function, with `params` parameters of type `ty`
+fn method_signature(function: &str, params: u32, ty: &str) -> String {
+    format!("{} {}({})", ty, method_name(function), vec![ty; params as usize].join(", "))
+}
+
+fn method_call(class_name: &str, function: &str, params: u32, ty: &str) -> String {
+    format!("{} '{}'::{}({})", ty, class_name, method_name(function), vec![ty; params as usize].join(", "))
+}
+
+struct MethodContext<'a> {
+    class_name: &'a str,
+    int_width: IntWidth,
+    /// CIL has no swap instruction, so swapping is done through two extra locals
+    /// (placed after the locals used by Instant variables)
+    swap_locals: (u32, u32),
+}
+
+trait CILOutput {
+    fn translate(&self, context: &MethodContext) -> Vec<String>;
+}
+
+/// conditional branch instruction, comparing two values from the stack
+fn branch_instruction(cond: &Condition) -> &'static str {
+    match cond {
+        Condition::Eq => "beq",
+        Condition::Ne => "bne.un",
+        Condition::Lt => "blt",
+        Condition::Le => "ble",
+        Condition::Gt => "bgt",
+        Condition::Ge => "bge",
+    }
+}
+
+fn load_local(addr: u32) -> String {
+    match addr {
+        0..=3 => format!("ldloc.{}", addr),
+        4..=255 => format!("ldloc.s {}", addr),
+        _ => format!("ldloc {}", addr),
+    }
+}
+
+fn load_arg(index: u32) -> String {
+    match index {
+        0..=3 => format!("ldarg.{}", index),
+        4..=255 => format!("ldarg.s {}", index),
+        _ => format!("ldarg {}", index),
+    }
+}
+
+fn store_local(addr: u32) -> String {
+    match addr {
+        0..=3 => format!("stloc.{}", addr),
+        4..=255 => format!("stloc.s {}", addr),
+        _ => format!("stloc {}", addr),
+    }
+}
+
+/// translates the code to a static method - `header` is the method declaration followed by its directives,
+/// arguments of functions are copied to the locals used for parameters by the stack code
+fn translate_method(
+    code: &CompiledCode, header: Vec<String>, params: u32, class_name: &str, int_width: IntWidth
+) -> Vec<String> {
+    let uses_swap = code.instructions.iter().any(|instr| matches!(instr, Instruction::SWAP));
+    let locals_count = if uses_swap { code.locals_limit + 2 } else { code.locals_limit };
+    // comparisons with zero other than brfalse / brtrue load the zero to the stack
+    let compares_with_zero = code.instructions.iter()
+        .any(|instr| matches!(instr, Instruction::IF { cond, .. } if !matches!(cond, Condition::Eq | Condition::Ne)));
+    let max_stack = if compares_with_zero { code.stack_limit + 1 } else { code.stack_limit };
+
+    let mut instruction_vec = header;
+    instruction_vec.push(format!(".maxstack {}", max_stack));
+    if locals_count > 0 {
+        let locals: Vec<String> = (0..locals_count).map(|addr| format!("{} V_{}", int_type(int_width), addr)).collect();
+        instruction_vec.push(format!(".locals init ({})", locals.join(", ")));
+    }
+    for param in 0..params {
+        instruction_vec.push(load_arg(param));
+        instruction_vec.push(store_local(param));
+    }
+
+    let context = MethodContext {
+        class_name,
+        int_width,
+        swap_locals: (code.locals_limit, code.locals_limit + 1),
+    };
+    for instr in code.instructions.iter() {
+        let mut instr_cil_format = instr.translate(&context);
+        instruction_vec.append(&mut instr_cil_format);
+    }
+    instruction_vec
+}
+
+/// translates the main program to the entry point of the assembly, and functions to separate methods
+fn translate_methods(compiled_program: &CompiledProgram, class_name: &str) -> Vec<String> {
+    let int_width = compiled_program.int_width;
+    let header = vec![
+        String::from(".method public hidebysig static void Main() cil managed"),
+        String::from("{"),
+        String::from(".entrypoint"),
+    ];
+    let mut instruction_vec = translate_method(&compiled_program.main, header, 0, class_name, int_width);
+    instruction_vec.push(String::from("ret"));
+    instruction_vec.push(String::from("}"));
+
+    // code of functions ends with RETURN
+    for function in compiled_program.functions.iter() {
+        let header = vec![
+            format!(
+                ".method private hidebysig static {} cil managed",
+                method_signature(&function.name, function.params, int_type(int_width)),
+            ),
+            String::from("{"),
+        ];
+        instruction_vec.append(&mut translate_method(&function.code, header, function.params, class_name, int_width));
+        instruction_vec.push(String::from("}"));
+    }
+    instruction_vec
+}
+
+impl CILOutput for Instruction {
+    fn translate(&self, context: &MethodContext) -> Vec<String> {
+        let mut instruction_vec: Vec<String> = vec![];
+        let ty = int_type(context.int_width);
+        let long = context.int_width == IntWidth::I64;
+        match self {
+            Instruction::PUSH { val } if long => {
+                instruction_vec.push(format!("ldc.i8 {}", val));
+            },
+            Instruction::PUSH { val } => {
+                let instr = match *val {
+                    -1 => String::from("ldc.i4.m1"),
+                    0..=8 => format!("ldc.i4.{}", val),
+                    -128..=127 => format!("ldc.i4.s {}", val),
+                    _ => format!("ldc.i4 {}", val),
+                };
+                instruction_vec.push(instr);
+            },
+            Instruction::MUL => {
+                instruction_vec.push(String::from("mul"));
+            },
+            Instruction::ADD => {
+                instruction_vec.push(String::from("add"));
+            },
+            Instruction::SUB => {
+                instruction_vec.push(String::from("sub"));
+            },
+            Instruction::DIV => {
+                instruction_vec.push(format!(
+                    "call {} '{}'::{}({}, {})", ty, context.class_name, DIVIDE_METHOD, ty, ty
+                ));
+            },
+            Instruction::REM => {
+                instruction_vec.push(format!(
+                    "call {} '{}'::{}({}, {})", ty, context.class_name, REMAINDER_METHOD, ty, ty
+                ));
+            },
+            Instruction::AND => {
+                instruction_vec.push(String::from("and"));
+            },
+            Instruction::OR => {
+                instruction_vec.push(String::from("or"));
+            },
+            Instruction::XOR => {
+                instruction_vec.push(String::from("xor"));
+            },
+            Instruction::SHL | Instruction::SHR => {
+                // shl doesn't mask the shift amount, unlike JVM, and the amount of shifts of int64 is an int32
+                if long {
+                    instruction_vec.push(String::from("conv.i4"));
+                }
+                instruction_vec.append(&mut vec![
+                    format!("ldc.i4.s {}", if long { 63 } else { 31 }),
+                    String::from("and"),
+                    String::from(if *self == Instruction::SHL { "shl" } else { "shr" }),
+                ]);
+            },
+            Instruction::NEG => {
+                instruction_vec.push(String::from("neg"));
+            },
+            Instruction::PRINT | Instruction::PRINT_END => {
+                instruction_vec.push(format!("call void [mscorlib]System.Console::WriteLine({})", ty));
+            },
+            Instruction::PRINT_INLINE => {
+                instruction_vec.extend(vec![
+                    format!("call void [mscorlib]System.Console::Write({})", ty),
+                    String::from("ldc.i4.s 32"),
+                    String::from("call void [mscorlib]System.Console::Write(char)"),
+                ]);
+            },
+            // WriteLine is a static method, so the slot reserved for the receiver is not used
+            Instruction::PRINT_BEGIN => {},
+            Instruction::STORE { addr } => {
+                instruction_vec.push(store_local(*addr as u32));
+            },
+            Instruction::LOAD { addr } => {
+                instruction_vec.push(load_local(*addr as u32));
+            },
+            Instruction::DUP => {
+                instruction_vec.push(String::from("dup"));
+            },
+            Instruction::SWAP => {
+                let (upper, lower) = context.swap_locals;
+                instruction_vec.append(&mut vec![
+                    store_local(upper),
+                    store_local(lower),
+                    load_local(upper),
+                    load_local(lower),
+                ]);
+            },
+            Instruction::LABEL { label } => {
+                instruction_vec.push(format!("L{}:", label));
+            },
+            Instruction::GOTO { label } => {
+                instruction_vec.push(format!("br L{}", label));
+            },
+            Instruction::IF { cond: Condition::Eq, label } => {
+                instruction_vec.push(format!("brfalse L{}", label));
+            },
+            Instruction::IF { cond: Condition::Ne, label } => {
+                instruction_vec.push(format!("brtrue L{}", label));
+            },
+            Instruction::IF { cond, label } => {
+                instruction_vec.push(String::from(if long { "ldc.i8 0" } else { "ldc.i4.0" }));
+                instruction_vec.push(format!("{} L{}", branch_instruction(cond), label));
+            },
+            Instruction::IF_CMP { cond, label } => {
+                instruction_vec.push(format!("{} L{}", branch_instruction(cond), label));
+            },
+            Instruction::CALL { function, args } => {
+                instruction_vec.push(format!("call {}", method_call(context.class_name, function, *args, ty)));
+            },
+            Instruction::RETURN => {
+                instruction_vec.push(String::from("ret"));
+            },
+            Instruction::READ => {
+                instruction_vec.push(format!("call {} '{}'::{}()", ty, context.class_name, READ_METHOD));
+            },
+            // programs with doubles are rejected before translation
+            _ => unreachable!(),
+        };
+        instruction_vec
+    }
+}
+
+/// constant -1 or 0 of the type of Instant values
+fn minus_one(int_width: IntWidth) -> String {
+    String::from(if int_width == IntWidth::I64 { "ldc.i8 -1" } else { "ldc.i4.m1" })
+}
+
+fn zero(int_width: IntWidth) -> String {
+    String::from(if int_width == IntWidth::I64 { "ldc.i8 0" } else { "ldc.i4.0" })
+}
+
+/// Division helper: `div` throws OverflowException for MIN / -1,
+/// while Instant programs wrap around (as in JVM, where the result is MIN)
+fn divide_method(int_width: IntWidth) -> Vec<String> {
+    let ty = int_type(int_width);
+    vec![
+        format!(".method private hidebysig static {} {}({} a, {} b) cil managed", ty, DIVIDE_METHOD, ty, ty),
+        String::from("{"),
+        String::from(".maxstack 2"),
+        String::from("ldarg.1"),
+        minus_one(int_width),
+        String::from("bne.un.s DIVIDE"),
+        String::from("ldarg.0"),
+        String::from("neg"),
+        String::from("ret"),
+        String::from("DIVIDE: ldarg.0"),
+        String::from("ldarg.1"),
+        String::from("div"),
+        String::from("ret"),
+        String::from("}"),
+    ]
+}
+
+/// Remainder helper: `rem` may throw ArithmeticException for MIN % -1, while the result should be 0
+fn remainder_method(int_width: IntWidth) -> Vec<String> {
+    let ty = int_type(int_width);
+    vec![
+        format!(".method private hidebysig static {} {}({} a, {} b) cil managed", ty, REMAINDER_METHOD, ty, ty),
+        String::from("{"),
+        String::from(".maxstack 2"),
+        String::from("ldarg.1"),
+        minus_one(int_width),
+        String::from("bne.un.s REMAINDER"),
+        zero(int_width),
+        String::from("ret"),
+        String::from("REMAINDER: ldarg.0"),
+        String::from("ldarg.1"),
+        String::from("rem"),
+        String::from("ret"),
+        String::from("}"),
+    ]
+}
+
+/// Reading helper, in the same way as `scanf("%d")` in the runtime of LLVM: whitespace is skipped, followed by
+/// an optional sign and digits, and the first character which is not a part of the integer is only peeked,
+/// so 0 is returned at the end of the input (where `Peek` returns -1). The result has the type of Instant values,
+/// digits are converted to it before they are added.
+fn read_method(int_width: IntWidth) -> Vec<String> {
+    let ty = int_type(int_width);
+    let convert = if int_width == IntWidth::I64 { vec![String::from("conv.i8")] } else { vec![] };
+    let mut instruction_vec = vec![
+        format!(".method private hidebysig static {} {}() cil managed", ty, READ_METHOD),
+        String::from("{"),
+        String::from(".maxstack 3"),
+        format!(".locals init (int32 c, int32 negative, {} result)", ty),
+        String::from("SKIP: call class [mscorlib]System.IO.TextReader [mscorlib]System.Console::get_In()"),
+        String::from("callvirt instance int32 [mscorlib]System.IO.TextReader::Peek()"),
+        String::from("stloc.0"),
+        String::from("ldloc.0"),
+        String::from("ldc.i4.s 32"),
+        String::from("beq.s SKIP_CHAR"),
+        String::from("ldloc.0"),
+        String::from("ldc.i4.s 9"),
+        String::from("blt.s SIGN"),
+        String::from("ldloc.0"),
+        String::from("ldc.i4.s 13"),
+        String::from("bgt.s SIGN"),
+        String::from("SKIP_CHAR: call class [mscorlib]System.IO.TextReader [mscorlib]System.Console::get_In()"),
+        String::from("callvirt instance int32 [mscorlib]System.IO.TextReader::Read()"),
+        String::from("pop"),
+        String::from("br.s SKIP"),
+        String::from("SIGN: ldloc.0"),
+        String::from("ldc.i4.s 45"),
+        String::from("ceq"),
+        String::from("stloc.1"),
+        String::from("ldloc.0"),
+        String::from("ldc.i4.s 45"),
+        String::from("beq.s SIGN_CHAR"),
+        String::from("ldloc.0"),
+        String::from("ldc.i4.s 43"),
+        String::from("bne.un.s DIGITS"),
+        String::from("SIGN_CHAR: call class [mscorlib]System.IO.TextReader [mscorlib]System.Console::get_In()"),
+        String::from("callvirt instance int32 [mscorlib]System.IO.TextReader::Read()"),
+        String::from("pop"),
+        String::from("DIGITS: call class [mscorlib]System.IO.TextReader [mscorlib]System.Console::get_In()"),
+        String::from("callvirt instance int32 [mscorlib]System.IO.TextReader::Peek()"),
+        String::from("stloc.0"),
+        String::from("ldloc.0"),
+        String::from("ldc.i4.s 48"),
+        String::from("blt.s DONE"),
+        String::from("ldloc.0"),
+        String::from("ldc.i4.s 57"),
+        String::from("bgt.s DONE"),
+        String::from("ldloc.2"),
+        String::from("ldc.i4.s 10"),
+    ];
+    instruction_vec.extend(convert.clone());
+    instruction_vec.extend(vec![
+        String::from("mul"),
+        String::from("ldloc.0"),
+        String::from("ldc.i4.s 48"),
+        String::from("sub"),
+    ]);
+    instruction_vec.extend(convert);
+    instruction_vec.extend(vec![
+        String::from("add"),
+        String::from("stloc.2"),
+        String::from("call class [mscorlib]System.IO.TextReader [mscorlib]System.Console::get_In()"),
+        String::from("callvirt instance int32 [mscorlib]System.IO.TextReader::Read()"),
+        String::from("pop"),
+        String::from("br.s DIGITS"),
+        String::from("DONE: ldloc.2"),
+        String::from("ldloc.1"),
+        String::from("brfalse.s POSITIVE"),
+        String::from("neg"),
+        String::from("POSITIVE: ret"),
+        String::from("}"),
+    ]);
+    instruction_vec
+}
+
+/// translates the program to ILAsm source of an assembly with a single class,
+/// unbounded integers and doubles are not supported
+pub fn translate(compiled_program: &CompiledProgram, name: &str) -> Result<Vec<String>, CompilationError> {
+    if compiled_program.int_width == IntWidth::Unbounded {
+        return Err(CompilationError::UnsupportedIntWidth { width: compiled_program.int_width });
+    }
+    if compiled_program.uses_doubles() {
+        return Err(CompilationError::UnsupportedDoubles);
+    }
+    let mut cil_representation = vec![
+        String::from(".assembly extern mscorlib {}"),
+        format!(".assembly '{}' {{}}", name),
+        format!(".module '{}.exe'", name),
+        format!(".class public auto ansi beforefieldinit '{}' extends [mscorlib]System.Object", name),
+        String::from("{"),
+    ];
+    cil_representation.append(&mut divide_method(compiled_program.int_width));
+    cil_representation.append(&mut remainder_method(compiled_program.int_width));
+    if compiled_program.reads_input() {
+        cil_representation.append(&mut read_method(compiled_program.int_width));
+    }
+    cil_representation.append(&mut translate_methods(compiled_program, name));
+    cil_representation.push(String::from("}"));
+    Ok(cil_representation)
+}
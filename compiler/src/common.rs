@@ -1,4 +1,232 @@
+use instant_parser::ast::Type;
+use crate::bigint::BigInt;
+
+use std::fmt;
+use std::io::BufRead;
+use std::str::FromStr;
+
 #[derive(Debug)]
 pub enum CompilationError {
     UnidentifiedVariable { identifier: String },
+    ExecutableMemoryUnavailable { reason: String },
+    UnknownPass { name: String },
+    UndefinedFunction { name: String },
+    DuplicateFunction { name: String },
+    DuplicateParameter { function: String, name: String },
+    ArityMismatch { function: String, expected: usize, found: usize },
+    ReturnOutsideFunction,
+    /// the literal (given by its digits) doesn't fit in integers of the selected width
+    LiteralOutOfRange { literal: String },
+    /// the backend can't compile programs with integers of the selected width
+    UnsupportedIntWidth { width: IntWidth },
+    /// the value can't be used where a value of the expected type is needed (integers are converted
+    /// to doubles implicitly, but doubles have to be converted to integers with `int(...)`)
+    TypeMismatch { expected: Type, found: Type },
+    /// the backend can't compile programs with doubles, which are also not supported together with unbounded integers
+    UnsupportedDoubles,
+}
+
+#[derive(Debug)]
+pub enum RuntimeError {
+    DivisionByZero,
+    /// nesting of function calls exceeded MAX_CALL_DEPTH
+    StackOverflow,
+    /// result of an operation on unbounded integers doesn't fit in i64, so it can't be computed
+    /// by the stack VM (and the operation is not folded by the optimizer)
+    IntegerOverflow,
+    /// result of a shift of unbounded integers would have `MAX_BIG_BITS` bits or more
+    ShiftOverflow,
+}
+
+/// Limit of nested function calls in the interpreter, stack VM and JIT, which keep their frames on the heap -
+/// it is a rule of the language in these modes, chosen so that deep recursion behaves the same in all of them,
+/// while compiled programs are only limited by the stack of their platform
+pub const MAX_CALL_DEPTH: usize = 100_000;
+
+/// Width of integers in the compiled program, selected with `--int-width=<bits>` (32 bits by default).
+/// Values are stored as i64 by the compiler and the interpreters, and results of operations are wrapped around
+/// to the selected width, so 32-bit programs behave exactly as if they were computed on i32.
+/// Unbounded integers (`--int-width=unbounded`) never wrap around: the interpreter computes them as `BigInt`,
+/// while the compiler keeps only constants which fit in i64, and folds only operations whose results fit too.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum IntWidth {
+    #[default]
+    I32,
+    I64,
+    Unbounded,
+}
+
+impl IntWidth {
+    /// number of bits of integers of a fixed width (backends check that integers are not unbounded before)
+    pub fn bits(self) -> u32 {
+        match self {
+            IntWidth::I32 => 32,
+            IntWidth::I64 => 64,
+            IntWidth::Unbounded => panic!("unbounded integers have no fixed number of bits"),
+        }
+    }
+
+    /// truncates the value to the width, as the result of an operation which wrapped around
+    pub fn wrap(self, val: i64) -> i64 {
+        match self {
+            IntWidth::I32 => val as i32 as i64,
+            IntWidth::I64 | IntWidth::Unbounded => val,
+        }
+    }
+
+    /// whether the value can be represented without wrapping around
+    pub fn contains(self, val: i64) -> bool {
+        self.wrap(val) == val
+    }
+
+    /// whether multiplying by the value is the same as shifting left by its number of trailing zeros -
+    /// with a fixed width, it's enough that bits of the value interpreted as an unsigned integer are a power of two
+    pub fn is_power_of_two(self, val: i64) -> bool {
+        match self {
+            IntWidth::Unbounded => val > 0 && (val as u64).is_power_of_two(),
+            _ => (val as u64 & (u64::MAX >> (64 - self.bits()))).is_power_of_two(),
+        }
+    }
+}
+
+impl FromStr for IntWidth {
+    type Err = String;
+
+    fn from_str(bits: &str) -> Result<Self, Self::Err> {
+        match bits {
+            "32" => Ok(IntWidth::I32),
+            "64" => Ok(IntWidth::I64),
+            "unbounded" => Ok(IntWidth::Unbounded),
+            _ => Err(format!("unsupported integer width: {}", bits)),
+        }
+    }
+}
+
+/// Value computed by the interpreter and the stack VM: an integer (of the type `V`) or a double
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Number<V> {
+    Int(V),
+    Double(f64),
+}
+
+impl<V> Number<V> {
+    pub fn ty(&self) -> Type {
+        match self {
+            Number::Int(_) => Type::Int,
+            Number::Double(_) => Type::Double,
+        }
+    }
+}
+
+/// Doubles are printed with a single digit after the decimal point, as with `printf("%.1f")` in the runtime of LLVM
+/// (so -0.0 keeps its sign, and infinities are printed as `inf` or `-inf`), except that NaN is printed as `nan`
+/// regardless of its sign bit
+impl<V: fmt::Display> fmt::Display for Number<V> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Number::Int(val) => val.fmt(f),
+            Number::Double(val) if val.is_nan() => write!(f, "nan"),
+            Number::Double(val) => {
+                let sign = if val.is_sign_negative() { "-" } else { "" };
+                match val.abs() {
+                    abs if abs.is_infinite() => write!(f, "{}inf", sign),
+                    abs => write!(f, "{}{:.1}", sign, abs),
+                }
+            },
+        }
+    }
+}
+
+/// Reads integers for `read()` in the interpreter, stack VM and JIT, in the same way as the runtimes
+/// of compiled programs: whitespace is skipped, then an optional sign and decimal digits are read
+/// (wrapping around on overflow of i64, narrower values are wrapped around by the reader's caller).
+/// Reading stops at the first character which can't be a part of the integer, so at the end of the input,
+/// or when the next character is not a digit, 0 is returned.
+pub struct IntReader<R: BufRead> {
+    input: R,
+}
+
+impl<R: BufRead> IntReader<R> {
+    pub fn new(input: R) -> IntReader<R> {
+        IntReader { input }
+    }
+
+    /// next byte of the input, without consuming it (input errors are treated as its end)
+    fn peek(&mut self) -> Option<u8> {
+        self.input.fill_buf().ok()?.first().copied()
+    }
+
+    /// reads the sign and digits of the next integer
+    fn read_digits(&mut self) -> (bool, Vec<u8>) {
+        while let Some(b' ' | b'\t'..=b'\r') = self.peek() {
+            self.input.consume(1);
+        }
+        let negative = self.peek() == Some(b'-');
+        if let Some(b'-' | b'+') = self.peek() {
+            self.input.consume(1);
+        }
+        let mut digits = vec![];
+        while let Some(digit @ b'0'..=b'9') = self.peek() {
+            digits.push(digit);
+            self.input.consume(1);
+        }
+        (negative, digits)
+    }
+
+    pub fn read(&mut self) -> i64 {
+        let (negative, digits) = self.read_digits();
+        let result = digits.iter()
+            .fold(0i64, |result, digit| result.wrapping_mul(10).wrapping_add((digit - b'0') as i64));
+        if negative { result.wrapping_neg() } else { result }
+    }
+
+    /// reads the next integer without any limit of its size
+    pub fn read_big(&mut self) -> BigInt {
+        let (negative, digits) = self.read_digits();
+        if digits.is_empty() {
+            return BigInt::default();
+        }
+        let result: BigInt = String::from_utf8(digits).unwrap().parse().unwrap();
+        if negative { -&result } else { result }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{BigInt, IntReader, IntWidth, Number};
+
+    #[test]
+    fn reads_integers_until_invalid_input() {
+        let mut reader = IntReader::new("  12\n-7\t+3 2147483648 -2147483648 - 5 8x 9".as_bytes());
+        let values: Vec<i64> = (0..9).map(|_| IntWidth::I32.wrap(reader.read())).collect();
+        // a sign without digits is read as 0, and nothing can be read after a character which is not a digit
+        assert_eq!(values, vec![12, -7, 3, -2147483648, -2147483648, 0, 5, 8, 0]);
+        assert_eq!(reader.read(), 0);
+    }
+
+    #[test]
+    fn reads_integers_wrapping_around_on_overflow_of_i64() {
+        let mut reader = IntReader::new("9223372036854775807 9223372036854775808 -9223372036854775808 5000000000".as_bytes());
+        let values: Vec<i64> = (0..4).map(|_| reader.read()).collect();
+        assert_eq!(values, vec![i64::MAX, i64::MIN, i64::MIN, 5_000_000_000]);
+        assert_eq!(IntWidth::I32.wrap(5_000_000_000), 705_032_704);
+    }
+
+    #[test]
+    fn reads_integers_of_any_size() {
+        let mut reader = IntReader::new(" 123456789012345678901234567890\n-99999999999999999999 +5 - 7".as_bytes());
+        let values: Vec<String> = (0..5).map(|_| reader.read_big().to_string()).collect();
+        assert_eq!(values, vec!["123456789012345678901234567890", "-99999999999999999999", "5", "0", "7"]);
+        assert_eq!(reader.read_big(), BigInt::default());
+    }
+
+    #[test]
+    fn prints_doubles_with_a_single_digit_after_the_point() {
+        let printed: Vec<String> = [2.5, 0.25, 0.35, -0.04, 1e20, f64::NEG_INFINITY, -f64::NAN, f64::NAN].iter()
+            .map(|val| Number::<i64>::Double(*val).to_string())
+            .collect();
+        // the exact binary value is rounded, so 0.35 (slightly less than its decimal) is rounded down, as in C
+        assert_eq!(printed, vec!["2.5", "0.2", "0.3", "-0.0", "100000000000000000000.0", "-inf", "nan", "nan"]);
+        assert_eq!(Number::<i64>::Int(-7).to_string(), "-7");
+    }
 }
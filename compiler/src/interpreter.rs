@@ -0,0 +1,572 @@
+use instant_parser::ast::{self, Type};
+use crate::bigint::{BigInt, MAX_BIG_BITS};
+use crate::common::{IntWidth, Number, RuntimeError, MAX_CALL_DEPTH};
+use crate::types::{binary_type, join};
+
+use std::collections::HashMap;
+
+/// Executes Instant programs directly on the abstract syntax tree.
+/// Arithmetic follows JVM semantics (for int or long values, depending on the integer width):
+/// operations wrap around on overflow, MIN / -1 == MIN, MIN % -1 == 0, division (and remainder) by zero
+/// results in an error, and shifts use only the lowest 5 (or 6) bits of the shift amount.
+/// Unbounded integers follow `java.math.BigInteger` instead, and doubles follow IEEE 754 (as in JVM and LLVM).
+/// Executing a statement or evaluating an expression only schedules tasks for its parts, so that all pending work
+/// (including the environments of callers) is kept on the heap, and the depth of nested calls is not limited
+/// by the native stack.
+enum Task<'p, V> {
+    /// executes the statement
+    Execute(&'p ast::Stmt),
+    /// evaluates the expression, pushing its value to the stack of values
+    Evaluate(&'p ast::Expr),
+    /// pops the value of an expression statement
+    Discard,
+    /// pops the value assigned to the variable
+    Assign(&'p str),
+    /// pops values of the print statement and prints them
+    Print(usize),
+    /// removes variables assigned first in the block, given the number of variables declared before it
+    EndBlock(usize),
+    /// pops the condition of the loop, and executes its body followed by the loop again if the condition holds
+    Loop { cond: &'p ast::Expr, body: &'p [Box<ast::Stmt>] },
+    Binary(ast::Opcode),
+    Neg,
+    Convert(Type),
+    /// pops the condition of the conditional expression of the given type, and evaluates one of its branches
+    Branch { then: &'p ast::Expr, otherwise: &'p ast::Expr, ty: Type },
+    /// converts the value of a branch to the type of the conditional expression
+    Join(Type),
+    /// pops the arguments and calls the function
+    Call(&'p ast::Function),
+    /// end of the body of a called function, with the environment of the caller - reached when the function
+    /// doesn't return explicitly, so it results in 0
+    EndCall(Environment<V>),
+    /// pops the result of the function, and returns from it
+    Return,
+}
+
+/// Integers the interpreter computes on: i64 holding integers of a fixed width, or `BigInt` for unbounded integers
+pub trait Value: Clone {
+    fn from_i64(val: i64) -> Self;
+    /// value of a literal which doesn't fit in i64, wrapped around to the width
+    /// (such literals are rejected when the program is lowered, unless integers are unbounded)
+    fn from_digits(digits: &str, int_width: IntWidth) -> Self;
+    /// value of `read()`, wrapped around to the width
+    fn wrap(self, int_width: IntWidth) -> Self;
+    fn is_zero(&self) -> bool;
+    fn neg(&self, int_width: IntWidth) -> Result<Self, RuntimeError>;
+    fn binary(op: ast::Opcode, lhs: &Self, rhs: &Self, int_width: IntWidth) -> Result<Self, RuntimeError>;
+    fn to_f64(&self) -> f64;
+    /// integer truncated from the double, saturating at the limits of the width (NaN is converted to 0)
+    fn from_f64(val: f64, int_width: IntWidth) -> Self;
+}
+
+/// Values of the variables visible in the executed function, with names of the variables in the order
+/// of their first assignment, so that a block can remove the ones assigned first inside it
+struct Environment<V> {
+    values: HashMap<String, Number<V>>,
+    declared: Vec<String>,
+}
+
+impl<V> Environment<V> {
+    fn new() -> Self {
+        Environment { values: HashMap::new(), declared: vec![] }
+    }
+
+    fn get(&self, var: &str) -> Option<&Number<V>> {
+        self.values.get(var)
+    }
+
+    fn insert(&mut self, var: &str, val: Number<V>) {
+        if self.values.insert(var.to_string(), val).is_none() {
+            self.declared.push(var.to_string());
+        }
+    }
+}
+
+/// Types of the conditional expressions of the program (by their addresses), computed once before it is executed -
+/// only one branch is evaluated, and its value is converted to a double if the other branch results in a double
+struct ConditionalTypes<'p> {
+    types: HashMap<*const ast::Expr, Type>,
+    /// types of the variables visible in the checked statement, in the order of their first assignment
+    variables: HashMap<&'p str, Type>,
+    declared: Vec<&'p str>,
+}
+
+impl<'p> ConditionalTypes<'p> {
+    fn of_program(program: &'p ast::Prog) -> HashMap<*const ast::Expr, Type> {
+        let mut conditionals = ConditionalTypes { types: HashMap::new(), variables: HashMap::new(), declared: vec![] };
+        conditionals.check_body(&[], &program.stmts);
+        for function in program.functions.iter() {
+            conditionals.check_body(&function.params, &function.body);
+        }
+        conditionals.types
+    }
+
+    /// checks the body of the main program or of a function, in which only the parameters (integers) are visible
+    fn check_body(&mut self, params: &'p [String], stmts: &'p [Box<ast::Stmt>]) {
+        self.variables = params.iter().map(|param| (param.as_str(), Type::Int)).collect();
+        self.declared.clear();
+        self.check_stmts(stmts);
+    }
+
+    /// checks statements of a block, variables first assigned in it are not visible after it
+    fn check_block(&mut self, stmts: &'p [Box<ast::Stmt>]) {
+        let block_start = self.declared.len();
+        self.check_stmts(stmts);
+        for var in self.declared.drain(block_start..) {
+            self.variables.remove(var);
+        }
+    }
+
+    fn check_stmts(&mut self, stmts: &'p [Box<ast::Stmt>]) {
+        for stmt in stmts.iter() {
+            match stmt.as_ref() {
+                ast::Stmt::Expr { expr } | ast::Stmt::Return { expr } => {
+                    self.check_expr(expr);
+                },
+                ast::Stmt::Decl { var, expr } => {
+                    let ty = self.check_expr(expr);
+                    if !self.variables.contains_key(var.as_str()) {
+                        self.variables.insert(var, ty);
+                        self.declared.push(var);
+                    }
+                },
+                ast::Stmt::Block { stmts } => self.check_block(stmts),
+                ast::Stmt::While { cond, body } => {
+                    self.check_expr(cond);
+                    self.check_block(body);
+                },
+                ast::Stmt::Print { exprs } => {
+                    for expr in exprs.iter() {
+                        self.check_expr(expr);
+                    }
+                },
+            }
+        }
+    }
+
+    /// type of the expression, recording types of the conditional expressions in it
+    fn check_expr(&mut self, expr: &'p ast::Expr) -> Type {
+        match expr {
+            // only bitwise operations and shifts on doubles are errors, and they result in integers
+            ast::Expr::Binary { left, op, right } => {
+                let (lhs, rhs) = (self.check_expr(left), self.check_expr(right));
+                binary_type(*op, lhs, rhs).unwrap_or(Type::Int)
+            },
+            ast::Expr::If { cond, then, otherwise } => {
+                self.check_expr(cond);
+                let ty = join(self.check_expr(then), self.check_expr(otherwise));
+                self.types.insert(expr, ty);
+                ty
+            },
+            ast::Expr::Neg { expr } => self.check_expr(expr),
+            ast::Expr::Convert { to, expr } => {
+                self.check_expr(expr);
+                *to
+            },
+            ast::Expr::Call { args, .. } => {
+                for arg in args.iter() {
+                    self.check_expr(arg);
+                }
+                Type::Int
+            },
+            ast::Expr::Number { .. } | ast::Expr::BigNumber { .. } | ast::Expr::Read => Type::Int,
+            ast::Expr::Double { .. } => Type::Double,
+            // variables are checked when the program is lowered to IR
+            ast::Expr::Variable { var } => self.variables[var.as_str()],
+        }
+    }
+}
+
+/// State of the execution: tasks left to be done, values of the evaluated expressions, and variables
+/// of the function being executed
+struct Interpreter<'p, 'o, V> {
+    functions: HashMap<&'p str, &'p ast::Function>,
+    conditional_types: HashMap<*const ast::Expr, Type>,
+    int_width: IntWidth,
+    input: &'o mut dyn FnMut() -> V,
+    output: &'o mut dyn FnMut(Number<V>, bool),
+    tasks: Vec<Task<'p, V>>,
+    values: Vec<Number<V>>,
+    env: Environment<V>,
+    call_depth: usize,
+}
+
+/// evaluates a single arithmetic operation on values of the given width, also used to fold constants during compilation -
+/// results of operations on unbounded integers which don't fit in i64 are reported as `IntegerOverflow`
+pub fn evaluate_binary(op: ast::Opcode, lhs: i64, rhs: i64, int_width: IntWidth) -> Result<i64, RuntimeError> {
+    if int_width == IntWidth::Unbounded {
+        let result = evaluate_big_binary(op, &BigInt::from(lhs), &BigInt::from(rhs))?;
+        return result.to_i64().ok_or(RuntimeError::IntegerOverflow);
+    }
+    let shift = (rhs as u32) & (int_width.bits() - 1);
+    let result = match op {
+        ast::Opcode::Add => lhs.wrapping_add(rhs),
+        ast::Opcode::Sub => lhs.wrapping_sub(rhs),
+        ast::Opcode::Mul => lhs.wrapping_mul(rhs),
+        ast::Opcode::Div | ast::Opcode::Rem if rhs == 0 => return Err(RuntimeError::DivisionByZero),
+        ast::Opcode::Div => lhs.wrapping_div(rhs),
+        ast::Opcode::Rem => lhs.wrapping_rem(rhs),
+        ast::Opcode::And => lhs & rhs,
+        ast::Opcode::Or => lhs | rhs,
+        ast::Opcode::Xor => lhs ^ rhs,
+        ast::Opcode::Shl => lhs.wrapping_shl(shift),
+        // values narrower than i64 are sign-extended, so the arithmetic shift gives the same bits
+        ast::Opcode::Shr => lhs.wrapping_shr(shift),
+        ast::Opcode::Eq => (lhs == rhs) as i64,
+        ast::Opcode::Ne => (lhs != rhs) as i64,
+        ast::Opcode::Lt => (lhs < rhs) as i64,
+        ast::Opcode::Le => (lhs <= rhs) as i64,
+        ast::Opcode::Gt => (lhs > rhs) as i64,
+        ast::Opcode::Ge => (lhs >= rhs) as i64,
+    };
+    Ok(int_width.wrap(result))
+}
+
+/// negates the value of the given width, in the same way as `evaluate_binary`
+pub fn evaluate_neg(val: i64, int_width: IntWidth) -> Result<i64, RuntimeError> {
+    match int_width {
+        IntWidth::Unbounded => val.checked_neg().ok_or(RuntimeError::IntegerOverflow),
+        _ => Ok(int_width.wrap(val.wrapping_neg())),
+    }
+}
+
+/// evaluates a single arithmetic operation (or comparison) on doubles, division by zero results in an infinity
+/// or NaN, and the remainder has the sign of the dividend (as `fmod` in C)
+pub fn evaluate_double_binary<V: Value>(op: ast::Opcode, lhs: f64, rhs: f64) -> Number<V> {
+    let result = match op {
+        ast::Opcode::Add => lhs + rhs,
+        ast::Opcode::Sub => lhs - rhs,
+        ast::Opcode::Mul => lhs * rhs,
+        ast::Opcode::Div => lhs / rhs,
+        ast::Opcode::Rem => lhs % rhs,
+        ast::Opcode::Eq => return Number::Int(V::from_i64((lhs == rhs) as i64)),
+        ast::Opcode::Ne => return Number::Int(V::from_i64((lhs != rhs) as i64)),
+        ast::Opcode::Lt => return Number::Int(V::from_i64((lhs < rhs) as i64)),
+        ast::Opcode::Le => return Number::Int(V::from_i64((lhs <= rhs) as i64)),
+        ast::Opcode::Gt => return Number::Int(V::from_i64((lhs > rhs) as i64)),
+        ast::Opcode::Ge => return Number::Int(V::from_i64((lhs >= rhs) as i64)),
+        // bitwise operations and shifts of doubles are rejected when the program is lowered
+        ast::Opcode::And | ast::Opcode::Or | ast::Opcode::Xor | ast::Opcode::Shl | ast::Opcode::Shr => unreachable!(),
+    };
+    Number::Double(result)
+}
+
+fn to_double<V: Value>(val: Number<V>) -> f64 {
+    match val {
+        Number::Int(val) => val.to_f64(),
+        Number::Double(val) => val,
+    }
+}
+
+/// converts the value to the type (doubles are truncated to integers of the width, as with `Value::from_f64`)
+fn convert<V: Value>(val: Number<V>, to: Type, int_width: IntWidth) -> Number<V> {
+    match (val, to) {
+        (Number::Double(val), Type::Int) => Number::Int(V::from_f64(val, int_width)),
+        (val, Type::Double) => Number::Double(to_double(val)),
+        (val, Type::Int) => val,
+    }
+}
+
+/// whether the condition is not 0 (conditions are integers, which is checked when the program is lowered)
+fn holds<V: Value>(cond: &Number<V>) -> bool {
+    match cond {
+        Number::Int(val) => !val.is_zero(),
+        Number::Double(val) => *val != 0.0,
+    }
+}
+
+/// evaluates a single arithmetic operation on unbounded integers
+pub fn evaluate_big_binary(op: ast::Opcode, lhs: &BigInt, rhs: &BigInt) -> Result<BigInt, RuntimeError> {
+    // the shift amount is given by the lowest 32 bits, as in `BigInteger.intValue()`
+    let shift = rhs.wrapping_to_i64() as i32 as i64;
+    let left_shift = match op {
+        ast::Opcode::Shl => shift,
+        ast::Opcode::Shr => -shift,
+        _ => 0,
+    };
+    if left_shift > 0 && !lhs.is_zero() && lhs.bit_length() + left_shift as u64 >= MAX_BIG_BITS {
+        return Err(RuntimeError::ShiftOverflow);
+    }
+    let result = match op {
+        ast::Opcode::Add => lhs + rhs,
+        ast::Opcode::Sub => lhs - rhs,
+        ast::Opcode::Mul => lhs * rhs,
+        ast::Opcode::Div => lhs.div_rem(rhs).ok_or(RuntimeError::DivisionByZero)?.0,
+        ast::Opcode::Rem => lhs.div_rem(rhs).ok_or(RuntimeError::DivisionByZero)?.1,
+        ast::Opcode::And => lhs & rhs,
+        ast::Opcode::Or => lhs | rhs,
+        ast::Opcode::Xor => lhs ^ rhs,
+        ast::Opcode::Shl => lhs << shift,
+        ast::Opcode::Shr => lhs >> shift,
+        ast::Opcode::Eq => BigInt::from((lhs == rhs) as i64),
+        ast::Opcode::Ne => BigInt::from((lhs != rhs) as i64),
+        ast::Opcode::Lt => BigInt::from((lhs < rhs) as i64),
+        ast::Opcode::Le => BigInt::from((lhs <= rhs) as i64),
+        ast::Opcode::Gt => BigInt::from((lhs > rhs) as i64),
+        ast::Opcode::Ge => BigInt::from((lhs >= rhs) as i64),
+    };
+    Ok(result)
+}
+
+impl Value for i64 {
+    fn from_i64(val: i64) -> Self {
+        val
+    }
+
+    fn from_digits(digits: &str, int_width: IntWidth) -> Self {
+        int_width.wrap(digits.parse::<BigInt>().unwrap().wrapping_to_i64())
+    }
+
+    fn wrap(self, int_width: IntWidth) -> Self {
+        int_width.wrap(self)
+    }
+
+    fn is_zero(&self) -> bool {
+        *self == 0
+    }
+
+    fn neg(&self, int_width: IntWidth) -> Result<Self, RuntimeError> {
+        evaluate_neg(*self, int_width)
+    }
+
+    fn binary(op: ast::Opcode, lhs: &Self, rhs: &Self, int_width: IntWidth) -> Result<Self, RuntimeError> {
+        evaluate_binary(op, *lhs, *rhs, int_width)
+    }
+
+    fn to_f64(&self) -> f64 {
+        *self as f64
+    }
+
+    fn from_f64(val: f64, int_width: IntWidth) -> Self {
+        // conversions with `as` saturate, and convert NaN to 0
+        match int_width {
+            IntWidth::I32 => val as i32 as i64,
+            _ => val as i64,
+        }
+    }
+}
+
+impl Value for BigInt {
+    fn from_i64(val: i64) -> Self {
+        BigInt::from(val)
+    }
+
+    fn from_digits(digits: &str, _int_width: IntWidth) -> Self {
+        digits.parse().unwrap()
+    }
+
+    fn wrap(self, _int_width: IntWidth) -> Self {
+        self
+    }
+
+    fn is_zero(&self) -> bool {
+        BigInt::is_zero(self)
+    }
+
+    fn neg(&self, _int_width: IntWidth) -> Result<Self, RuntimeError> {
+        Ok(-self)
+    }
+
+    fn binary(op: ast::Opcode, lhs: &Self, rhs: &Self, _int_width: IntWidth) -> Result<Self, RuntimeError> {
+        evaluate_big_binary(op, lhs, rhs)
+    }
+
+    // doubles are rejected together with unbounded integers when the program is lowered
+    fn to_f64(&self) -> f64 {
+        unreachable!()
+    }
+
+    fn from_f64(_val: f64, _int_width: IntWidth) -> Self {
+        unreachable!()
+    }
+}
+
+impl<'p, V: Value> Interpreter<'p, '_, V> {
+    fn pop(&mut self) -> Number<V> {
+        self.values.pop().expect("value stack underflow")
+    }
+
+    /// pops the given number of values, in the order they were pushed
+    fn pop_values(&mut self, count: usize) -> Vec<Number<V>> {
+        self.values.split_off(self.values.len() - count)
+    }
+
+    /// schedules statements to be executed in order
+    fn execute_stmts(&mut self, stmts: &'p [Box<ast::Stmt>]) {
+        self.tasks.extend(stmts.iter().rev().map(|stmt| Task::Execute(stmt)));
+    }
+
+    /// schedules statements of a block, variables first assigned in the block are removed after it
+    fn execute_block(&mut self, stmts: &'p [Box<ast::Stmt>]) {
+        self.tasks.push(Task::EndBlock(self.env.declared.len()));
+        self.execute_stmts(stmts);
+    }
+
+    /// schedules expressions to be evaluated in order, so that their values are pushed in the same order
+    fn evaluate_exprs(&mut self, exprs: &'p [Box<ast::Expr>]) {
+        self.tasks.extend(exprs.iter().rev().map(|expr| Task::Evaluate(expr)));
+    }
+
+    fn execute(&mut self, stmt: &'p ast::Stmt) {
+        match stmt {
+            ast::Stmt::Expr { expr } => self.tasks.extend([Task::Discard, Task::Evaluate(expr)]),
+            ast::Stmt::Decl { var, expr } => self.tasks.extend([Task::Assign(var), Task::Evaluate(expr)]),
+            ast::Stmt::Block { stmts } => self.execute_block(stmts),
+            ast::Stmt::While { cond, body } => self.tasks.extend([Task::Loop { cond, body }, Task::Evaluate(cond)]),
+            ast::Stmt::Return { expr } => self.tasks.extend([Task::Return, Task::Evaluate(expr)]),
+            ast::Stmt::Print { exprs } => {
+                self.tasks.push(Task::Print(exprs.len()));
+                self.evaluate_exprs(exprs);
+            },
+        }
+    }
+
+    fn evaluate(&mut self, expr: &'p ast::Expr) {
+        match expr {
+            ast::Expr::Binary { left, op, right } => {
+                self.tasks.extend([Task::Binary(*op), Task::Evaluate(right), Task::Evaluate(left)]);
+            },
+            ast::Expr::If { cond, then, otherwise } => {
+                let ty = self.conditional_types[&(expr as *const ast::Expr)];
+                self.tasks.extend([Task::Branch { then, otherwise, ty }, Task::Evaluate(cond)]);
+            },
+            ast::Expr::Neg { expr } => self.tasks.extend([Task::Neg, Task::Evaluate(expr)]),
+            ast::Expr::Convert { to, expr } => self.tasks.extend([Task::Convert(*to), Task::Evaluate(expr)]),
+            // functions and their arity are checked when the program is lowered to IR
+            ast::Expr::Call { function, args } => {
+                self.tasks.push(Task::Call(self.functions[function.as_str()]));
+                self.evaluate_exprs(args);
+            },
+            ast::Expr::Number { val } => self.values.push(Number::Int(V::from_i64(*val))),
+            ast::Expr::BigNumber { digits } => self.values.push(Number::Int(V::from_digits(digits, self.int_width))),
+            ast::Expr::Double { val } => self.values.push(Number::Double(*val)),
+            // variables are checked before execution, when the program is lowered to IR as well
+            ast::Expr::Variable { var } => self.values.push(self.env.values[var].clone()),
+            ast::Expr::Read => {
+                let val = (self.input)().wrap(self.int_width);
+                self.values.push(Number::Int(val));
+            },
+        }
+    }
+
+    /// executes the scheduled tasks until there are none left
+    fn run(&mut self) -> Result<(), RuntimeError> {
+        while let Some(task) = self.tasks.pop() {
+            match task {
+                Task::Execute(stmt) => self.execute(stmt),
+                Task::Evaluate(expr) => self.evaluate(expr),
+                Task::Discard => {
+                    self.pop();
+                },
+                Task::Assign(var) => {
+                    let mut val = self.pop();
+                    // the type of a variable is given by its first assignment, integers assigned later to a double are converted
+                    if let Some(previous) = self.env.get(var) {
+                        val = convert(val, previous.ty(), self.int_width);
+                    }
+                    self.env.insert(var, val);
+                },
+                Task::Print(count) => {
+                    for (index, val) in self.pop_values(count).into_iter().enumerate() {
+                        (self.output)(val, index == count - 1);
+                    }
+                },
+                Task::EndBlock(block_start) => {
+                    for var in self.env.declared.drain(block_start..) {
+                        self.env.values.remove(&var);
+                    }
+                },
+                Task::Loop { cond, body } => {
+                    if holds(&self.pop()) {
+                        self.tasks.extend([Task::Loop { cond, body }, Task::Evaluate(cond)]);
+                        self.execute_block(body);
+                    }
+                },
+                Task::Binary(op) => {
+                    let rhs = self.pop();
+                    let lhs = self.pop();
+                    let val = match (lhs, rhs) {
+                        (Number::Int(lhs), Number::Int(rhs)) => Number::Int(V::binary(op, &lhs, &rhs, self.int_width)?),
+                        (lhs, rhs) => evaluate_double_binary(op, to_double(lhs), to_double(rhs)),
+                    };
+                    self.values.push(val);
+                },
+                Task::Neg => {
+                    let val = match self.pop() {
+                        Number::Int(val) => Number::Int(val.neg(self.int_width)?),
+                        Number::Double(val) => Number::Double(-val),
+                    };
+                    self.values.push(val);
+                },
+                Task::Convert(to) => {
+                    let val = convert(self.pop(), to, self.int_width);
+                    self.values.push(val);
+                },
+                Task::Branch { then, otherwise, ty } => {
+                    let branch = if holds(&self.pop()) { then } else { otherwise };
+                    self.tasks.extend([Task::Join(ty), Task::Evaluate(branch)]);
+                },
+                Task::Join(ty) => {
+                    let val = convert(self.pop(), ty, self.int_width);
+                    self.values.push(val);
+                },
+                Task::Call(function) => {
+                    let mut function_env = Environment::new();
+                    for (param, arg) in function.params.iter().zip(self.pop_values(function.params.len())) {
+                        function_env.insert(param, arg);
+                    }
+                    if self.call_depth == MAX_CALL_DEPTH {
+                        return Err(RuntimeError::StackOverflow);
+                    }
+                    self.call_depth += 1;
+                    let caller_env = std::mem::replace(&mut self.env, function_env);
+                    self.tasks.push(Task::EndCall(caller_env));
+                    self.execute_stmts(&function.body);
+                },
+                Task::EndCall(caller_env) => {
+                    self.env = caller_env;
+                    self.call_depth -= 1;
+                    self.values.push(Number::Int(V::from_i64(0)));
+                },
+                Task::Return => {
+                    // tasks left in the body of the function are dropped
+                    let result = self.pop();
+                    self.env = loop {
+                        if let Task::EndCall(caller_env) = self.tasks.pop().expect("return outside of a function") {
+                            break caller_env;
+                        }
+                    };
+                    self.call_depth -= 1;
+                    self.values.push(result);
+                },
+            }
+        }
+        Ok(())
+    }
+}
+
+/// executes the program on integers of the given width (stored as i64, or as `BigInt` when they are unbounded),
+/// taking values of `read()` from `input` (wrapped around to the width) and passing every printed value to `output`,
+/// together with whether it ends the line (otherwise it is followed by a space) - the program has to be lowered first,
+/// which checks its variables, functions and types
+pub fn interpret<V: Value>(
+    program: &ast::Prog,
+    int_width: IntWidth,
+    input: &mut dyn FnMut() -> V,
+    output: &mut dyn FnMut(Number<V>, bool),
+) -> Result<(), RuntimeError> {
+    let mut interpreter = Interpreter {
+        functions: program.functions.iter().map(|function| (function.name.as_str(), function)).collect(),
+        conditional_types: ConditionalTypes::of_program(program),
+        int_width,
+        input,
+        output,
+        tasks: vec![],
+        values: vec![],
+        env: Environment::new(),
+        call_depth: 0,
+    };
+    interpreter.execute_stmts(&program.stmts);
+    interpreter.run()
+}
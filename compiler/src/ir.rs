@@ -0,0 +1,840 @@
+use instant_parser::ast::{self, Type};
+use crate::bigint::BigInt;
+use crate::common::{CompilationError, IntWidth};
+use crate::types;
+
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+
+/// Numbered temporary, each temporary is defined by exactly one operation
+pub type Temp = u32;
+/// Numbered variable, names are stored in `Program::variables`
+pub type Var = u32;
+/// Numbered label, marking the beginning of a basic block
+pub type Label = u32;
+
+/// Three-address code operation
+#[derive(Debug, Clone, PartialEq)]
+pub enum Op {
+    Const { dst: Temp, val: i64 },
+    BinOp { dst: Temp, op: ast::Opcode, lhs: Temp, rhs: Temp },
+    Neg { dst: Temp, src: Temp },
+    Load { dst: Temp, var: Var },
+    Store { var: Var, src: Temp },
+    /// prints the values in a single line, separated by spaces
+    Print { srcs: Vec<Temp> },
+    /// beginning of a basic block - blocks are entered only by jumps, except the first one
+    Label { label: Label },
+    Jump { label: Label },
+    /// jumps to `then_label` if `cond` is not 0, and to `else_label` otherwise
+    Branch { cond: Temp, then_label: Label, else_label: Label },
+    /// value of the temporary from the block (given by its label) which jumped to the current block
+    Phi { dst: Temp, incoming: Vec<(Temp, Label)> },
+    /// calls the function with the given arguments, which become its first variables
+    Call { dst: Temp, function: String, args: Vec<Temp> },
+    /// ends the call of the function, only in function bodies - the next op is a label or the end of the body
+    Return { src: Temp },
+    /// reads the next integer of the input, so reads are never removed nor reordered (just like calls)
+    Read { dst: Temp },
+    DoubleConst { dst: Temp, val: f64 },
+    /// arithmetic operation or comparison of doubles, comparisons result in integers
+    DoubleBinOp { dst: Temp, op: ast::Opcode, lhs: Temp, rhs: Temp },
+    DoubleNeg { dst: Temp, src: Temp },
+    /// converts the integer to a double, or truncates the double to an integer (see `ast::Expr::Convert`)
+    Convert { dst: Temp, src: Temp, to: Type },
+}
+
+impl Op {
+    /// temporary defined by the operation
+    pub fn dst(&self) -> Option<Temp> {
+        match self {
+            Op::Const { dst, .. } | Op::BinOp { dst, .. } | Op::Neg { dst, .. } | Op::Load { dst, .. }
+            | Op::Phi { dst, .. } | Op::Call { dst, .. } | Op::Read { dst } | Op::DoubleConst { dst, .. }
+            | Op::DoubleBinOp { dst, .. } | Op::DoubleNeg { dst, .. } | Op::Convert { dst, .. } => Some(*dst),
+            Op::Store { .. } | Op::Print { .. } | Op::Label { .. } | Op::Jump { .. } | Op::Branch { .. }
+            | Op::Return { .. } => None,
+        }
+    }
+
+    /// temporaries used by the operation
+    pub fn operands(&self) -> Vec<Temp> {
+        match self {
+            Op::BinOp { lhs, rhs, .. } | Op::DoubleBinOp { lhs, rhs, .. } => vec![*lhs, *rhs],
+            Op::Neg { src, .. } | Op::Store { src, .. } | Op::Branch { cond: src, .. } | Op::Return { src }
+            | Op::DoubleNeg { src, .. } | Op::Convert { src, .. } => {
+                vec![*src]
+            },
+            Op::Phi { incoming, .. } => incoming.iter().map(|(temp, _)| *temp).collect(),
+            Op::Call { args: temps, .. } | Op::Print { srcs: temps } => temps.clone(),
+            Op::Const { .. } | Op::Load { .. } | Op::Label { .. } | Op::Jump { .. } | Op::Read { .. }
+            | Op::DoubleConst { .. } => vec![],
+        }
+    }
+
+    /// replaces every temporary used by the operation with its mapping
+    pub fn replace_operands(&mut self, mapping: &dyn Fn(Temp) -> Temp) {
+        match self {
+            Op::BinOp { lhs, rhs, .. } | Op::DoubleBinOp { lhs, rhs, .. } => {
+                *lhs = mapping(*lhs);
+                *rhs = mapping(*rhs);
+            },
+            Op::Neg { src, .. } | Op::Store { src, .. } | Op::Branch { cond: src, .. } | Op::Return { src }
+            | Op::DoubleNeg { src, .. } | Op::Convert { src, .. } => {
+                *src = mapping(*src)
+            },
+            Op::Phi { incoming, .. } => {
+                for (temp, _) in incoming.iter_mut() {
+                    *temp = mapping(*temp);
+                }
+            },
+            Op::Call { args: temps, .. } | Op::Print { srcs: temps } => {
+                for temp in temps.iter_mut() {
+                    *temp = mapping(*temp);
+                }
+            },
+            Op::Const { .. } | Op::Load { .. } | Op::Label { .. } | Op::Jump { .. } | Op::Read { .. }
+            | Op::DoubleConst { .. } => {},
+        }
+    }
+}
+
+/// Loop of the program: block starting at `header`, which is entered again by the backward jump
+/// at the end of the loop, followed by the `exit` block
+#[derive(Debug, Clone, PartialEq)]
+pub struct Loop {
+    pub header: Label,
+    pub exit: Label,
+    /// positions of the header label and the backward jump
+    pub start: usize,
+    pub end: usize,
+    /// variables stored and loaded anywhere in the loop (including nested loops)
+    pub stored: HashSet<Var>,
+    pub loaded: HashSet<Var>,
+}
+
+/// Program lowered to three-address code, shared by the stack and LLVM backends
+#[derive(Debug, Clone)]
+pub struct Program {
+    pub ops: Vec<Op>,
+    /// names of variables, indexed by `Var` - in order of their first assignment
+    pub variables: Vec<String>,
+    /// types of variables, indexed by `Var` - given by their first assignment (parameters are integers)
+    pub variable_types: Vec<Type>,
+    pub temps_count: u32,
+    pub labels_count: u32,
+    /// number of parameters, which are the first variables of a function body (0 in the main program)
+    pub params: u32,
+    /// functions defined in the main program, in order of their definitions (empty in function bodies)
+    pub functions: Vec<Function>,
+    /// width of integers, the same in the main program and its functions - constants are always in its range
+    pub int_width: IntWidth,
+}
+
+/// Function lowered to a separate program, which is entered with its parameters already stored
+#[derive(Debug, Clone)]
+pub struct Function {
+    pub name: String,
+    pub body: Program,
+}
+
+impl Program {
+    pub fn new_temp(&mut self) -> Temp {
+        self.temps_count += 1;
+        self.temps_count - 1
+    }
+
+    pub fn new_label(&mut self) -> Label {
+        self.labels_count += 1;
+        self.labels_count - 1
+    }
+
+    /// checks that every temporary is defined exactly once, before all of its uses,
+    /// and that all variables are defined - used to catch bugs in optimization passes
+    pub fn verify(&self) -> Result<(), String> {
+        for function in self.functions.iter() {
+            function.body.verify().map_err(|e| format!("{} in function {}", e, function.name))?;
+        }
+        if self.params as usize > self.variables.len() {
+            return Err(String::from("parameters are missing from variables"));
+        }
+        if self.variable_types.len() != self.variables.len() {
+            return Err(String::from("types of variables are missing"));
+        }
+        let mut defined = vec![false; self.temps_count as usize];
+        for (position, op) in self.ops.iter().enumerate() {
+            for temp in op.operands() {
+                if !defined.get(temp as usize).copied().unwrap_or(false) {
+                    return Err(format!("t{} is used before definition at op {}", temp, position));
+                }
+            }
+            if let Some(dst) = op.dst() {
+                match defined.get_mut(dst as usize) {
+                    Some(true) => return Err(format!("t{} is defined more than once", dst)),
+                    Some(is_defined) => *is_defined = true,
+                    None => return Err(format!("t{} is out of range", dst)),
+                }
+            }
+            let var = match op {
+                Op::Load { var, .. } | Op::Store { var, .. } => Some(*var),
+                _ => None,
+            };
+            if var.is_some_and(|var| var as usize >= self.variables.len()) {
+                return Err(format!("undefined variable at op {}", position));
+            }
+        }
+        Ok(())
+    }
+
+    /// loops of the program by their header labels - all jumps are forward, except for the jumps
+    /// at the end of loops, which go back to their headers
+    pub fn loops(&self) -> HashMap<Label, Loop> {
+        let mut label_positions: HashMap<Label, usize> = HashMap::new();
+        let mut loops: HashMap<Label, Loop> = HashMap::new();
+        for (position, op) in self.ops.iter().enumerate() {
+            match op {
+                Op::Label { label } => {
+                    label_positions.insert(*label, position);
+                },
+                Op::Jump { label } if label_positions.contains_key(label) => {
+                    let start = label_positions[label];
+                    let exit = match self.ops.get(position + 1) {
+                        Some(Op::Label { label }) => *label,
+                        _ => panic!("loop ending at op {} is not followed by a label", position),
+                    };
+                    let mut stored: HashSet<Var> = HashSet::new();
+                    let mut loaded: HashSet<Var> = HashSet::new();
+                    for op in self.ops[start..position].iter() {
+                        match op {
+                            Op::Store { var, .. } => stored.insert(*var),
+                            Op::Load { var, .. } => loaded.insert(*var),
+                            _ => false,
+                        };
+                    }
+                    loops.insert(*label, Loop { header: *label, exit, start, end: position, stored, loaded });
+                },
+                _ => {},
+            }
+        }
+        loops
+    }
+
+    /// type of the temporary defined by the operation, given types of the temporaries defined before it -
+    /// a phi has the type of its incoming values, which are converted to the same type by the branches
+    fn result_type(&self, op: &Op, temp_types: &[Type]) -> Type {
+        match op {
+            Op::DoubleConst { .. } | Op::DoubleNeg { .. } => Type::Double,
+            Op::DoubleBinOp { op, .. } if !op.is_comparison() => Type::Double,
+            Op::Convert { to, .. } => *to,
+            Op::Load { var, .. } => self.variable_types[*var as usize],
+            Op::Phi { incoming, .. } => temp_types[incoming[0].0 as usize],
+            _ => Type::Int,
+        }
+    }
+
+    /// types of all temporaries, indexed by `Temp`
+    pub fn temp_types(&self) -> Vec<Type> {
+        let mut temp_types = vec![Type::Int; self.temps_count as usize];
+        for op in self.ops.iter() {
+            if let Some(dst) = op.dst() {
+                temp_types[dst as usize] = self.result_type(op, &temp_types);
+            }
+        }
+        temp_types
+    }
+
+    /// whether the program or any of its functions computes on doubles
+    pub fn uses_doubles(&self) -> bool {
+        self.functions.iter().any(|function| function.body.uses_doubles())
+            || self.ops.iter().any(|op| matches!(op, Op::DoubleConst { .. } | Op::Convert { to: Type::Double, .. }))
+    }
+
+    /// number of uses of every temporary, indexed by `Temp`
+    pub fn use_counts(&self) -> Vec<u32> {
+        let mut use_counts = vec![0; self.temps_count as usize];
+        for op in self.ops.iter() {
+            for temp in op.operands() {
+                use_counts[temp as usize] += 1;
+            }
+        }
+        use_counts
+    }
+}
+
+fn opcode_name(op: &ast::Opcode) -> &'static str {
+    match op {
+        ast::Opcode::Add => "add",
+        ast::Opcode::Sub => "sub",
+        ast::Opcode::Mul => "mul",
+        ast::Opcode::Div => "div",
+        ast::Opcode::Rem => "rem",
+        ast::Opcode::And => "and",
+        ast::Opcode::Or => "or",
+        ast::Opcode::Xor => "xor",
+        ast::Opcode::Shl => "shl",
+        ast::Opcode::Shr => "shr",
+        ast::Opcode::Eq => "eq",
+        ast::Opcode::Ne => "ne",
+        ast::Opcode::Lt => "lt",
+        ast::Opcode::Le => "le",
+        ast::Opcode::Gt => "gt",
+        ast::Opcode::Ge => "ge",
+    }
+}
+
+impl fmt::Display for Program {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for function in self.functions.iter() {
+            let params = &function.body.variables[..function.body.params as usize];
+            writeln!(f, "fn {}({}):", function.name, params.join(", "))?;
+            writeln!(f, "{}", function.body)?;
+        }
+        for op in self.ops.iter() {
+            match op {
+                Op::Const { dst, val } => writeln!(f, "t{} = {}", dst, val)?,
+                Op::BinOp { dst, op, lhs, rhs } => {
+                    writeln!(f, "t{} = {} t{}, t{}", dst, opcode_name(op), lhs, rhs)?
+                },
+                Op::Neg { dst, src } => writeln!(f, "t{} = neg t{}", dst, src)?,
+                Op::Load { dst, var } => writeln!(f, "t{} = load {}", dst, self.variables[*var as usize])?,
+                Op::Store { var, src } => writeln!(f, "store {}, t{}", self.variables[*var as usize], src)?,
+                Op::Print { srcs } => {
+                    let srcs: Vec<String> = srcs.iter().map(|src| format!("t{}", src)).collect();
+                    writeln!(f, "print {}", srcs.join(", "))?
+                },
+                Op::Label { label } => writeln!(f, "L{}:", label)?,
+                Op::Jump { label } => writeln!(f, "jump L{}", label)?,
+                Op::Branch { cond, then_label, else_label } => {
+                    writeln!(f, "branch t{}, L{}, L{}", cond, then_label, else_label)?
+                },
+                Op::Phi { dst, incoming } => {
+                    let incoming: Vec<String> = incoming.iter()
+                        .map(|(temp, label)| format!("[t{}, L{}]", temp, label))
+                        .collect();
+                    writeln!(f, "t{} = phi {}", dst, incoming.join(", "))?
+                },
+                Op::Call { dst, function, args } => {
+                    let args: Vec<String> = args.iter().map(|arg| format!("t{}", arg)).collect();
+                    writeln!(f, "t{} = call {}({})", dst, function, args.join(", "))?
+                },
+                Op::Return { src } => writeln!(f, "return t{}", src)?,
+                Op::Read { dst } => writeln!(f, "t{} = read", dst)?,
+                // doubles are printed with a decimal point or an exponent, so that they differ from integers
+                Op::DoubleConst { dst, val } => writeln!(f, "t{} = {:?}", dst, val)?,
+                Op::DoubleBinOp { dst, op, lhs, rhs } => {
+                    writeln!(f, "t{} = d{} t{}, t{}", dst, opcode_name(op), lhs, rhs)?
+                },
+                Op::DoubleNeg { dst, src } => writeln!(f, "t{} = dneg t{}", dst, src)?,
+                Op::Convert { dst, src, to: Type::Int } => writeln!(f, "t{} = int t{}", dst, src)?,
+                Op::Convert { dst, src, to: Type::Double } => writeln!(f, "t{} = double t{}", dst, src)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+/// State of lowering: the program built so far with types of its temporaries, mapping of variable names
+/// to their numbers, the label of the current basic block (None in the first block), and arities of all functions
+/// (the function being lowered is None in the main program)
+pub struct Builder {
+    program: Program,
+    temp_types: Vec<Type>,
+    env: HashMap<String, Var>,
+    /// names added to the environment, removed from it when the scope declaring them ends
+    declared: Vec<String>,
+    /// number of variables declared under every name, which gives the suffix of the next one
+    name_counts: HashMap<String, usize>,
+    current_label: Option<Label>,
+    arities: HashMap<String, usize>,
+    function: Option<String>,
+}
+
+impl Builder {
+    fn new(arities: HashMap<String, usize>, function: Option<String>, int_width: IntWidth) -> Self {
+        Builder {
+            program: Program {
+                ops: vec![],
+                variables: vec![],
+                variable_types: vec![],
+                temps_count: 0,
+                labels_count: 0,
+                params: 0,
+                functions: vec![],
+                int_width,
+            },
+            temp_types: vec![],
+            env: HashMap::new(),
+            declared: vec![],
+            name_counts: HashMap::new(),
+            current_label: None,
+            arities,
+            function,
+        }
+    }
+
+    fn start_block(&mut self, label: Label) {
+        self.program.ops.push(Op::Label { label });
+        self.current_label = Some(label);
+    }
+
+    /// adds a new variable to the program and the environment, variables declared in different
+    /// scopes under the same name get unique names in the program (`x`, `x.1`, `x.2`, ...)
+    fn new_variable(&mut self, name: &str, ty: Type) -> Var {
+        // identifiers can't contain dots, so suffixed names never collide with names of other variables
+        let count = self.name_counts.entry(name.to_string()).or_insert(0);
+        let unique_name = match *count {
+            0 => name.to_string(),
+            suffix => format!("{}.{}", name, suffix),
+        };
+        *count += 1;
+        let var = self.program.variables.len() as Var;
+        self.program.variables.push(unique_name);
+        self.program.variable_types.push(ty);
+        self.env.insert(name.to_string(), var);
+        self.declared.push(name.to_string());
+        var
+    }
+
+    /// lowers statements of a block, variables first assigned in the block are not visible after it
+    fn lower_scope(&mut self, stmts: &[Box<ast::Stmt>]) -> Result<(), CompilationError> {
+        let scope_start = self.declared.len();
+        for stmt in stmts.iter() {
+            stmt.lower_ir(self)?;
+        }
+        for name in self.declared.drain(scope_start..) {
+            self.env.remove(&name);
+        }
+        Ok(())
+    }
+
+    /// adds the operation to the program, recording the type of the temporary defined by it
+    fn push(&mut self, op: Op) {
+        if let Some(dst) = op.dst() {
+            let ty = self.program.result_type(&op, &self.temp_types);
+            if self.temp_types.len() <= dst as usize {
+                self.temp_types.resize(dst as usize + 1, Type::Int);
+            }
+            self.temp_types[dst as usize] = ty;
+        }
+        self.program.ops.push(op);
+    }
+
+    /// adds the operation defining a new temporary to the program
+    fn emit(&mut self, op: impl FnOnce(Temp) -> Op) -> Temp {
+        let dst = self.program.new_temp();
+        self.push(op(dst));
+        dst
+    }
+
+    /// value of the temporary converted to the type - only integers are converted to doubles implicitly,
+    /// a double used where an integer is expected is an error
+    fn convert(&mut self, temp: Temp, to: Type) -> Result<Temp, CompilationError> {
+        match self.temp_types[temp as usize] {
+            Type::Int if to == Type::Double => Ok(self.emit(|dst| Op::Convert { dst, src: temp, to })),
+            from => types::expect(to, from).map(|_| temp),
+        }
+    }
+
+    /// checks that the temporary is an integer (a condition, an argument or a result of a function)
+    fn expect_int(&self, temp: Temp) -> Result<(), CompilationError> {
+        types::expect(Type::Int, self.temp_types[temp as usize])
+    }
+
+    fn variable_type(&self, var: &str) -> Option<Type> {
+        self.env.get(var).map(|var| self.program.variable_types[*var as usize])
+    }
+
+    /// constants of the IR are i64, so unbounded literals which don't fit in it are computed
+    /// from their 32-bit limbs, as `((l_n << 32) + l_n-1) << 32 + ...`, which is negated for negative literals
+    fn lower_big_literal(&mut self, val: &BigInt) -> Temp {
+        let mut limbs = val.magnitude().iter().rev();
+        let first_limb = *limbs.next().unwrap() as i64;
+        let mut result = self.emit(|dst| Op::Const { dst, val: first_limb });
+        for limb in limbs {
+            let amount = self.emit(|dst| Op::Const { dst, val: 32 });
+            let shifted = self.emit(|dst| Op::BinOp { dst, op: ast::Opcode::Shl, lhs: result, rhs: amount });
+            let limb = self.emit(|dst| Op::Const { dst, val: *limb as i64 });
+            result = self.emit(|dst| Op::BinOp { dst, op: ast::Opcode::Add, lhs: shifted, rhs: limb });
+        }
+        if val.is_negative() {
+            result = self.emit(|dst| Op::Neg { dst, src: result });
+        }
+        result
+    }
+
+    /// lowers the expression in a new block, which jumps to `end_label`,
+    /// returns its value (converted to the type) and the label of the block that jumps to `end_label`
+    fn lower_block(
+        &mut self, expr: &ast::Expr, ty: Type, label: Label, end_label: Label
+    ) -> Result<(Temp, Label), CompilationError> {
+        self.start_block(label);
+        let value = expr.lower_ir(self)?;
+        let value = self.convert(value, ty)?;
+        let last_label = self.current_label.unwrap();
+        self.program.ops.push(Op::Jump { label: end_label });
+        Ok((value, last_label))
+    }
+}
+
+pub trait LowerStmt {
+    fn lower_ir(&self, builder: &mut Builder) -> Result<(), CompilationError>;
+}
+
+pub trait LowerExpr {
+    fn lower_ir(&self, builder: &mut Builder) -> Result<Temp, CompilationError>;
+}
+
+impl LowerStmt for ast::Prog {
+    fn lower_ir(&self, builder: &mut Builder) -> Result<(), CompilationError> {
+        for stmt in self.stmts.iter() {
+            stmt.lower_ir(builder)?;
+        }
+        Ok(())
+    }
+}
+
+impl LowerStmt for ast::Stmt {
+    fn lower_ir(&self, builder: &mut Builder) -> Result<(), CompilationError> {
+        match self {
+            // the value is unused, but the expression may still call functions, read the input or fail
+            ast::Stmt::Expr { expr } => {
+                expr.lower_ir(builder)?;
+            },
+            ast::Stmt::Print { exprs } => {
+                let mut srcs = vec![];
+                for expr in exprs.iter() {
+                    srcs.push(expr.lower_ir(builder)?);
+                }
+                builder.program.ops.push(Op::Print { srcs });
+            },
+            ast::Stmt::Decl { var, expr } => {
+                // expression is lowered first, so that the variable is undefined inside it
+                let src = expr.lower_ir(builder)?;
+                let (var, src) = match builder.env.get(var) {
+                    Some(existing_var) => {
+                        let existing_var = *existing_var;
+                        let ty = builder.program.variable_types[existing_var as usize];
+                        (existing_var, builder.convert(src, ty)?)
+                    },
+                    None => (builder.new_variable(var, builder.temp_types[src as usize]), src),
+                };
+                builder.program.ops.push(Op::Store { var, src });
+            },
+            ast::Stmt::Block { stmts } => {
+                builder.lower_scope(stmts)?;
+            },
+            ast::Stmt::Return { expr } => {
+                if builder.function.is_none() {
+                    return Err(CompilationError::ReturnOutsideFunction);
+                }
+                let src = expr.lower_ir(builder)?;
+                builder.expect_int(src)?;
+                builder.program.ops.push(Op::Return { src });
+                // statements after the return are unreachable, but they are still lowered to a new block
+                let label = builder.program.new_label();
+                builder.start_block(label);
+            },
+            ast::Stmt::While { cond, body } => {
+                let header_label = builder.program.new_label();
+                let body_label = builder.program.new_label();
+                let exit_label = builder.program.new_label();
+                builder.program.ops.push(Op::Jump { label: header_label });
+                builder.start_block(header_label);
+                let cond = cond.lower_ir(builder)?;
+                builder.expect_int(cond)?;
+                builder.program.ops.push(Op::Branch { cond, then_label: body_label, else_label: exit_label });
+                builder.start_block(body_label);
+                builder.lower_scope(body)?;
+                builder.program.ops.push(Op::Jump { label: header_label });
+                builder.start_block(exit_label);
+            },
+        }
+        Ok(())
+    }
+}
+
+impl LowerExpr for ast::Expr {
+    fn lower_ir(&self, builder: &mut Builder) -> Result<Temp, CompilationError> {
+        let op = match self {
+            ast::Expr::Binary { left, op, right } => {
+                let lhs = left.lower_ir(builder)?;
+                let rhs = right.lower_ir(builder)?;
+                let (lhs_type, rhs_type) = (builder.temp_types[lhs as usize], builder.temp_types[rhs as usize]);
+                types::binary_type(*op, lhs_type, rhs_type)?;
+                if lhs_type == Type::Int && rhs_type == Type::Int {
+                    Op::BinOp { dst: builder.program.new_temp(), op: *op, lhs, rhs }
+                } else {
+                    let lhs = builder.convert(lhs, Type::Double)?;
+                    let rhs = builder.convert(rhs, Type::Double)?;
+                    Op::DoubleBinOp { dst: builder.program.new_temp(), op: *op, lhs, rhs }
+                }
+            },
+            ast::Expr::If { cond, then, otherwise } => {
+                // type of the result is known before the branches are lowered, so that they can convert their values
+                let ty = types::infer(self, &|var| builder.variable_type(var))?;
+                let cond = cond.lower_ir(builder)?;
+                builder.expect_int(cond)?;
+                let then_label = builder.program.new_label();
+                let else_label = builder.program.new_label();
+                let end_label = builder.program.new_label();
+                builder.program.ops.push(Op::Branch { cond, then_label, else_label });
+                let then_value = builder.lower_block(then, ty, then_label, end_label)?;
+                let else_value = builder.lower_block(otherwise, ty, else_label, end_label)?;
+                builder.start_block(end_label);
+                Op::Phi { dst: builder.program.new_temp(), incoming: vec![then_value, else_value] }
+            },
+            ast::Expr::Neg { expr } => {
+                let src = expr.lower_ir(builder)?;
+                match builder.temp_types[src as usize] {
+                    Type::Int => Op::Neg { dst: builder.program.new_temp(), src },
+                    Type::Double => Op::DoubleNeg { dst: builder.program.new_temp(), src },
+                }
+            },
+            ast::Expr::Number { val } => {
+                if !builder.program.int_width.contains(*val) {
+                    return Err(CompilationError::LiteralOutOfRange { literal: val.to_string() });
+                }
+                Op::Const { dst: builder.program.new_temp(), val: *val }
+            },
+            ast::Expr::BigNumber { digits } => {
+                if builder.program.int_width != IntWidth::Unbounded {
+                    return Err(CompilationError::LiteralOutOfRange { literal: digits.clone() });
+                }
+                return Ok(builder.lower_big_literal(&digits.parse().unwrap()));
+            },
+            ast::Expr::Double { .. } if builder.program.int_width == IntWidth::Unbounded => {
+                return Err(CompilationError::UnsupportedDoubles);
+            },
+            // literals with too many digits are parsed as infinity
+            ast::Expr::Double { val } if !val.is_finite() => {
+                return Err(CompilationError::LiteralOutOfRange { literal: val.to_string() });
+            },
+            ast::Expr::Double { val } => Op::DoubleConst { dst: builder.program.new_temp(), val: *val },
+            ast::Expr::Variable { var } => {
+                match builder.env.get(var) {
+                    Some(var) => Op::Load { dst: builder.program.new_temp(), var: *var },
+                    None => {
+                        return Err(CompilationError::UnidentifiedVariable { identifier: var.clone() });
+                    },
+                }
+            },
+            ast::Expr::Call { function, args } => {
+                let expected = match builder.arities.get(function) {
+                    Some(arity) => *arity,
+                    None => return Err(CompilationError::UndefinedFunction { name: function.clone() }),
+                };
+                if args.len() != expected {
+                    return Err(CompilationError::ArityMismatch {
+                        function: function.clone(),
+                        expected,
+                        found: args.len(),
+                    });
+                }
+                let mut arg_temps = vec![];
+                for arg in args.iter() {
+                    let arg = arg.lower_ir(builder)?;
+                    builder.expect_int(arg)?;
+                    arg_temps.push(arg);
+                }
+                Op::Call { dst: builder.program.new_temp(), function: function.clone(), args: arg_temps }
+            },
+            ast::Expr::Read => Op::Read { dst: builder.program.new_temp() },
+            ast::Expr::Convert { to: Type::Double, .. } if builder.program.int_width == IntWidth::Unbounded => {
+                return Err(CompilationError::UnsupportedDoubles);
+            },
+            ast::Expr::Convert { to, expr } => {
+                let src = expr.lower_ir(builder)?;
+                if builder.temp_types[src as usize] == *to {
+                    return Ok(src);
+                }
+                Op::Convert { dst: builder.program.new_temp(), src, to: *to }
+            },
+        };
+        let dst = op.dst().unwrap();
+        builder.push(op);
+        Ok(dst)
+    }
+}
+
+/// lowers the function body to a separate program, with parameters as its first variables,
+/// and a return of 0 at the end
+fn lower_function(
+    function: &ast::Function, arities: &HashMap<String, usize>, int_width: IntWidth
+) -> Result<Program, CompilationError> {
+    let mut builder = Builder::new(arities.clone(), Some(function.name.clone()), int_width);
+    for param in function.params.iter() {
+        if builder.env.contains_key(param) {
+            return Err(CompilationError::DuplicateParameter { function: function.name.clone(), name: param.clone() });
+        }
+        builder.new_variable(param, Type::Int);
+    }
+    builder.program.params = function.params.len() as u32;
+    for stmt in function.body.iter() {
+        stmt.lower_ir(&mut builder)?;
+    }
+    let src = builder.emit(|dst| Op::Const { dst, val: 0 });
+    builder.program.ops.push(Op::Return { src });
+    Ok(builder.program)
+}
+
+/// lowers the program to three-address code on integers of the given width and doubles, checking that all variables
+/// are defined before use, that all called functions are defined with the right number of parameters,
+/// that all literals fit in the width, and that doubles are used only where they are allowed (see `types`)
+pub fn lower(program: &ast::Prog, int_width: IntWidth) -> Result<Program, CompilationError> {
+    let mut arities: HashMap<String, usize> = HashMap::new();
+    for function in program.functions.iter() {
+        if arities.insert(function.name.clone(), function.params.len()).is_some() {
+            return Err(CompilationError::DuplicateFunction { name: function.name.clone() });
+        }
+    }
+    let mut functions = vec![];
+    for function in program.functions.iter() {
+        let body = lower_function(function, &arities, int_width)?;
+        functions.push(Function { name: function.name.clone(), body });
+    }
+    let mut builder = Builder::new(arities, None, int_width);
+    program.lower_ir(&mut builder)?;
+    builder.program.functions = functions;
+    Ok(builder.program)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use instant_parser::instant::ProgParser;
+
+    fn lower_source(source: &str) -> Result<Program, CompilationError> {
+        lower(&ProgParser::new().parse(source).unwrap(), IntWidth::I32)
+    }
+
+    #[test]
+    fn lowers_to_three_address_code() {
+        let program = lower_source("a = 1 + 2; a * a").unwrap();
+        let expected = "t0 = 1\nt1 = 2\nt2 = add t0, t1\nstore a, t2\nt3 = load a\nt4 = load a\nt5 = mul t3, t4\nprint t5\n";
+        assert_eq!(program.to_string(), expected);
+        assert_eq!(program.use_counts(), vec![1, 1, 1, 1, 1, 1]);
+    }
+
+    #[test]
+    fn lowers_negation() {
+        let program = lower_source("a = -2147483648; -a - -(1)").unwrap();
+        let expected = "t0 = -2147483648\nstore a, t0\nt1 = load a\nt2 = neg t1\nt3 = 1\nt4 = neg t3\n\
+            t5 = sub t2, t4\nprint t5\n";
+        assert_eq!(program.to_string(), expected);
+        assert!(matches!(
+            lower_source("2147483648"),
+            Err(CompilationError::LiteralOutOfRange { literal }) if literal == "2147483648"
+        ));
+        assert!(matches!(lower_source("--2147483649"), Err(CompilationError::LiteralOutOfRange { .. })));
+        let program = ProgParser::new().parse("9223372036854775808").unwrap();
+        assert!(matches!(lower(&program, IntWidth::I64), Err(CompilationError::LiteralOutOfRange { .. })));
+    }
+
+    #[test]
+    fn accepts_literals_of_the_selected_width() {
+        let program = ProgParser::new().parse("-9223372036854775808; 2147483648").unwrap();
+        let program = lower(&program, IntWidth::I64).unwrap();
+        assert_eq!(program.to_string(), "t0 = -9223372036854775808\nprint t0\nt1 = 2147483648\nprint t1\n");
+        assert_eq!(program.int_width, IntWidth::I64);
+    }
+
+    #[test]
+    fn lowers_unbounded_literals_to_operations_on_their_limbs() {
+        let program = ProgParser::new().parse("-18446744073709551621; 5").unwrap();
+        let program = lower(&program, IntWidth::Unbounded).unwrap();
+        let expected = "t0 = 1\nt1 = 32\nt2 = shl t0, t1\nt3 = 0\nt4 = add t2, t3\nt5 = 32\nt6 = shl t4, t5\nt7 = 5\n\
+            t8 = add t6, t7\nt9 = neg t8\nprint t9\nt10 = 5\nprint t10\n";
+        assert_eq!(program.to_string(), expected);
+    }
+
+    #[test]
+    fn lowers_conditionals_to_basic_blocks() {
+        let program = lower_source("a = 1; if a < 2 then a else -a").unwrap();
+        let expected = "t0 = 1\nstore a, t0\nt1 = load a\nt2 = 2\nt3 = lt t1, t2\nbranch t3, L0, L1\n\
+            L0:\nt4 = load a\njump L2\nL1:\nt5 = load a\nt6 = neg t5\njump L2\nL2:\nt7 = phi [t4, L0], [t6, L1]\nprint t7\n";
+        assert_eq!(program.to_string(), expected);
+
+        // phi of the outer conditional refers to the last block of the nested one
+        let program = lower_source("if 1 then if 2 then 3 else 4 else 5").unwrap();
+        assert_eq!(program.ops[program.ops.len() - 2], Op::Phi { dst: 6, incoming: vec![(4, 5), (5, 1)] });
+    }
+
+    #[test]
+    fn lowers_loops_with_a_single_backward_jump() {
+        let program = lower_source("i = 0; while (i < 2) { i = i + 1 }").unwrap();
+        let expected = "t0 = 0\nstore i, t0\njump L0\nL0:\nt1 = load i\nt2 = 2\nt3 = lt t1, t2\nbranch t3, L1, L2\n\
+            L1:\nt4 = load i\nt5 = 1\nt6 = add t4, t5\nstore i, t6\njump L0\nL2:\n";
+        assert_eq!(program.to_string(), expected);
+        let loops = program.loops();
+        assert_eq!(loops.len(), 1);
+        assert_eq!((loops[&0].exit, loops[&0].start, loops[&0].end), (2, 3, 13));
+        assert_eq!(loops[&0].stored, HashSet::from([0]));
+    }
+
+    #[test]
+    fn variables_of_blocks_are_not_visible_after_them() {
+        let program = lower_source("x = 1; { y = x; x = y }; while (x) { y = 2; x = 0 }; x").unwrap();
+        assert_eq!(program.variables, vec!["x", "y", "y.1"]);
+        let program = lower_source("{ y = 1 }; { y = 2; { y = 3 } }; { y = 4 }").unwrap();
+        assert_eq!(program.variables, vec!["y", "y.1", "y.2"]);
+        assert!(matches!(
+            lower_source("{ y = 1 }; y"),
+            Err(CompilationError::UnidentifiedVariable { .. })
+        ));
+    }
+
+    #[test]
+    fn verifies_definitions_of_temporaries() {
+        let mut program = lower_source("a = 1; a").unwrap();
+        assert!(program.verify().is_ok());
+        program.ops.swap(0, 1);
+        assert!(program.verify().is_err());
+    }
+
+    #[test]
+    fn rejects_undefined_variables() {
+        assert!(matches!(
+            lower_source("a = a + 1"),
+            Err(CompilationError::UnidentifiedVariable { .. })
+        ));
+    }
+
+    #[test]
+    fn lowers_functions_and_calls() {
+        let program = lower_source("fn f(a, b) { return a - b }; f(1, f(2, 3))").unwrap();
+        // the block after return is unreachable, every block of a function still ends with a return
+        let expected = "fn f(a, b):\nt0 = load a\nt1 = load b\nt2 = sub t0, t1\nreturn t2\nL0:\nt3 = 0\nreturn t3\n\n\
+            t0 = 1\nt1 = 2\nt2 = 3\nt3 = call f(t1, t2)\nt4 = call f(t0, t3)\nprint t4\n";
+        assert_eq!(program.to_string(), expected);
+        assert_eq!(program.functions[0].body.params, 2);
+    }
+
+    #[test]
+    fn lowers_print_statements() {
+        let program = lower_source("a = 1; a; print a, 2").unwrap();
+        let expected = "t0 = 1\nstore a, t0\nt1 = load a\nprint t1\nt2 = load a\nt3 = 2\nprint t2, t3\n";
+        assert_eq!(program.to_string(), expected);
+
+        // in the explicit print mode, values of expression statements are evaluated, but not printed
+        let program = lower_source("pragma explicit_print; fn f(a) { a; print a }; f(1) + 2; print 3").unwrap();
+        let expected = "fn f(a):\nt0 = load a\nt1 = load a\nprint t1\nt2 = 0\nreturn t2\n\n\
+            t0 = 1\nt1 = call f(t0)\nt2 = 2\nt3 = add t1, t2\nt4 = 3\nprint t4\n";
+        assert_eq!(program.to_string(), expected);
+    }
+
+    #[test]
+    fn rejects_invalid_functions_and_calls() {
+        assert!(matches!(lower_source("f(1)"), Err(CompilationError::UndefinedFunction { .. })));
+        assert!(matches!(
+            lower_source("fn f(a) = a; f(1, 2)"),
+            Err(CompilationError::ArityMismatch { expected: 1, found: 2, .. })
+        ));
+        assert!(matches!(lower_source("fn f() = 1; fn f() = 2"), Err(CompilationError::DuplicateFunction { .. })));
+        assert!(matches!(lower_source("fn f(a, a) = a"), Err(CompilationError::DuplicateParameter { .. })));
+        assert!(matches!(lower_source("return 1"), Err(CompilationError::ReturnOutsideFunction)));
+        // functions don't see variables of the main program
+        assert!(matches!(
+            lower_source("a = 1; fn f() = a; f()"),
+            Err(CompilationError::UnidentifiedVariable { .. })
+        ));
+    }
+}
@@ -1,85 +1,583 @@
-use crate::stack::{CompiledCode, Instruction};
+use crate::common::IntWidth;
+use crate::stack::{CompiledCode, CompiledProgram, Condition, Instruction};
 
+/// suffix of the conditional jump instructions (`ifeq`, `if_icmpeq`, ...)
+fn condition_suffix(cond: &Condition) -> &'static str {
+    match cond {
+        Condition::Eq => "eq",
+        Condition::Ne => "ne",
+        Condition::Lt => "lt",
+        Condition::Le => "le",
+        Condition::Gt => "gt",
+        Condition::Ge => "ge",
+    }
+}
+
+/// Helper method peeking the next character of `System.in`, names of Instant functions can't contain `$`
+const PEEK_METHOD: &str = "instant$peek()I";
+
+/// JVM types of Instant values: mnemonics of typed instructions start with `prefix` (`iadd`, `ladd`, ...),
+/// and long values take two slots of the stack and locals - unbounded values are references to `BigInteger`,
+/// their loads, stores and returns start with `a` and the arithmetic is made with its methods.
+/// Doubles also take two slots, so in programs using them every value is given `slots` = 2 slots
+/// (locals may hold integers and doubles in turn, and the stack limit is counted in values).
+struct JvmType {
+    prefix: char,
+    descriptor: &'static str,
+    size: u32,
+    slots: u32,
+    read_method: &'static str,
+}
+
+const BIG_INTEGER: &str = "java/math/BigInteger";
+
+/// Helper method formatting a double as `printf("%.1f")` in the runtime of LLVM
+const FORMAT_DOUBLE_METHOD: &str = "instant$formatDouble(D)Ljava/lang/String;";
+
+fn jvm_type(int_width: IntWidth, uses_doubles: bool) -> JvmType {
+    let mut jvm_type = match int_width {
+        IntWidth::I32 => JvmType { prefix: 'i', descriptor: "I", size: 1, slots: 1, read_method: "instant$readInt()I" },
+        IntWidth::I64 => JvmType { prefix: 'l', descriptor: "J", size: 2, slots: 2, read_method: "instant$readLong()J" },
+        IntWidth::Unbounded => JvmType {
+            prefix: 'a',
+            descriptor: "Ljava/math/BigInteger;",
+            size: 1,
+            slots: 1,
+            read_method: "instant$readBigInteger()Ljava/math/BigInteger;",
+        },
+    };
+    if uses_doubles {
+        jvm_type.slots = 2;
+    }
+    jvm_type
+}
+
+/// load or store of a value with the given prefix in the local starting at the slot
+fn local_instruction(prefix: char, mnemonic: &str, slot: i32) -> String {
+    match slot {
+        0..=3 => format!("{}{}_{}", prefix, mnemonic, slot),
+        _ => format!("{}{} {}", prefix, mnemonic, slot),
+    }
+}
+
+/// instructions comparing two doubles, which result in 1 if the condition holds and 0 otherwise (also for NaN,
+/// for which only `!=` holds): the result of `dcmpl` or `dcmpg` (-1, 0 or 1) is turned into 0 or 1 by its sign bit
+fn double_comparison(cond: &Condition) -> Vec<&'static str> {
+    match cond {
+        Condition::Lt => vec!["dcmpg", "bipush 31", "iushr"],
+        Condition::Le => vec!["dcmpg", "iconst_1", "isub", "bipush 31", "iushr"],
+        Condition::Gt => vec!["dcmpl", "ineg", "bipush 31", "iushr"],
+        Condition::Ge => vec!["dcmpl", "ineg", "iconst_1", "isub", "bipush 31", "iushr"],
+        Condition::Eq => vec!["dcmpl", "dup", "imul", "iconst_1", "isub", "bipush 31", "iushr"],
+        Condition::Ne => vec!["dcmpl", "dup", "imul"],
+    }
+}
+
+impl JvmType {
+    /// typed instruction, eg. `add` is translated to `iadd` or `ladd`
+    fn typed(&self, mnemonic: &str) -> String {
+        format!("{}{}", self.prefix, mnemonic)
+    }
+
+    fn is_big(&self) -> bool {
+        self.prefix == 'a'
+    }
+
+    /// arithmetic instruction, eg. `add` is translated to `iadd`, `ladd` or a call of `BigInteger.add`
+    fn arithmetic(&self, mnemonic: &str) -> String {
+        if !self.is_big() {
+            return self.typed(mnemonic);
+        }
+        let method = match mnemonic {
+            "add" => "add",
+            "sub" => "subtract",
+            "mul" => "multiply",
+            "div" => "divide",
+            "rem" => "remainder",
+            "and" => "and",
+            "or" => "or",
+            "xor" => "xor",
+            "neg" => return format!("invokevirtual {}/negate()L{};", BIG_INTEGER, BIG_INTEGER),
+            _ => unreachable!(),
+        };
+        format!("invokevirtual {}/{}(L{};)L{};", BIG_INTEGER, method, BIG_INTEGER, BIG_INTEGER)
+    }
+
+    /// converts the int on top of the stack to the type
+    fn int_conversion(&self) -> Vec<String> {
+        match self.prefix {
+            'i' => vec![],
+            'l' => vec![String::from("i2l")],
+            _ => vec![String::from("i2l"), format!("invokestatic {}/valueOf(J)L{};", BIG_INTEGER, BIG_INTEGER)],
+        }
+    }
+
+    /// descriptor of the `print` and `println` methods of `PrintStream` used for the values
+    fn print_descriptor(&self) -> &'static str {
+        if self.is_big() { "Ljava/lang/Object;" } else { self.descriptor }
+    }
+
+    /// load or store of the value in the local starting at the slot
+    fn local(&self, mnemonic: &str, slot: i32) -> String {
+        local_instruction(self.prefix, mnemonic, slot)
+    }
+
+    /// stack limit of the code in slots - in the long mode, one more value is needed for comparisons with 0
+    /// (made with `lconst_0` and `lcmp`) and swaps (made with `dup2_x2` and `pop2`), and in the unbounded mode
+    /// one more slot is needed for the long converted to a pushed `BigInteger`
+    fn stack_limit(&self, code: &CompiledCode) -> u32 {
+        match self.size {
+            _ if self.is_big() => code.stack_limit + 1,
+            1 => code.stack_limit * self.slots,
+            size => (code.stack_limit + 1) * size,
+        }
+    }
+
+    fn locals_limit(&self, code: &CompiledCode) -> u32 {
+        code.locals_limit * self.slots
+    }
+
+    /// parameters are passed in consecutive locals of `size` slots, so when values take more slots, they are
+    /// moved to their locals first - starting from the last one, which is never moved onto a parameter not moved yet
+    fn parameters_prologue(&self, params: u32) -> Vec<String> {
+        if self.slots == self.size {
+            return vec![];
+        }
+        (1..params as i32).rev()
+            .flat_map(|param| vec![
+                self.local("load", param * self.size as i32),
+                self.local("store", param * self.slots as i32),
+            ])
+            .collect()
+    }
+}
+
+/// name and descriptor of the static method implementing an Instant function
+fn method_descriptor(name: &str, params: u32, jvm_type: &JvmType) -> String {
+    let descriptor = jvm_type.descriptor;
+    format!("{}({}){}", name, descriptor.repeat(params as usize), descriptor)
+}
+
+/// calls are translated to `invokestatic` of methods in the class of the program
 trait JVMOutput {
-    fn translate(&self) -> Vec<String>;
+    fn translate(&self, class_name: &str, jvm_type: &JvmType) -> Vec<String>;
 }
 
-impl JVMOutput for CompiledCode {
-    fn translate(&self) -> Vec<String> {
+impl JVMOutput for CompiledProgram {
+    fn translate(&self, class_name: &str, jvm_type: &JvmType) -> Vec<String> {
         let mut instruction_vec: Vec<String> = vec![
             String::from(".method public static main([Ljava/lang/String;)V"),
-            format!(".limit stack {}", self.stack_limit),
-            format!(".limit locals {}", self.locals_limit + 1), // +1 local for main argument
+            format!(".limit stack {}", jvm_type.stack_limit(&self.main)),
+            format!(".limit locals {}", jvm_type.locals_limit(&self.main) + 1), // +1 local for main argument
         ];
+        instruction_vec.append(&mut self.main.translate(class_name, jvm_type));
+        instruction_vec.push(String::from("return"));
+        instruction_vec.push(String::from(".end method"));
+
+        // functions are static methods, with parameters in the first locals and the code ending with a return
+        for function in self.functions.iter() {
+            instruction_vec.append(&mut vec![
+                format!(".method public static {}", method_descriptor(&function.name, function.params, jvm_type)),
+                format!(".limit stack {}", jvm_type.stack_limit(&function.code)),
+                format!(".limit locals {}", jvm_type.locals_limit(&function.code)),
+            ]);
+            instruction_vec.append(&mut jvm_type.parameters_prologue(function.params));
+            instruction_vec.append(&mut function.code.translate(class_name, jvm_type));
+            instruction_vec.push(String::from(".end method"));
+        }
+        instruction_vec
+    }
+}
 
+impl JVMOutput for CompiledCode {
+    fn translate(&self, class_name: &str, jvm_type: &JvmType) -> Vec<String> {
+        let mut instruction_vec: Vec<String> = vec![];
         for instr in self.instructions.iter() {
-            let mut instr_jvm_format = instr.translate();
+            let mut instr_jvm_format = instr.translate(class_name, jvm_type);
             instruction_vec.append(&mut instr_jvm_format);
         }
-
-        instruction_vec.push(String::from("return"));
-        instruction_vec.push(String::from(".end method"));
         instruction_vec
     }
 }
 
 impl JVMOutput for Instruction {
-    fn translate(&self) -> Vec<String> {
+    fn translate(&self, class_name: &str, jvm_type: &JvmType) -> Vec<String> {
         let mut instruction_vec: Vec<String> = vec![];
+        let long = jvm_type.size == 2;
+        let big = jvm_type.is_big();
         match self {
+            // constants of BigInteger are used for 0, 1 and 10, other values are converted from longs
+            Instruction::PUSH { val } if big => {
+                match *val {
+                    0 => instruction_vec.push(format!("getstatic {}/ZERO L{};", BIG_INTEGER, BIG_INTEGER)),
+                    1 => instruction_vec.push(format!("getstatic {}/ONE L{};", BIG_INTEGER, BIG_INTEGER)),
+                    10 => instruction_vec.push(format!("getstatic {}/TEN L{};", BIG_INTEGER, BIG_INTEGER)),
+                    _ => instruction_vec.append(&mut vec![
+                        format!("ldc2_w {}", val),
+                        format!("invokestatic {}/valueOf(J)L{};", BIG_INTEGER, BIG_INTEGER),
+                    ]),
+                }
+            },
+            Instruction::PUSH { val } if long => {
+                let instr = match *val {
+                    0..=1 => format!("lconst_{}", val),
+                    _ => format!("ldc2_w {}", val),
+                };
+                instruction_vec.push(instr);
+            },
             Instruction::PUSH { val } => {
                 let instr = match *val {
                     -1 => String::from("iconst_m1"),
                     0..=5 => format!("iconst_{}", val),
-                    5..=127 => format!("bipush {}", val),
-                    _ => format!("sipush {} {}", ((val >> 16) / (2 << 8)) as u8, (val >> 24) as u8),
-                    // larger are truncated without any warning TODO: fix this
+                    -128..=127 => format!("bipush {}", val),
+                    -32768..=32767 => format!("sipush {}", val),
+                    _ => format!("ldc {}", val),
                 };
                 instruction_vec.push(instr);
             },
             Instruction::MUL => {
-                instruction_vec.push(String::from("imul"));
+                instruction_vec.push(jvm_type.arithmetic("mul"));
             },
             Instruction::ADD => {
-                instruction_vec.push(String::from("iadd"));
+                instruction_vec.push(jvm_type.arithmetic("add"));
             },
             Instruction::SUB => {
-                instruction_vec.push(String::from("isub"));
+                instruction_vec.push(jvm_type.arithmetic("sub"));
             },
             Instruction::DIV => {
-                instruction_vec.push(String::from("idiv"));
+                instruction_vec.push(jvm_type.arithmetic("div"));
             },
-            Instruction::PRINT => {
-                let mut print_instructions = vec![
-                    String::from("getstatic java/lang/System/out Ljava/io/PrintStream;"),
-                    String::from("swap"),
-                    String::from("invokevirtual java/io/PrintStream/println(I)V"),
-                ];
-                instruction_vec.append(&mut print_instructions);
+            Instruction::REM => {
+                instruction_vec.push(jvm_type.arithmetic("rem"));
+            },
+            Instruction::AND => {
+                instruction_vec.push(jvm_type.arithmetic("and"));
+            },
+            Instruction::OR => {
+                instruction_vec.push(jvm_type.arithmetic("or"));
+            },
+            Instruction::XOR => {
+                instruction_vec.push(jvm_type.arithmetic("xor"));
+            },
+            // the shift amount of shiftLeft and shiftRight of BigInteger is an int, made of its lowest 32 bits
+            Instruction::SHL | Instruction::SHR if big => {
+                let method = if *self == Instruction::SHL { "shiftLeft" } else { "shiftRight" };
+                instruction_vec.append(&mut vec![
+                    format!("invokevirtual {}/intValue()I", BIG_INTEGER),
+                    format!("invokevirtual {}/{}(I)L{};", BIG_INTEGER, method, BIG_INTEGER),
+                ]);
+            },
+            // the shift amount of lshl and lshr is an int
+            Instruction::SHL | Instruction::SHR if long => {
+                instruction_vec.push(String::from("l2i"));
+                instruction_vec.push(String::from(if *self == Instruction::SHL { "lshl" } else { "lshr" }));
+            },
+            Instruction::SHL => {
+                instruction_vec.push(String::from("ishl"));
+            },
+            Instruction::SHR => {
+                instruction_vec.push(String::from("ishr"));
+            },
+            Instruction::NEG => {
+                instruction_vec.push(jvm_type.arithmetic("neg"));
+            },
+            Instruction::PRINT | Instruction::PRINT_INLINE => {
+                instruction_vec.push(String::from("getstatic java/lang/System/out Ljava/io/PrintStream;"));
+                // the receiver is moved below the printed value, long values are swapped with dup_x2 and pop
+                if long {
+                    instruction_vec.push(String::from("dup_x2"));
+                    instruction_vec.push(String::from("pop"));
+                } else {
+                    instruction_vec.push(String::from("swap"));
+                }
+                if *self == Instruction::PRINT {
+                    instruction_vec.push(format!("invokevirtual java/io/PrintStream/println({})V", jvm_type.print_descriptor()));
+                } else {
+                    instruction_vec.append(&mut vec![
+                        format!("invokevirtual java/io/PrintStream/print({})V", jvm_type.print_descriptor()),
+                        String::from("getstatic java/lang/System/out Ljava/io/PrintStream;"),
+                        String::from("bipush 32"),
+                        String::from("invokevirtual java/io/PrintStream/print(C)V"),
+                    ]);
+                }
+            },
+            Instruction::PRINT_BEGIN => {
+                instruction_vec.push(String::from("getstatic java/lang/System/out Ljava/io/PrintStream;"));
+            },
+            Instruction::PRINT_END => {
+                instruction_vec.push(format!("invokevirtual java/io/PrintStream/println({})V", jvm_type.print_descriptor()));
             },
             Instruction::STORE { addr } => {
-                let instr = match *addr {
-                    0..=3 => format!("istore_{}", addr),
-                    _ => format!("istore {}", addr),
-                };
-                instruction_vec.push(instr);
+                instruction_vec.push(jvm_type.local("store", addr * jvm_type.slots as i32));
             },
             Instruction::LOAD { addr } => {
-                let instr = match *addr {
-                    0..=3 => format!("iload_{}", addr),
-                    _ => format!("iload {}", addr),
-                };
-                instruction_vec.push(instr);
+                instruction_vec.push(jvm_type.local("load", addr * jvm_type.slots as i32));
+            },
+            Instruction::SWAP if long => {
+                instruction_vec.push(String::from("dup2_x2"));
+                instruction_vec.push(String::from("pop2"));
             },
             Instruction::SWAP => {
                 instruction_vec.push(String::from("swap"));
             },
+            Instruction::DUP => {
+                instruction_vec.push(String::from(if long { "dup2" } else { "dup" }));
+            },
+            Instruction::LABEL { label } => {
+                instruction_vec.push(format!("L{}:", label));
+            },
+            Instruction::GOTO { label } => {
+                instruction_vec.push(format!("goto L{}", label));
+            },
+            // BigInteger values are compared with signum and compareTo, which result in -1, 0 or 1
+            Instruction::IF { cond, label } if big => {
+                instruction_vec.push(format!("invokevirtual {}/signum()I", BIG_INTEGER));
+                instruction_vec.push(format!("if{} L{}", condition_suffix(cond), label));
+            },
+            Instruction::IF_CMP { cond, label } if big => {
+                instruction_vec.push(format!("invokevirtual {}/compareTo(L{};)I", BIG_INTEGER, BIG_INTEGER));
+                instruction_vec.push(format!("if{} L{}", condition_suffix(cond), label));
+            },
+            // long values are compared with lcmp, which results in -1, 0 or 1
+            Instruction::IF { cond, label } if long => {
+                instruction_vec.push(String::from("lconst_0"));
+                instruction_vec.push(String::from("lcmp"));
+                instruction_vec.push(format!("if{} L{}", condition_suffix(cond), label));
+            },
+            Instruction::IF { cond, label } => {
+                instruction_vec.push(format!("if{} L{}", condition_suffix(cond), label));
+            },
+            Instruction::IF_CMP { cond, label } if long => {
+                instruction_vec.push(String::from("lcmp"));
+                instruction_vec.push(format!("if{} L{}", condition_suffix(cond), label));
+            },
+            Instruction::IF_CMP { cond, label } => {
+                instruction_vec.push(format!("if_icmp{} L{}", condition_suffix(cond), label));
+            },
+            Instruction::CALL { function, args } => {
+                instruction_vec.push(
+                    format!("invokestatic {}/{}", class_name, method_descriptor(function, *args, jvm_type))
+                );
+            },
+            Instruction::RETURN => {
+                instruction_vec.push(jvm_type.typed("return"));
+            },
+            Instruction::READ => {
+                instruction_vec.push(format!("invokestatic {}/{}", class_name, jvm_type.read_method));
+            },
+            // Jasmin parses decimal literals with the precision of floats, so other doubles are pushed as their bits
+            Instruction::PUSH_DOUBLE { bits } if *bits == 0 => instruction_vec.push(String::from("dconst_0")),
+            Instruction::PUSH_DOUBLE { bits } if f64::from_bits(*bits) == 1.0 => {
+                instruction_vec.push(String::from("dconst_1"));
+            },
+            Instruction::PUSH_DOUBLE { bits } => {
+                instruction_vec.push(format!("ldc2_w {}", *bits as i64));
+                instruction_vec.push(String::from("invokestatic java/lang/Double/longBitsToDouble(J)D"));
+            },
+            Instruction::DADD => instruction_vec.push(String::from("dadd")),
+            Instruction::DSUB => instruction_vec.push(String::from("dsub")),
+            Instruction::DMUL => instruction_vec.push(String::from("dmul")),
+            Instruction::DDIV => instruction_vec.push(String::from("ddiv")),
+            Instruction::DREM => instruction_vec.push(String::from("drem")),
+            Instruction::DNEG => instruction_vec.push(String::from("dneg")),
+            Instruction::DCMP { cond } => {
+                instruction_vec.extend(double_comparison(cond).into_iter().map(String::from));
+                if long {
+                    instruction_vec.push(String::from("i2l"));
+                }
+            },
+            // conversions of doubles to integers saturate, and convert NaN to 0
+            Instruction::I2D => instruction_vec.push(String::from(if long { "l2d" } else { "i2d" })),
+            Instruction::D2I => instruction_vec.push(String::from(if long { "d2l" } else { "d2i" })),
+            Instruction::DSTORE { addr } => {
+                instruction_vec.push(local_instruction('d', "store", addr * jvm_type.slots as i32));
+            },
+            Instruction::DLOAD { addr } => {
+                instruction_vec.push(local_instruction('d', "load", addr * jvm_type.slots as i32));
+            },
+            // doubles are formatted to strings before they are printed, so the receiver is swapped with a string
+            Instruction::DPRINT | Instruction::DPRINT_INLINE => {
+                instruction_vec.append(&mut vec![
+                    format!("invokestatic {}/{}", class_name, FORMAT_DOUBLE_METHOD),
+                    String::from("getstatic java/lang/System/out Ljava/io/PrintStream;"),
+                    String::from("swap"),
+                ]);
+                if *self == Instruction::DPRINT {
+                    instruction_vec.push(String::from("invokevirtual java/io/PrintStream/println(Ljava/lang/String;)V"));
+                } else {
+                    instruction_vec.append(&mut vec![
+                        String::from("invokevirtual java/io/PrintStream/print(Ljava/lang/String;)V"),
+                        String::from("getstatic java/lang/System/out Ljava/io/PrintStream;"),
+                        String::from("bipush 32"),
+                        String::from("invokevirtual java/io/PrintStream/print(C)V"),
+                    ]);
+                }
+            },
+            Instruction::DPRINT_END => {
+                instruction_vec.append(&mut vec![
+                    format!("invokestatic {}/{}", class_name, FORMAT_DOUBLE_METHOD),
+                    String::from("invokevirtual java/io/PrintStream/println(Ljava/lang/String;)V"),
+                ]);
+            },
         };
         instruction_vec
     }
 }
 
-pub fn translate(compiled_program: &CompiledCode, name: &String) -> Vec<String> {
+/// Reads the next integer in the same way as `scanf("%d")` in the runtime of LLVM: whitespace is skipped,
+/// followed by an optional sign and digits, and the first character which is not a part of the integer
+/// is left in the input (peeked using `mark` and `reset` of `System.in`, which is buffered), so 0 is returned
+/// at the end of the input. The integer is accumulated in locals starting from 2, as an int, a long or a BigInteger.
+fn read_methods(class_name: &str, jvm_type: &JvmType) -> Vec<String> {
+    let peek = vec![format!("invokestatic {}/{}", class_name, PEEK_METHOD), String::from("istore_0")];
+    let consume = vec![
+        String::from("getstatic java/lang/System/in Ljava/io/InputStream;"),
+        String::from("invokevirtual java/io/InputStream/read()I"),
+        String::from("pop"),
+    ];
+    let mut instruction_vec = vec![
+        format!(".method public static {}", PEEK_METHOD),
+        String::from(".limit stack 3"),
+        String::from(".limit locals 1"),
+        String::from("getstatic java/lang/System/in Ljava/io/InputStream;"),
+        String::from("dup"),
+        String::from("iconst_1"),
+        String::from("invokevirtual java/io/InputStream/mark(I)V"),
+        String::from("invokevirtual java/io/InputStream/read()I"),
+        String::from("istore_0"),
+        String::from("getstatic java/lang/System/in Ljava/io/InputStream;"),
+        String::from("invokevirtual java/io/InputStream/reset()V"),
+        String::from("iload_0"),
+        String::from("ireturn"),
+        String::from(".end method"),
+        format!(".method public static {}", jvm_type.read_method),
+        format!(".limit stack {}", 2 * jvm_type.size + 1),
+        format!(".limit locals {}", 2 + jvm_type.size),
+        String::from("Skip:"),
+    ];
+    // whitespace (space, or characters from tab to carriage return) is skipped
+    instruction_vec.extend(peek.clone());
+    instruction_vec.extend(vec![
+        String::from("iload_0"),
+        String::from("bipush 32"),
+        String::from("if_icmpeq SkipChar"),
+        String::from("iload_0"),
+        String::from("bipush 9"),
+        String::from("if_icmplt Sign"),
+        String::from("iload_0"),
+        String::from("bipush 13"),
+        String::from("if_icmpgt Sign"),
+        String::from("SkipChar:"),
+    ]);
+    instruction_vec.extend(consume.clone());
+    instruction_vec.extend(vec![
+        String::from("goto Skip"),
+        String::from("Sign:"),
+        String::from("iconst_0"),
+        String::from("istore_1"),
+        String::from("iconst_0"),
+    ]);
+    instruction_vec.extend(jvm_type.int_conversion());
+    instruction_vec.extend(vec![
+        jvm_type.local("store", 2),
+        String::from("iload_0"),
+        String::from("bipush 45"),
+        String::from("if_icmpne Plus"),
+        String::from("iconst_1"),
+        String::from("istore_1"),
+        String::from("goto SignChar"),
+        String::from("Plus:"),
+        String::from("iload_0"),
+        String::from("bipush 43"),
+        String::from("if_icmpne Digits"),
+        String::from("SignChar:"),
+    ]);
+    instruction_vec.extend(consume.clone());
+    instruction_vec.extend(peek.clone());
+    instruction_vec.extend(vec![
+        String::from("Digits:"),
+        String::from("iload_0"),
+        String::from("bipush 48"),
+        String::from("if_icmplt Done"),
+        String::from("iload_0"),
+        String::from("bipush 57"),
+        String::from("if_icmpgt Done"),
+        jvm_type.local("load", 2),
+        String::from("bipush 10"),
+    ]);
+    instruction_vec.extend(jvm_type.int_conversion());
+    instruction_vec.extend(vec![
+        jvm_type.arithmetic("mul"),
+        String::from("iload_0"),
+        String::from("bipush 48"),
+        String::from("isub"),
+    ]);
+    instruction_vec.extend(jvm_type.int_conversion());
+    instruction_vec.extend(vec![
+        jvm_type.arithmetic("add"),
+        jvm_type.local("store", 2),
+    ]);
+    instruction_vec.extend(consume);
+    instruction_vec.extend(peek);
+    instruction_vec.extend(vec![
+        String::from("goto Digits"),
+        String::from("Done:"),
+        jvm_type.local("load", 2),
+        String::from("iload_1"),
+        String::from("ifeq Positive"),
+        jvm_type.arithmetic("neg"),
+        String::from("Positive:"),
+        jvm_type.typed("return"),
+        String::from(".end method"),
+    ]);
+    instruction_vec
+}
+
+/// Formats the double with a single digit after the decimal point, rounded half to even from its exact value
+/// (as `printf("%.1f")`), using `BigDecimal` for finite values - the sign is printed also for -0.0
+fn format_double_method() -> Vec<String> {
+    vec![
+        format!(".method public static {}", FORMAT_DOUBLE_METHOD),
+        String::from(".limit stack 6"),
+        String::from(".limit locals 2"),
+        String::from("dload_0"),
+        String::from("invokestatic java/lang/Double/isNaN(D)Z"),
+        String::from("ifne NaN"),
+        String::from("dload_0"),
+        String::from("invokestatic java/lang/Double/isInfinite(D)Z"),
+        String::from("ifne Infinity"),
+        String::from("new java/math/BigDecimal"),
+        String::from("dup"),
+        String::from("dload_0"),
+        String::from("invokestatic java/lang/Math/abs(D)D"),
+        String::from("invokespecial java/math/BigDecimal/<init>(D)V"),
+        String::from("iconst_1"),
+        String::from("getstatic java/math/RoundingMode/HALF_EVEN Ljava/math/RoundingMode;"),
+        String::from("invokevirtual java/math/BigDecimal/setScale(ILjava/math/RoundingMode;)Ljava/math/BigDecimal;"),
+        String::from("invokevirtual java/math/BigDecimal/toPlainString()Ljava/lang/String;"),
+        String::from("goto Sign"),
+        String::from("NaN:"),
+        String::from("ldc \"nan\""),
+        String::from("goto Done"),
+        String::from("Infinity:"),
+        String::from("ldc \"inf\""),
+        String::from("Sign:"),
+        String::from("dconst_1"),
+        String::from("dload_0"),
+        String::from("invokestatic java/lang/Math/copySign(DD)D"),
+        String::from("dconst_0"),
+        String::from("dcmpg"),
+        String::from("ifge Done"),
+        String::from("ldc \"-\""),
+        String::from("swap"),
+        String::from("invokevirtual java/lang/String/concat(Ljava/lang/String;)Ljava/lang/String;"),
+        String::from("Done:"),
+        String::from("areturn"),
+        String::from(".end method"),
+    ]
+}
+
+pub fn translate(compiled_program: &CompiledProgram, name: &String) -> Vec<String> {
     let mut jasmin_representation = vec![
         String::from(".bytecode 47.0"),
         format!(".class public {}", name),
@@ -90,6 +588,13 @@ pub fn translate(compiled_program: &CompiledCode, name: &String) -> Vec<String>
         String::from("return"),
         String::from(".end method"),
     ];
-    jasmin_representation.append(&mut compiled_program.translate());
+    let jvm_type = jvm_type(compiled_program.int_width, compiled_program.uses_doubles());
+    jasmin_representation.append(&mut compiled_program.translate(name, &jvm_type));
+    if compiled_program.reads_input() {
+        jasmin_representation.append(&mut read_methods(name, &jvm_type));
+    }
+    if compiled_program.uses_doubles() {
+        jasmin_representation.append(&mut format_double_method());
+    }
     jasmin_representation
 }
@@ -0,0 +1,939 @@
+use crate::common::{CompilationError, IntWidth, RuntimeError, MAX_CALL_DEPTH};
+use crate::stack::{CompiledCode, CompiledProgram, Condition, DepthTracker, Instruction};
+
+use std::collections::HashMap;
+
+use std::ffi::c_void;
+use std::ptr;
+
+// x86-64 registers, numbered as in the instruction encoding
+const RAX: u8 = 0;
+const RCX: u8 = 1;
+const RDX: u8 = 2;
+const RBX: u8 = 3;
+const RSP: u8 = 4;
+const RBP: u8 = 5;
+const RSI: u8 = 6;
+const RDI: u8 = 7;
+const R8: u8 = 8;
+const R9: u8 = 9;
+const R10: u8 = 10;
+const R11: u8 = 11;
+const R12: u8 = 12;
+const R13: u8 = 13;
+const R14: u8 = 14;
+const R15: u8 = 15;
+
+/// Registers holding the topmost values of the abstract stack, indexed by stack depth.
+/// Deeper values are spilled to the memory buffer, right after the variables.
+const STACK_REGISTERS: &[u8] = &[RCX, RSI, RDI, R8, R9, R10, R14, R15];
+
+/// Scratch registers, used for operations on spilled values (RAX and RDX are also used by idiv)
+const SCRATCH_LHS: u8 = RAX;
+const SCRATCH_RHS: u8 = RDX;
+const SCRATCH_DIVISOR: u8 = R11;
+
+/// Registers holding the arguments of the compiled function during its whole execution,
+/// VARIABLES_BASE points to the frame of the currently executed Instant function
+const VARIABLES_BASE: u8 = RBX;
+const PRINT_FUNCTION: u8 = R12;
+const PRINT_CONTEXT: u8 = R13;
+/// Points to the call depth counter on the machine stack, which is right below the read function
+/// and the saved registers
+const FRAME_POINTER: u8 = RBP;
+const READ_FUNCTION_OFFSET: i32 = 8;
+/// Return addresses of Instant functions are pushed to a separate call stack (allocated for MAX_CALL_DEPTH calls),
+/// its pointer is saved right below the call depth counter while the print and read functions run on the machine stack
+const CALL_STACK_POINTER_OFFSET: i32 = -8;
+
+/// Callee-saved registers that are used by the compiled code, pushed at the entry together with the read function
+/// and the call depth counter (followed by the saved pointer of the call stack, so that the machine stack
+/// is 16-byte aligned for the calls of the print and read functions)
+const SAVED_REGISTERS: &[u8] = &[RBX, RBP, R12, R13, R14, R15];
+
+/// Return values of the compiled function
+const EXIT_SUCCESS: i32 = 0;
+const EXIT_DIVISION_BY_ZERO: i32 = 1;
+const EXIT_STACK_OVERFLOW: i32 = 2;
+
+/// Memory words hold 64-bit values, 32-bit values are stored in their lower halves
+const WORD_SIZE: i32 = 8;
+
+/// Values are passed to (and returned from) the trampolines as i64, 32-bit values are wrapped around by them,
+/// so the upper halves of the registers don't matter
+type PrintFunction = extern "C" fn(*mut c_void, i64, i32);
+type ReadFunction = extern "C" fn(*mut c_void) -> i64;
+type CompiledFunction = extern "C" fn(*mut i64, PrintFunction, *mut c_void, ReadFunction, *mut u64) -> i32;
+
+/// Minimal x86-64 assembler, supporting only the instructions used by the compiler.
+/// All arithmetic is done on registers of the width of Instant integers (`wide` registers for 64-bit integers),
+/// memory is addressed relative to VARIABLES_BASE (or FRAME_POINTER, for the call depth counter).
+struct Assembler {
+    code: Vec<u8>,
+    labels: Vec<Option<usize>>,
+    fixups: Vec<(usize, usize)>,
+    wide: bool,
+}
+
+impl Assembler {
+    fn new(int_width: IntWidth) -> Assembler {
+        Assembler { code: vec![], labels: vec![], fixups: vec![], wide: int_width == IntWidth::I64 }
+    }
+
+    fn rex(&mut self, wide: bool, reg: u8, rm: u8) {
+        let rex = 0x40 | (wide as u8) << 3 | (reg >> 3) << 2 | (rm >> 3);
+        if rex != 0x40 {
+            self.code.push(rex);
+        }
+    }
+
+    fn modrm_register(&mut self, reg: u8, rm: u8) {
+        self.code.push(0xC0 | (reg & 7) << 3 | (rm & 7));
+    }
+
+    fn modrm_memory(&mut self, reg: u8, base: u8, disp: i32) {
+        // [base + disp32], bases encoded as RSP would need an additional SIB byte
+        debug_assert_ne!(base & 7, RSP);
+        self.code.push(0x80 | (reg & 7) << 3 | (base & 7));
+        self.code.extend_from_slice(&disp.to_le_bytes());
+    }
+
+    /// loads the constant, which has to fit in the register
+    fn mov_imm(&mut self, dst: u8, val: i64) {
+        self.rex(self.wide, 0, dst);
+        self.code.push(0xB8 + (dst & 7));
+        if self.wide {
+            self.code.extend_from_slice(&val.to_le_bytes());
+        } else {
+            self.code.extend_from_slice(&(val as i32).to_le_bytes());
+        }
+    }
+
+    fn mov(&mut self, dst: u8, src: u8) {
+        if dst != src {
+            self.rex(self.wide, src, dst);
+            self.code.push(0x89);
+            self.modrm_register(src, dst);
+        }
+    }
+
+    fn mov64(&mut self, dst: u8, src: u8) {
+        self.rex(true, src, dst);
+        self.code.push(0x89);
+        self.modrm_register(src, dst);
+    }
+
+    fn load(&mut self, dst: u8, base: u8, disp: i32) {
+        self.rex(self.wide, dst, base);
+        self.code.push(0x8B);
+        self.modrm_memory(dst, base, disp);
+    }
+
+    fn store(&mut self, src: u8, base: u8, disp: i32) {
+        self.rex(self.wide, src, base);
+        self.code.push(0x89);
+        self.modrm_memory(src, base, disp);
+    }
+
+    /// loads a 64-bit register (used for pointers)
+    fn load64(&mut self, dst: u8, base: u8, disp: i32) {
+        self.rex(true, dst, base);
+        self.code.push(0x8B);
+        self.modrm_memory(dst, base, disp);
+    }
+
+    /// stores a 64-bit register (used for pointers)
+    fn store64(&mut self, src: u8, base: u8, disp: i32) {
+        self.rex(true, src, base);
+        self.code.push(0x89);
+        self.modrm_memory(src, base, disp);
+    }
+
+    /// adds a constant to a 64-bit register (used for pointers)
+    fn add64_imm(&mut self, reg: u8, val: i32) {
+        self.rex(true, 0, reg);
+        self.code.push(0x81);
+        self.modrm_register(0, reg);
+        self.code.extend_from_slice(&val.to_le_bytes());
+    }
+
+    fn cmp_imm32(&mut self, reg: u8, val: i32) {
+        self.rex(self.wide, 0, reg);
+        self.code.push(0x81);
+        self.modrm_register(7, reg);
+        self.code.extend_from_slice(&val.to_le_bytes());
+    }
+
+    fn add(&mut self, dst: u8, src: u8) {
+        self.rex(self.wide, src, dst);
+        self.code.push(0x01);
+        self.modrm_register(src, dst);
+    }
+
+    fn sub(&mut self, dst: u8, src: u8) {
+        self.rex(self.wide, src, dst);
+        self.code.push(0x29);
+        self.modrm_register(src, dst);
+    }
+
+    fn and(&mut self, dst: u8, src: u8) {
+        self.rex(self.wide, src, dst);
+        self.code.push(0x21);
+        self.modrm_register(src, dst);
+    }
+
+    fn or(&mut self, dst: u8, src: u8) {
+        self.rex(self.wide, src, dst);
+        self.code.push(0x09);
+        self.modrm_register(src, dst);
+    }
+
+    fn xor(&mut self, dst: u8, src: u8) {
+        self.rex(self.wide, src, dst);
+        self.code.push(0x31);
+        self.modrm_register(src, dst);
+    }
+
+    fn imul(&mut self, dst: u8, src: u8) {
+        self.rex(self.wide, dst, src);
+        self.code.extend_from_slice(&[0x0F, 0xAF]);
+        self.modrm_register(dst, src);
+    }
+
+    fn test(&mut self, reg: u8) {
+        self.rex(self.wide, reg, reg);
+        self.code.push(0x85);
+        self.modrm_register(reg, reg);
+    }
+
+    /// compares lhs with rhs, setting the flags as lhs - rhs
+    fn cmp(&mut self, lhs: u8, rhs: u8) {
+        self.rex(self.wide, rhs, lhs);
+        self.code.push(0x39);
+        self.modrm_register(rhs, lhs);
+    }
+
+    fn cmp_imm8(&mut self, reg: u8, val: i8) {
+        self.rex(self.wide, 0, reg);
+        self.code.push(0x83);
+        self.modrm_register(7, reg);
+        self.code.push(val as u8);
+    }
+
+    fn neg(&mut self, reg: u8) {
+        self.rex(self.wide, 0, reg);
+        self.code.push(0xF7);
+        self.modrm_register(3, reg);
+    }
+
+    /// shifts reg left by cl (masked to 5 bits, or 6 bits for wide registers, by the processor)
+    fn shl_cl(&mut self, reg: u8) {
+        self.rex(self.wide, 0, reg);
+        self.code.push(0xD3);
+        self.modrm_register(4, reg);
+    }
+
+    /// shifts reg right arithmetically by cl (masked to 5 or 6 bits by the processor)
+    fn sar_cl(&mut self, reg: u8) {
+        self.rex(self.wide, 0, reg);
+        self.code.push(0xD3);
+        self.modrm_register(7, reg);
+    }
+
+    /// sign-extends eax into edx:eax (or rax into rdx:rax)
+    fn cdq(&mut self) {
+        self.rex(self.wide, 0, 0);
+        self.code.push(0x99);
+    }
+
+    /// divides edx:eax (or rdx:rax) by reg, quotient is stored in eax and remainder in edx
+    fn idiv(&mut self, reg: u8) {
+        self.rex(self.wide, 0, reg);
+        self.code.push(0xF7);
+        self.modrm_register(7, reg);
+    }
+
+    fn push(&mut self, reg: u8) {
+        self.rex(false, 0, reg);
+        self.code.push(0x50 + (reg & 7));
+    }
+
+    fn pop(&mut self, reg: u8) {
+        self.rex(false, 0, reg);
+        self.code.push(0x58 + (reg & 7));
+    }
+
+    fn call(&mut self, reg: u8) {
+        self.rex(false, 0, reg);
+        self.code.push(0xFF);
+        self.modrm_register(2, reg);
+    }
+
+    /// calls the function whose address is stored in memory
+    fn call_memory(&mut self, base: u8, disp: i32) {
+        self.rex(false, 0, base);
+        self.code.push(0xFF);
+        self.modrm_memory(2, base, disp);
+    }
+
+    fn call_label(&mut self, label: usize) {
+        self.code.push(0xE8);
+        self.jump_rel32(label);
+    }
+
+    fn ret(&mut self) {
+        self.code.push(0xC3);
+    }
+
+    fn new_label(&mut self) -> usize {
+        self.labels.push(None);
+        self.labels.len() - 1
+    }
+
+    fn bind(&mut self, label: usize) {
+        self.labels[label] = Some(self.code.len());
+    }
+
+    fn jump_rel32(&mut self, label: usize) {
+        self.fixups.push((self.code.len(), label));
+        self.code.extend_from_slice(&[0; 4]);
+    }
+
+    fn jmp(&mut self, label: usize) {
+        self.code.push(0xE9);
+        self.jump_rel32(label);
+    }
+
+    fn jz(&mut self, label: usize) {
+        self.code.extend_from_slice(&[0x0F, 0x84]);
+        self.jump_rel32(label);
+    }
+
+    /// jumps if lhs >= rhs after the comparison of unsigned values
+    fn jae(&mut self, label: usize) {
+        self.code.extend_from_slice(&[0x0F, 0x83]);
+        self.jump_rel32(label);
+    }
+
+    fn jne(&mut self, label: usize) {
+        self.code.extend_from_slice(&[0x0F, 0x85]);
+        self.jump_rel32(label);
+    }
+
+    /// jumps if the condition holds for the flags of a signed comparison
+    fn jcc(&mut self, cond: &Condition, label: usize) {
+        let opcode = match cond {
+            Condition::Eq => 0x84,
+            Condition::Ne => 0x85,
+            Condition::Lt => 0x8C,
+            Condition::Ge => 0x8D,
+            Condition::Le => 0x8E,
+            Condition::Gt => 0x8F,
+        };
+        self.code.extend_from_slice(&[0x0F, opcode]);
+        self.jump_rel32(label);
+    }
+
+    fn finish(mut self) -> Vec<u8> {
+        for (position, label) in self.fixups.iter() {
+            let target = self.labels[*label].expect("jump to unbound label");
+            let offset = target as i32 - (*position as i32 + 4);
+            self.code[*position..*position + 4].copy_from_slice(&offset.to_le_bytes());
+        }
+        self.code
+    }
+}
+
+/// Translates abstract stack instructions to machine code, keeping track of the current stack depth
+/// to map stack slots to registers (the same way as the RISC-V backend does).
+struct Translator {
+    asm: Assembler,
+    depth: u32,
+    locals_limit: u32,
+    /// size of the frame of the translated code (in words), frames of called functions are placed after it
+    frame_size: u32,
+    div_by_zero: usize,
+    stack_overflow: usize,
+    /// assembler labels of the labels in the stack code, separate for each function
+    labels: HashMap<u32, usize>,
+    /// assembler labels of the entry points of functions
+    functions: HashMap<String, usize>,
+}
+
+impl Translator {
+    fn slot_register(&self, depth: u32) -> Option<u8> {
+        STACK_REGISTERS.get(depth as usize).copied()
+    }
+
+    fn spill_offset(&self, depth: u32) -> i32 {
+        (self.locals_limit + depth - STACK_REGISTERS.len() as u32) as i32 * WORD_SIZE
+    }
+
+    fn read_slot(&mut self, depth: u32, scratch: u8) -> u8 {
+        match self.slot_register(depth) {
+            Some(reg) => reg,
+            None => {
+                let offset = self.spill_offset(depth);
+                self.asm.load(scratch, VARIABLES_BASE, offset);
+                scratch
+            },
+        }
+    }
+
+    fn target_register(&self, depth: u32, scratch: u8) -> u8 {
+        self.slot_register(depth).unwrap_or(scratch)
+    }
+
+    fn write_slot(&mut self, depth: u32, reg: u8) {
+        match self.slot_register(depth) {
+            Some(slot_reg) => self.asm.mov(slot_reg, reg),
+            None => {
+                let offset = self.spill_offset(depth);
+                self.asm.store(reg, VARIABLES_BASE, offset);
+            },
+        }
+    }
+
+    fn translate_binary(&mut self, instr: &Instruction) {
+        // lhs is always read to the register it is written back to
+        let lhs = self.read_slot(self.depth - 2, SCRATCH_LHS);
+        let rhs = self.read_slot(self.depth - 1, SCRATCH_RHS);
+        match instr {
+            Instruction::ADD => self.asm.add(lhs, rhs),
+            Instruction::SUB => self.asm.sub(lhs, rhs),
+            Instruction::MUL => self.asm.imul(lhs, rhs),
+            Instruction::AND => self.asm.and(lhs, rhs),
+            Instruction::OR => self.asm.or(lhs, rhs),
+            Instruction::XOR => self.asm.xor(lhs, rhs),
+            _ => unreachable!(),
+        }
+        self.write_slot(self.depth - 2, lhs);
+        self.depth -= 1;
+    }
+
+    fn translate_div(&mut self, remainder: bool) {
+        let lhs = self.read_slot(self.depth - 2, SCRATCH_LHS);
+        let rhs = self.read_slot(self.depth - 1, SCRATCH_DIVISOR);
+        let divide = self.asm.new_label();
+        let done = self.asm.new_label();
+
+        self.asm.test(rhs);
+        self.asm.jz(self.div_by_zero);
+        // idiv traps on the minimal value / -1, but the result should wrap around (as in JVM),
+        // and the remainder of division by -1 is always 0
+        self.asm.cmp_imm8(rhs, -1);
+        self.asm.jne(divide);
+        if remainder {
+            self.asm.mov_imm(RDX, 0);
+        } else {
+            self.asm.mov(RAX, lhs);
+            self.asm.neg(RAX);
+        }
+        self.asm.jmp(done);
+        self.asm.bind(divide);
+        self.asm.mov(RAX, lhs);
+        self.asm.cdq();
+        self.asm.idiv(rhs);
+        self.asm.bind(done);
+
+        self.write_slot(self.depth - 2, if remainder { RDX } else { RAX });
+        self.depth -= 1;
+    }
+
+    fn translate_shift(&mut self, right: bool) {
+        let lhs = self.read_slot(self.depth - 2, SCRATCH_LHS);
+        let rhs = self.read_slot(self.depth - 1, SCRATCH_RHS);
+        // shift amount has to be in cl, so the value of the bottom stack slot (RCX) is saved for the time of shift
+        self.asm.mov(RAX, lhs);
+        self.asm.mov(R11, RCX);
+        self.asm.mov(RCX, rhs);
+        if right {
+            self.asm.sar_cl(RAX);
+        } else {
+            self.asm.shl_cl(RAX);
+        }
+        self.asm.mov(RCX, R11);
+        self.write_slot(self.depth - 2, RAX);
+        self.depth -= 1;
+    }
+
+    /// arguments are the only values on the stack, they are copied to the first locals of the frame
+    /// placed after the current one, and no registers need to be preserved across the call
+    fn translate_call(&mut self, function: &str, args: u32) {
+        self.asm.load(RAX, FRAME_POINTER, 0);
+        self.asm.cmp_imm32(RAX, MAX_CALL_DEPTH as i32);
+        self.asm.jae(self.stack_overflow);
+        self.asm.mov_imm(RDX, 1);
+        self.asm.add(RAX, RDX);
+        self.asm.store(RAX, FRAME_POINTER, 0);
+
+        let frame_offset = self.frame_size as i32 * WORD_SIZE;
+        for arg in 0..args {
+            let src = self.read_slot(arg, SCRATCH_LHS);
+            self.asm.store(src, VARIABLES_BASE, frame_offset + arg as i32 * WORD_SIZE);
+        }
+        self.asm.add64_imm(VARIABLES_BASE, frame_offset);
+        let label = self.functions[function];
+        self.asm.call_label(label);
+        self.asm.add64_imm(VARIABLES_BASE, -frame_offset);
+
+        self.asm.load(RDX, FRAME_POINTER, 0);
+        self.asm.mov_imm(R11, 1);
+        self.asm.sub(RDX, R11);
+        self.asm.store(RDX, FRAME_POINTER, 0);
+        self.write_slot(0, RAX);
+        self.depth = self.depth + 1 - args;
+    }
+
+    /// the print and read functions are called on the machine stack, which has enough space for any Rust code
+    fn switch_to_machine_stack(&mut self) {
+        self.asm.store64(RSP, FRAME_POINTER, CALL_STACK_POINTER_OFFSET);
+        self.asm.mov64(RSP, FRAME_POINTER);
+        self.asm.add64_imm(RSP, CALL_STACK_POINTER_OFFSET);
+    }
+
+    fn switch_to_call_stack(&mut self) {
+        self.asm.load64(RSP, FRAME_POINTER, CALL_STACK_POINTER_OFFSET);
+    }
+
+    fn label(&mut self, label: u32) -> usize {
+        let asm = &mut self.asm;
+        *self.labels.entry(label).or_insert_with(|| asm.new_label())
+    }
+
+    fn translate(&mut self, instr: &Instruction) {
+        match instr {
+            Instruction::PUSH { val } => {
+                let target = self.target_register(self.depth, SCRATCH_LHS);
+                self.asm.mov_imm(target, *val);
+                self.write_slot(self.depth, target);
+                self.depth += 1;
+            },
+            Instruction::LOAD { addr } => {
+                let target = self.target_register(self.depth, SCRATCH_LHS);
+                self.asm.load(target, VARIABLES_BASE, addr * WORD_SIZE);
+                self.write_slot(self.depth, target);
+                self.depth += 1;
+            },
+            Instruction::STORE { addr } => {
+                let src = self.read_slot(self.depth - 1, SCRATCH_LHS);
+                self.asm.store(src, VARIABLES_BASE, addr * WORD_SIZE);
+                self.depth -= 1;
+            },
+            Instruction::ADD | Instruction::SUB | Instruction::MUL | Instruction::AND | Instruction::OR
+            | Instruction::XOR => self.translate_binary(instr),
+            Instruction::DIV => self.translate_div(false),
+            Instruction::REM => self.translate_div(true),
+            Instruction::SHL => self.translate_shift(false),
+            Instruction::SHR => self.translate_shift(true),
+            Instruction::NEG => {
+                let src = self.read_slot(self.depth - 1, SCRATCH_LHS);
+                self.asm.neg(src);
+                self.write_slot(self.depth - 1, src);
+            },
+            Instruction::DUP => {
+                let src = self.read_slot(self.depth - 1, SCRATCH_LHS);
+                self.write_slot(self.depth, src);
+                self.depth += 1;
+            },
+            Instruction::SWAP => {
+                let lower = self.read_slot(self.depth - 2, SCRATCH_LHS);
+                let upper = self.read_slot(self.depth - 1, SCRATCH_RHS);
+                self.asm.mov(SCRATCH_LHS, lower);
+                self.write_slot(self.depth - 2, upper);
+                self.write_slot(self.depth - 1, SCRATCH_LHS);
+            },
+            Instruction::PRINT | Instruction::PRINT_INLINE => {
+                // print is the last instruction of a statement, so no other values are live
+                // and caller-saved registers don't need to be preserved across the call
+                let src = self.read_slot(self.depth - 1, RSI);
+                self.asm.mov(RSI, src);
+                self.asm.mov_imm(RDX, (*instr == Instruction::PRINT) as i64);
+                self.asm.mov64(RDI, PRINT_CONTEXT);
+                self.switch_to_machine_stack();
+                self.asm.call(PRINT_FUNCTION);
+                self.switch_to_call_stack();
+                self.depth -= 1;
+            },
+            // the slot reserved for the print receiver is never written, the print function doesn't need it
+            Instruction::PRINT_BEGIN => {
+                self.depth += 1;
+            },
+            Instruction::PRINT_END => {
+                self.translate(&Instruction::PRINT);
+                self.depth -= 1;
+            },
+            Instruction::LABEL { label } => {
+                let label = self.label(*label);
+                self.asm.bind(label);
+            },
+            Instruction::GOTO { label } => {
+                let label = self.label(*label);
+                self.asm.jmp(label);
+            },
+            Instruction::IF { cond, label } => {
+                let src = self.read_slot(self.depth - 1, SCRATCH_LHS);
+                let label = self.label(*label);
+                self.asm.test(src);
+                self.asm.jcc(cond, label);
+                self.depth -= 1;
+            },
+            Instruction::IF_CMP { cond, label } => {
+                let lhs = self.read_slot(self.depth - 2, SCRATCH_LHS);
+                let rhs = self.read_slot(self.depth - 1, SCRATCH_RHS);
+                let label = self.label(*label);
+                self.asm.cmp(lhs, rhs);
+                self.asm.jcc(cond, label);
+                self.depth -= 2;
+            },
+            Instruction::CALL { function, args } => self.translate_call(function, *args),
+            // the stack is empty (as in calls), so no registers need to be preserved
+            Instruction::READ => {
+                self.asm.mov64(RDI, PRINT_CONTEXT);
+                self.switch_to_machine_stack();
+                self.asm.call_memory(FRAME_POINTER, READ_FUNCTION_OFFSET);
+                self.switch_to_call_stack();
+                self.write_slot(self.depth, RAX);
+                self.depth += 1;
+            },
+            Instruction::RETURN => {
+                let src = self.read_slot(self.depth - 1, SCRATCH_LHS);
+                self.asm.mov(RAX, src);
+                self.asm.ret();
+                self.depth -= 1;
+            },
+            // programs with doubles are rejected before translation
+            _ => unreachable!(),
+        }
+    }
+}
+
+/// size of the memory needed by the code (in words): its locals and the stack slots spilled from registers
+fn frame_size(code: &CompiledCode) -> u32 {
+    code.locals_limit + code.stack_limit.saturating_sub(STACK_REGISTERS.len() as u32)
+}
+
+impl Translator {
+    fn translate_code(&mut self, code: &CompiledCode) {
+        self.locals_limit = code.locals_limit;
+        self.frame_size = frame_size(code);
+        self.labels.clear();
+        let mut tracker = DepthTracker::default();
+        for instr in code.instructions.iter() {
+            self.depth = tracker.step(instr);
+            self.translate(instr);
+        }
+    }
+}
+
+/// compiles the program to a function with signature of `CompiledFunction`, which returns one of
+/// the exit codes; Instant functions are compiled to subroutines, called on the separate call stack
+fn assemble(compiled_program: &CompiledProgram) -> Vec<u8> {
+    let mut asm = Assembler::new(compiled_program.int_width);
+    let div_by_zero = asm.new_label();
+    let stack_overflow = asm.new_label();
+    let exit = asm.new_label();
+    let functions = compiled_program.functions.iter()
+        .map(|function| (function.name.clone(), asm.new_label()))
+        .collect();
+
+    for reg in SAVED_REGISTERS.iter() {
+        asm.push(*reg);
+    }
+    asm.push(RCX);
+    asm.mov_imm(RAX, 0);
+    asm.push(RAX);
+    asm.mov64(FRAME_POINTER, RSP);
+    asm.mov64(VARIABLES_BASE, RDI);
+    asm.mov64(PRINT_FUNCTION, RSI);
+    asm.mov64(PRINT_CONTEXT, RDX);
+    asm.mov64(RSP, R8);
+
+    let mut translator = Translator {
+        asm,
+        depth: 0,
+        locals_limit: 0,
+        frame_size: 0,
+        div_by_zero,
+        stack_overflow,
+        labels: HashMap::new(),
+        functions,
+    };
+    translator.translate_code(&compiled_program.main);
+    translator.asm.mov_imm(RAX, i64::from(EXIT_SUCCESS));
+    translator.asm.jmp(exit);
+
+    for function in compiled_program.functions.iter() {
+        let label = translator.functions[&function.name];
+        translator.asm.bind(label);
+        translator.translate_code(&function.code);
+    }
+    let mut asm = translator.asm;
+
+    asm.bind(div_by_zero);
+    asm.mov_imm(RAX, i64::from(EXIT_DIVISION_BY_ZERO));
+    asm.jmp(exit);
+
+    asm.bind(stack_overflow);
+    asm.mov_imm(RAX, i64::from(EXIT_STACK_OVERFLOW));
+
+    // return addresses of the calls in progress are dropped together with the call stack
+    asm.bind(exit);
+    asm.mov64(RSP, FRAME_POINTER);
+    asm.pop(RDX);
+    asm.pop(RDX);
+    for reg in SAVED_REGISTERS.iter().rev() {
+        asm.pop(*reg);
+    }
+    asm.ret();
+
+    asm.finish()
+}
+
+/// Input and output of the running program, passed to the trampolines as their context
+struct Io<'a> {
+    input: &'a mut dyn FnMut() -> i64,
+    output: &'a mut dyn FnMut(i64, bool),
+    int_width: IntWidth,
+}
+
+extern "C" fn print_trampoline(context: *mut c_void, val: i64, newline: i32) {
+    let io = unsafe { &mut *(context as *mut Io) };
+    (io.output)(io.int_width.wrap(val), newline != 0);
+}
+
+extern "C" fn read_trampoline(context: *mut c_void) -> i64 {
+    let io = unsafe { &mut *(context as *mut Io) };
+    io.int_width.wrap((io.input)())
+}
+
+/// Machine code of the compiled program, placed in executable memory
+pub struct JitProgram {
+    code: *mut c_void,
+    code_size: usize,
+    memory_size: usize,
+    int_width: IntWidth,
+}
+
+impl JitProgram {
+    fn new(machine_code: &[u8], memory_size: usize, int_width: IntWidth) -> Result<JitProgram, CompilationError> {
+        let code_size = machine_code.len();
+        let code = unsafe {
+            let code = libc::mmap(
+                ptr::null_mut(),
+                code_size,
+                libc::PROT_READ | libc::PROT_WRITE,
+                libc::MAP_PRIVATE | libc::MAP_ANONYMOUS,
+                -1,
+                0,
+            );
+            if code == libc::MAP_FAILED {
+                return Err(CompilationError::ExecutableMemoryUnavailable {
+                    reason: std::io::Error::last_os_error().to_string()
+                });
+            }
+            ptr::copy_nonoverlapping(machine_code.as_ptr(), code as *mut u8, code_size);
+            code
+        };
+        // created before changing the protection, so that memory is unmapped in case of failure
+        let program = JitProgram { code, code_size, memory_size, int_width };
+        if unsafe { libc::mprotect(code, code_size, libc::PROT_READ | libc::PROT_EXEC) } != 0 {
+            return Err(CompilationError::ExecutableMemoryUnavailable {
+                reason: std::io::Error::last_os_error().to_string()
+            });
+        }
+        Ok(program)
+    }
+
+    /// executes the program, taking the read values from `input` (wrapped around to the width of integers)
+    /// and passing every printed value to `output`, together with whether it ends the line
+    pub fn run(&self, input: &mut dyn FnMut() -> i64, output: &mut dyn FnMut(i64, bool)) -> Result<(), RuntimeError> {
+        let mut memory: Vec<i64> = vec![0; self.memory_size];
+        // every call pushes only its return address, the stack grows down from the end of the buffer
+        let mut call_stack: Vec<u64> = vec![0; MAX_CALL_DEPTH];
+        let call_stack_end = call_stack.as_mut_ptr_range().end;
+        let mut io = Io { input, output, int_width: self.int_width };
+        let context = &mut io as *mut Io as *mut c_void;
+
+        let function: CompiledFunction = unsafe { std::mem::transmute(self.code) };
+        match function(memory.as_mut_ptr(), print_trampoline, context, read_trampoline, call_stack_end) {
+            EXIT_SUCCESS => Ok(()),
+            EXIT_DIVISION_BY_ZERO => Err(RuntimeError::DivisionByZero),
+            EXIT_STACK_OVERFLOW => Err(RuntimeError::StackOverflow),
+            code => unreachable!("unexpected exit code {}", code),
+        }
+    }
+}
+
+impl Drop for JitProgram {
+    fn drop(&mut self) {
+        unsafe {
+            libc::munmap(self.code, self.code_size);
+        }
+    }
+}
+
+/// compiles the program to x86-64 machine code, that can be executed in the current process
+/// (unbounded integers and doubles are not supported)
+pub fn compile_jit(compiled_program: &CompiledProgram) -> Result<JitProgram, CompilationError> {
+    if compiled_program.int_width == IntWidth::Unbounded {
+        return Err(CompilationError::UnsupportedIntWidth { width: compiled_program.int_width });
+    }
+    if compiled_program.uses_doubles() {
+        return Err(CompilationError::UnsupportedDoubles);
+    }
+    let machine_code = assemble(compiled_program);
+    // frames of the functions are placed after the frame of main, in order of calls
+    let max_function_frame = compiled_program.functions.iter()
+        .map(|function| frame_size(&function.code))
+        .max()
+        .unwrap_or(0);
+    let memory_size = frame_size(&compiled_program.main) as usize + MAX_CALL_DEPTH * max_function_frame as usize;
+    JitProgram::new(&machine_code, memory_size, compiled_program.int_width)
+}
+
+#[cfg(test)]
+mod tests {
+    use instant_parser::instant::ProgParser;
+    use crate::interpreter::interpret;
+    use crate::ir::lower;
+    use crate::opt::{OptOptions, PassManager};
+    use crate::peephole;
+    use crate::common::{IntWidth, Number, RuntimeError, MAX_CALL_DEPTH};
+    use crate::stack::compile_stack;
+    use super::compile_jit;
+
+    fn assert_same_as_interpreter(source_code: &str) {
+        assert_same_as_interpreter_optimized(source_code, &OptOptions::default(), IntWidth::I32);
+    }
+
+    /// the same sequence of values for every execution
+    fn test_input() -> impl FnMut() -> i64 {
+        let mut count = 0;
+        move || {
+            count += 1;
+            count * 3 - 4
+        }
+    }
+
+    fn assert_same_as_interpreter_optimized(source_code: &str, options: &OptOptions, int_width: IntWidth) {
+        let program = ProgParser::new().parse(source_code).unwrap();
+
+        let mut expected: Vec<(Number<i64>, bool)> = vec![];
+        let expected_result = interpret(
+            &program, int_width, &mut test_input(), &mut |val, newline| expected.push((val, newline)),
+        );
+        let mut optimized_program = lower(&program, int_width).unwrap();
+        PassManager::new(options).unwrap().run(&mut optimized_program, &mut |_, _| {}, &mut |_, _| {});
+        let mut actual: Vec<(Number<i64>, bool)> = vec![];
+        let mut compiled_program = compile_stack(&optimized_program);
+        if options.stack_pass_enabled("peephole") {
+            peephole::optimize_program(&mut compiled_program);
+        }
+        let actual_result = compile_jit(&compiled_program).unwrap()
+            .run(&mut test_input(), &mut |val, newline| actual.push((Number::Int(val), newline)));
+
+        assert_eq!(expected, actual);
+        assert_eq!(expected_result.is_ok(), actual_result.is_ok());
+    }
+
+    #[test]
+    fn arithmetic() {
+        assert_same_as_interpreter("1+2*3; 44-2; 7/2; a=2147483647; a+1; a*a; 0-a-1; b=a/(0-1); b; (0-a-1)/(0-1)");
+    }
+
+    #[test]
+    fn division_by_zero() {
+        assert_same_as_interpreter("1; 2; a=0; 3/a; 4");
+    }
+
+    #[test]
+    fn spilled_stack_slots() {
+        // balanced tree of depth 12 needs a stack of depth 13, which doesn't fit in registers
+        fn balanced(depth: u32, ops: &[&str]) -> String {
+            if depth == 0 {
+                return String::from("x");
+            }
+            let op = ops[depth as usize % ops.len()];
+            format!("({}{}{})", balanced(depth - 1, ops), op, balanced(depth - 1, ops))
+        }
+        let source_code = format!(
+            "x=3; {}; {}; y={}; y",
+            balanced(12, &["-", "+", "*"]), balanced(12, &["/", "+", "-"]), balanced(12, &["*", "-"]),
+        );
+        assert_same_as_interpreter(&source_code);
+    }
+
+    #[test]
+    fn shifts() {
+        // multiplications by powers of two are replaced with shifts, which need the first stack register
+        let mut options = OptOptions::default();
+        options.enabled.insert(String::from("simplify"));
+        assert_same_as_interpreter_optimized("x=3; x*8; (x*2)+((x*4)*(x*2+x*16)); x*2147483647*(0-2147483647-1)", &options, IntWidth::I32);
+        assert_same_as_interpreter("x=-7; x << 33; x >> 1; x >> 31; (x << x) >> (x + 40); 1 << (x >> 1 & 31)");
+    }
+
+    #[test]
+    fn remainder_and_bitwise_operations() {
+        assert_same_as_interpreter("a=-7; a % 3; 7 % a; -2147483648 % -1; a & 12; a | 12; a ^ -1; (a % 4) ^ (a & a | 1)");
+        assert_same_as_interpreter("1; a=0; 2 % a; 3");
+    }
+
+    #[test]
+    fn comparisons_and_conditionals() {
+        assert_same_as_interpreter("a=-7; a < 0; a == -7; 3 != 3; a >= a; a > 2147483647; a <= -2147483648");
+        assert_same_as_interpreter("a=0; if a then 1 / a else 2; if a == 0 then (if a < 1 then 3 else 4) else 5");
+        assert_same_as_interpreter("a=2; a * (a + (if a > 1 then a * (a - a * (a + 1)) else 0)) - (a >= 2)");
+    }
+
+    #[test]
+    fn loops() {
+        assert_same_as_interpreter("i=0; s=0; while (i < 10) { s = s + i * (i - 3); i = i + 1 }; s; i");
+        assert_same_as_interpreter("i=3; while (i) { j=0; while (j < i) { i * 10 + j; j = j + 1 }; i = i - 1 }; 7 / i");
+    }
+
+    #[test]
+    fn functions() {
+        assert_same_as_interpreter("fn f(a, b) = a * 2 - b; x = 5; f(x, 1) + f(f(x, x), f(1, x)); f(x, x)");
+        assert_same_as_interpreter("fn fib(n) = if n < 2 then n else fib(n - 1) + fib(n - 2); fib(20)");
+        assert_same_as_interpreter("fn p(x) { x; return x + 1 }; fn d(x) = p(x) / x; p(1) * p(2); d(3); d(0); 4");
+    }
+
+    #[test]
+    fn reads() {
+        assert_same_as_interpreter("read(); a = read(); a * read() - read(); (read() << 1) + a");
+        assert_same_as_interpreter("fn f(x) = read() - x; a = 1 + read() * f(read()); a; b = 3 + 4 * 5; read() / b");
+        assert_same_as_interpreter("i = 0; while (read() < 20) { i = i + 1 }; i");
+    }
+
+    #[test]
+    fn print_statements() {
+        assert_same_as_interpreter("a = 7; print a, a * 2, -a; print a; b = a - 7; print 1, a / b, 2");
+        assert_same_as_interpreter("pragma explicit_print; fn f(x) { print x, x; x; return x + 1 }; f(1) * 3; print f(2), 3");
+    }
+
+    #[test]
+    fn wide_integers() {
+        let mut options = OptOptions::default();
+        options.enabled.insert(String::from("simplify"));
+        let source_code = "a=2147483647; a+1; a*a; a*a*a*a; 5000000000*3; b=-9223372036854775808; b/-1; b%-1; b-1; \
+            x=-7; x<<33; x>>63; (x<<x)>>(x+40); x*4294967296; a < 5000000000; read() * 3000000000";
+        assert_same_as_interpreter_optimized(source_code, &options, IntWidth::I64);
+        assert_same_as_interpreter_optimized(source_code, &OptOptions::default(), IntWidth::I64);
+    }
+
+    #[test]
+    fn nested_calls_are_limited() {
+        // down(n) makes n + 1 nested calls, so only the first one fits in the limit
+        let source_code = format!(
+            "fn down(n) = if n then down(n - 1) + 1 else 0; down({}); down({})", MAX_CALL_DEPTH - 1, MAX_CALL_DEPTH
+        );
+        let program = ProgParser::new().parse(&source_code).unwrap();
+        let compiled_program = compile_stack(&lower(&program, IntWidth::I32).unwrap());
+        let mut output: Vec<i64> = vec![];
+        let result = compile_jit(&compiled_program).unwrap().run(&mut || 0, &mut |val, _| output.push(val));
+        assert_eq!(output, vec![MAX_CALL_DEPTH as i64 - 1]);
+        assert!(matches!(result, Err(RuntimeError::StackOverflow)));
+    }
+}
+
@@ -1,8 +1,20 @@
+pub mod ir;
+pub mod opt;
 pub mod stack;
+pub mod peephole;
 pub mod jasmin;
 pub mod llvm;
+pub mod c;
+pub mod riscv;
+pub mod cil;
+pub mod interpreter;
+pub mod vm;
+#[cfg(all(target_arch = "x86_64", unix))]
+pub mod jit;
 
-mod common;
+pub mod common;
+pub mod types;
+pub mod bigint;
 
 #[cfg(test)]
 mod tests {
@@ -1,163 +1,408 @@
-use instant_parser::ast;
-use crate::common::CompilationError;
+use instant_parser::ast::{self, Type};
+use crate::ir;
+use crate::common::IntWidth;
 
-use std::collections::HashSet;
-use instant_parser::ast::Stmt;
+use std::collections::HashMap;
 
 pub trait FormatLLVM {
     fn format_llvm(&self) -> String;
 }
 
-enum CompilationResult {
+pub enum CompilationResult {
     Register { id: u32 },
-    Constant { val: i32 },
-    None,
+    Constant { val: i64 },
+    /// double constants are written with the hexadecimal representation of their bits, which is always exact
+    DoubleConstant { bits: u64 },
 }
 
 impl FormatLLVM for CompilationResult {
     fn format_llvm(&self) -> String {
         match self {
             CompilationResult::Constant { val } => val.to_string(),
+            CompilationResult::DoubleConstant { bits } => format!("0x{:016X}", bits),
             CompilationResult::Register { id } => format!("%r{}", id),
-            CompilationResult::None => String::from(""),
         }
     }
 }
 
+/// Values of temporaries: constants are never stored in registers,
+/// they are substituted directly into the instructions that use them
+pub type Values = HashMap<ir::Temp, CompilationResult>;
+
+/// operations are compiled with types of all temporaries of their program (see `ir::Program::temp_types`)
 pub trait CompileLLVM {
-    fn compile_llvm(
-        &self,
-        available_reg: &mut u32,
-        variables: &mut HashSet<String>,
-    ) -> Result<CompiledCode, CompilationError>;
-}
-
-pub struct CompiledCode {
-    instructions: Vec<String>,
-    result: CompilationResult,
-}
-
-impl CompileLLVM for ast::Prog {
-    fn compile_llvm(
-        &self,
-        available_reg: &mut u32,
-        variables: &mut HashSet<String>
-    ) -> Result<CompiledCode, CompilationError> {
-        let mut instructions: Vec<String> = vec![];
-        for stmt in self.stmts.iter() {
-            let mut compiled_stmt = stmt.compile_llvm(
-                available_reg,
-                variables,
-            )?;
-            instructions.append(&mut compiled_stmt.instructions);
-        }
-        let compiled_program = CompiledCode {
-            instructions,
-            result: CompilationResult::None
-        };
-        Ok(compiled_program)
+    fn compile_llvm(&self, values: &mut Values, program: &ir::Program, temp_types: &[Type]) -> Vec<String>;
+}
+
+/// LLVM type of Instant values, unbounded integers are pointers to values allocated by the runtime
+fn int_type(int_width: IntWidth) -> &'static str {
+    match int_width {
+        IntWidth::I32 => "i32",
+        IntWidth::I64 => "i64",
+        IntWidth::Unbounded => "i32*",
     }
 }
 
-impl CompileLLVM for ast::Stmt {
-    fn compile_llvm(
-        &self,
-        available_reg: &mut u32,
-        variables: &mut HashSet<String>
-    ) -> Result<CompiledCode, CompilationError> {
-        match self {
-            Stmt::Expr { expr } => {
-                let mut compiled_expr = expr.compile_llvm(available_reg, variables)?;
-                let print_instr = format!(
-                    "call void @printInt(i32 {})",
-                    compiled_expr.result.format_llvm(),
-                );
-                compiled_expr.instructions.push(print_instr);
-                compiled_expr.result = CompilationResult::None;
-                Ok(compiled_expr)
-            },
-            Stmt::Decl { var, expr } => {
-                let mut compiled_expr = expr.compile_llvm(available_reg, variables)?;
-
-                if !variables.contains(var) {
-                    // allocate memory for the new variable
-                    let alloc_instr = format!(
-                        "%{}ptr = alloca i32", var
-                    );
-                    compiled_expr.instructions.push(alloc_instr);
-                    variables.insert(var.clone());
-                }
-
-                // update value of existing variable
-                let store_instr = format!(
-                    "store i32 {}, i32* %{}ptr",
-                    compiled_expr.result.format_llvm(),
-                    var
-                );
-                compiled_expr.instructions.push(store_instr);
-                compiled_expr.result = CompilationResult::None;
-                Ok(compiled_expr)
-            },
-        }
+/// LLVM type of values of the type, integers have the type of the integer width
+fn value_type(ty: Type, int_width: IntWidth) -> &'static str {
+    match ty {
+        Type::Int => int_type(int_width),
+        Type::Double => "double",
     }
 }
 
-impl CompileLLVM for ast::Expr {
-    fn compile_llvm(
-        &self,
-        available_reg: &mut u32,
-        variables: &mut HashSet<String>
-    ) -> Result<CompiledCode, CompilationError> {
-        match self {
-            ast::Expr::Binary { left, op, right } => {
-                let mut compiled_instructions: Vec<String> = vec![];
+/// Functions of the runtime: printing a value followed by a newline, printing it followed by a space,
+/// and reading a value, for integers of the given width
+fn runtime_functions(int_width: IntWidth) -> (&'static str, &'static str, &'static str) {
+    match int_width {
+        IntWidth::I32 => ("@printInt", "@printIntInline", "@readInt"),
+        IntWidth::I64 => ("@printLong", "@printLongInline", "@readLong"),
+        IntWidth::Unbounded => ("@printBig", "@printBigInline", "@readBig"),
+    }
+}
+
+/// Functions of the runtime dividing integers of the given width and computing the remainder, which exit
+/// with code 1 on division by zero (`sdiv` and `srem` are undefined then, and for the minimal value divided by -1)
+fn division_functions(int_width: IntWidth) -> (&'static str, &'static str) {
+    match int_width {
+        IntWidth::I32 => ("@divInt", "@remInt"),
+        IntWidth::I64 => ("@divLong", "@remLong"),
+        // unbounded integers are divided by `@bigDiv` and `@bigRem`, like other operations on them
+        IntWidth::Unbounded => unreachable!(),
+    }
+}
+
+/// Declarations of the functions of the runtime operating on unbounded integers
+const BIG_FUNCTIONS: [&str; 14] = [
+    "declare i32* @bigFromLong(i64)",
+    "declare i32* @bigAdd(i32*, i32*)",
+    "declare i32* @bigSub(i32*, i32*)",
+    "declare i32* @bigMul(i32*, i32*)",
+    "declare i32* @bigDiv(i32*, i32*)",
+    "declare i32* @bigRem(i32*, i32*)",
+    "declare i32* @bigAnd(i32*, i32*)",
+    "declare i32* @bigOr(i32*, i32*)",
+    "declare i32* @bigXor(i32*, i32*)",
+    "declare i32* @bigShl(i32*, i32*)",
+    "declare i32* @bigShr(i32*, i32*)",
+    "declare i32* @bigNeg(i32*)",
+    "declare i32 @bigCompare(i32*, i32*)",
+    "declare i32 @bigSign(i32*)",
+];
+
+/// Intrinsic converting doubles to integers of the width, which saturates and converts NaN to 0
+fn double_to_int(int_width: IntWidth) -> String {
+    let ty = int_type(int_width);
+    format!("@llvm.fptosi.sat.{}.f64", ty)
+}
+
+/// instruction of an arithmetic operation or a comparison of doubles, comparisons are ordered (false for NaN),
+/// except for `!=` which holds for NaN
+fn double_instruction(op: ast::Opcode) -> &'static str {
+    match op {
+        ast::Opcode::Add => "fadd",
+        ast::Opcode::Sub => "fsub",
+        ast::Opcode::Mul => "fmul",
+        ast::Opcode::Div => "fdiv",
+        ast::Opcode::Rem => "frem",
+        ast::Opcode::Eq => "fcmp oeq",
+        ast::Opcode::Ne => "fcmp une",
+        ast::Opcode::Lt => "fcmp olt",
+        ast::Opcode::Le => "fcmp ole",
+        ast::Opcode::Gt => "fcmp ogt",
+        ast::Opcode::Ge => "fcmp oge",
+        // bitwise operations and shifts are rejected for doubles by the type checker
+        ast::Opcode::And | ast::Opcode::Or | ast::Opcode::Xor | ast::Opcode::Shl | ast::Opcode::Shr => unreachable!(),
+    }
+}
+
+/// function of the runtime computing the result of an arithmetic operation on unbounded integers
+fn big_function(op: ast::Opcode) -> &'static str {
+    match op {
+        ast::Opcode::Add => "@bigAdd",
+        ast::Opcode::Sub => "@bigSub",
+        ast::Opcode::Mul => "@bigMul",
+        ast::Opcode::Div => "@bigDiv",
+        ast::Opcode::Rem => "@bigRem",
+        ast::Opcode::And => "@bigAnd",
+        ast::Opcode::Or => "@bigOr",
+        ast::Opcode::Xor => "@bigXor",
+        ast::Opcode::Shl => "@bigShl",
+        ast::Opcode::Shr => "@bigShr",
+        ast::Opcode::Eq | ast::Opcode::Ne | ast::Opcode::Lt | ast::Opcode::Le | ast::Opcode::Gt
+        | ast::Opcode::Ge => unreachable!(),
+    }
+}
 
-                let mut lhs = left.compile_llvm(available_reg, variables)?;
-                compiled_instructions.append(&mut lhs.instructions);
-                let mut rhs = right.compile_llvm(available_reg, variables)?;
-                compiled_instructions.append(&mut rhs.instructions);
+fn variable_pointer(variables: &[String], var: ir::Var) -> String {
+    format!("%{}ptr", variables[var as usize])
+}
 
-                let current_reg = CompilationResult::Register { id: available_reg.clone() };
+/// Instant functions are prefixed, so that they don't collide with `main` and functions of the runtime
+fn function_name(function: &str) -> String {
+    format!("@instant.{}", function)
+}
+
+impl CompileLLVM for ir::Op {
+    fn compile_llvm(&self, values: &mut Values, program: &ir::Program, temp_types: &[Type]) -> Vec<String> {
+        let (variables, int_width) = (&program.variables, program.int_width);
+        let ty = int_type(int_width);
+        let big = int_width == IntWidth::Unbounded;
+        match self {
+            // unbounded integers are allocated by the runtime, so their constants are stored in registers
+            ir::Op::Const { dst, val } if big => {
+                let current_reg = CompilationResult::Register { id: *dst };
+                let const_instr = format!("{} = call i32* @bigFromLong(i64 {})", current_reg.format_llvm(), val);
+                values.insert(*dst, current_reg);
+                vec![const_instr]
+            },
+            // unbounded integers are compared by the runtime, which results in -1, 0 or 1
+            ir::Op::BinOp { dst, op, lhs, rhs } if big && op.is_comparison() => {
+                let current_reg = CompilationResult::Register { id: *dst };
+                let reg = current_reg.format_llvm();
+                let instructions = vec![
+                    format!(
+                        "{}.cmp = call i32 @bigCompare(i32* {}, i32* {})",
+                        reg, values[lhs].format_llvm(), values[rhs].format_llvm(),
+                    ),
+                    format!("{}.i1 = {} i32 {}.cmp, 0", reg, op.format_llvm(), reg),
+                    format!("{}.i64 = zext i1 {}.i1 to i64", reg, reg),
+                    format!("{} = call i32* @bigFromLong(i64 {}.i64)", reg, reg),
+                ];
+                values.insert(*dst, current_reg);
+                instructions
+            },
+            ir::Op::BinOp { dst, op, lhs, rhs } if big => {
+                let current_reg = CompilationResult::Register { id: *dst };
                 let current_instr = format!(
-                    "{} = {} {}, {}",
+                    "{} = call i32* {}(i32* {}, i32* {})",
                     current_reg.format_llvm(),
-                    op.format_llvm(),
-                    lhs.result.format_llvm(),
-                    rhs.result.format_llvm(),
+                    big_function(*op),
+                    values[lhs].format_llvm(),
+                    values[rhs].format_llvm(),
                 );
-                compiled_instructions.push(current_instr);
-                *available_reg += 1;
-
-                let compiled_code = CompiledCode {
-                    instructions: compiled_instructions,
-                    result: current_reg
-                };
-                Ok(compiled_code)
+                values.insert(*dst, current_reg);
+                vec![current_instr]
+            },
+            ir::Op::Neg { dst, src } if big => {
+                let current_reg = CompilationResult::Register { id: *dst };
+                let current_instr = format!(
+                    "{} = call i32* @bigNeg(i32* {})", current_reg.format_llvm(), values[src].format_llvm()
+                );
+                values.insert(*dst, current_reg);
+                vec![current_instr]
+            },
+            ir::Op::Branch { cond, then_label, else_label } if big => {
+                let condition = format!("%L{}.cond", then_label);
+                vec![
+                    format!("%L{}.sign = call i32 @bigSign(i32* {})", then_label, values[cond].format_llvm()),
+                    format!("{} = icmp ne i32 %L{}.sign, 0", condition, then_label),
+                    format!("br i1 {}, label %L{}, label %L{}", condition, then_label, else_label),
+                ]
+            },
+            ir::Op::Const { dst, val } => {
+                values.insert(*dst, CompilationResult::Constant { val: *val });
+                vec![]
             },
-            ast::Expr::Number { val } => {
-                let compiled_code = CompiledCode {
-                    instructions: vec![],
-                    result: CompilationResult::Constant { val: val.clone() }
+            ir::Op::BinOp { dst, op: op @ (ast::Opcode::Shl | ast::Opcode::Shr), lhs, rhs } => {
+                // shifts by the width of the type or more bits are poison in LLVM, so the amount is masked as in JVM
+                let mask = int_width.bits() as i64 - 1;
+                let mut instructions = vec![];
+                let amount = match values[rhs] {
+                    CompilationResult::Constant { val } => (val & mask).to_string(),
+                    _ => {
+                        instructions.push(format!("%r{}.amount = and {} {}, {}", dst, ty, values[rhs].format_llvm(), mask));
+                        format!("%r{}.amount", dst)
+                    },
                 };
-                Ok(compiled_code)
+                let current_reg = CompilationResult::Register { id: *dst };
+                instructions.push(format!(
+                    "{} = {} {} {}, {}", current_reg.format_llvm(), op.format_llvm(), ty, values[lhs].format_llvm(), amount
+                ));
+                values.insert(*dst, current_reg);
+                instructions
             },
-            ast::Expr::Variable { var } => {
-                if variables.contains(var) {
-                    let current_reg = CompilationResult::Register { id: available_reg.clone() };
-                    let current_instr = format!(
-                        "{} = load i32, i32* %{}ptr",
+            ir::Op::BinOp { dst, op: op @ (ast::Opcode::Div | ast::Opcode::Rem), lhs, rhs } => {
+                let current_reg = CompilationResult::Register { id: *dst };
+                let current_instr = match values[rhs] {
+                    // constant divisors other than 0 and -1 are safe to use directly
+                    CompilationResult::Constant { val } if val != 0 && val != -1 => format!(
+                        "{} = {} {} {}, {}",
                         current_reg.format_llvm(),
-                        var
-                    );
-                    *available_reg += 1;
-                    let compiled_code = CompiledCode {
-                        instructions: vec![current_instr],
-                        result: current_reg,
-                    };
-                    Ok(compiled_code)
+                        op.format_llvm(),
+                        ty,
+                        values[lhs].format_llvm(),
+                        val,
+                    ),
+                    _ => {
+                        let (div, rem) = division_functions(int_width);
+                        format!(
+                            "{} = call {} {}({} {}, {} {})",
+                            current_reg.format_llvm(),
+                            ty,
+                            if *op == ast::Opcode::Div { div } else { rem },
+                            ty,
+                            values[lhs].format_llvm(),
+                            ty,
+                            values[rhs].format_llvm(),
+                        )
+                    },
+                };
+                values.insert(*dst, current_reg);
+                vec![current_instr]
+            },
+            ir::Op::BinOp { dst, op, lhs, rhs } if op.is_comparison() => {
+                // comparisons result in i1, which is extended to 0 or 1
+                let current_reg = CompilationResult::Register { id: *dst };
+                let compare_instr = format!(
+                    "{}.i1 = {} {} {}, {}",
+                    current_reg.format_llvm(),
+                    op.format_llvm(),
+                    ty,
+                    values[lhs].format_llvm(),
+                    values[rhs].format_llvm(),
+                );
+                let extend_instr = format!(
+                    "{} = zext i1 {}.i1 to {}", current_reg.format_llvm(), current_reg.format_llvm(), ty
+                );
+                values.insert(*dst, current_reg);
+                vec![compare_instr, extend_instr]
+            },
+            ir::Op::BinOp { dst, op, lhs, rhs } => {
+                let current_reg = CompilationResult::Register { id: *dst };
+                let current_instr = format!(
+                    "{} = {} {} {}, {}",
+                    current_reg.format_llvm(),
+                    op.format_llvm(),
+                    ty,
+                    values[lhs].format_llvm(),
+                    values[rhs].format_llvm(),
+                );
+                values.insert(*dst, current_reg);
+                vec![current_instr]
+            },
+            ir::Op::Neg { dst, src } => {
+                let current_reg = CompilationResult::Register { id: *dst };
+                let current_instr = format!("{} = sub {} 0, {}", current_reg.format_llvm(), ty, values[src].format_llvm());
+                values.insert(*dst, current_reg);
+                vec![current_instr]
+            },
+            ir::Op::Load { dst, var } => {
+                let ty = value_type(program.variable_types[*var as usize], int_width);
+                let current_reg = CompilationResult::Register { id: *dst };
+                let current_instr = format!(
+                    "{} = load {}, {}* {}",
+                    current_reg.format_llvm(),
+                    ty,
+                    ty,
+                    variable_pointer(variables, *var),
+                );
+                values.insert(*dst, current_reg);
+                vec![current_instr]
+            },
+            ir::Op::Store { var, src } => {
+                let ty = value_type(program.variable_types[*var as usize], int_width);
+                let store_instr = format!(
+                    "store {} {}, {}* {}",
+                    ty,
+                    values[src].format_llvm(),
+                    ty,
+                    variable_pointer(variables, *var),
+                );
+                vec![store_instr]
+            },
+            // values except the last one are followed by a space instead of a newline
+            ir::Op::Print { srcs } => {
+                let (print, print_inline, _) = runtime_functions(int_width);
+                srcs.iter().enumerate()
+                    .map(|(index, src)| {
+                        let last = index == srcs.len() - 1;
+                        let function = match temp_types[*src as usize] {
+                            Type::Int if last => print,
+                            Type::Int => print_inline,
+                            Type::Double if last => "@printDouble",
+                            Type::Double => "@printDoubleInline",
+                        };
+                        let ty = value_type(temp_types[*src as usize], int_width);
+                        format!("call void {}({} {})", function, ty, values[src].format_llvm())
+                    })
+                    .collect()
+            },
+            ir::Op::Label { label } => vec![format!("L{}:", label)],
+            ir::Op::Jump { label } => vec![format!("br label %L{}", label)],
+            ir::Op::Branch { cond, then_label, else_label } => {
+                // condition is named after the branch, as the same temporary may be a condition of many branches
+                let condition = format!("%L{}.cond", then_label);
+                vec![
+                    format!("{} = icmp ne {} {}, 0", condition, ty, values[cond].format_llvm()),
+                    format!("br i1 {}, label %L{}, label %L{}", condition, then_label, else_label),
+                ]
+            },
+            ir::Op::Phi { dst, incoming } => {
+                let current_reg = CompilationResult::Register { id: *dst };
+                let incoming: Vec<String> = incoming.iter()
+                    .map(|(temp, label)| format!("[{}, %L{}]", values[temp].format_llvm(), label))
+                    .collect();
+                let ty = value_type(temp_types[*dst as usize], int_width);
+                let phi_instr = format!("{} = phi {} {}", current_reg.format_llvm(), ty, incoming.join(", "));
+                values.insert(*dst, current_reg);
+                vec![phi_instr]
+            },
+            ir::Op::Call { dst, function, args } => {
+                let current_reg = CompilationResult::Register { id: *dst };
+                let args: Vec<String> = args.iter()
+                    .map(|arg| format!("{} {}", ty, values[arg].format_llvm()))
+                    .collect();
+                let call_instr = format!(
+                    "{} = call {} {}({})", current_reg.format_llvm(), ty, function_name(function), args.join(", ")
+                );
+                values.insert(*dst, current_reg);
+                vec![call_instr]
+            },
+            ir::Op::Return { src } => vec![format!("ret {} {}", ty, values[src].format_llvm())],
+            ir::Op::Read { dst } => {
+                let current_reg = CompilationResult::Register { id: *dst };
+                let (_, _, read) = runtime_functions(int_width);
+                let read_instr = format!("{} = call {} {}()", current_reg.format_llvm(), ty, read);
+                values.insert(*dst, current_reg);
+                vec![read_instr]
+            },
+            ir::Op::DoubleConst { dst, val } => {
+                values.insert(*dst, CompilationResult::DoubleConstant { bits: val.to_bits() });
+                vec![]
+            },
+            ir::Op::DoubleBinOp { dst, op, lhs, rhs } => {
+                let current_reg = CompilationResult::Register { id: *dst };
+                let reg = current_reg.format_llvm();
+                let (lhs, rhs) = (values[lhs].format_llvm(), values[rhs].format_llvm());
+                let instructions = if op.is_comparison() {
+                    vec![
+                        format!("{}.i1 = {} double {}, {}", reg, double_instruction(*op), lhs, rhs),
+                        format!("{} = zext i1 {}.i1 to {}", reg, reg, ty),
+                    ]
                 } else {
-                    Err(CompilationError::UnidentifiedVariable { identifier: var.clone() })
-                }
+                    vec![format!("{} = {} double {}, {}", reg, double_instruction(*op), lhs, rhs)]
+                };
+                values.insert(*dst, current_reg);
+                instructions
+            },
+            ir::Op::DoubleNeg { dst, src } => {
+                let current_reg = CompilationResult::Register { id: *dst };
+                let current_instr = format!("{} = fneg double {}", current_reg.format_llvm(), values[src].format_llvm());
+                values.insert(*dst, current_reg);
+                vec![current_instr]
+            },
+            ir::Op::Convert { dst, src, to } => {
+                let current_reg = CompilationResult::Register { id: *dst };
+                let src = values[src].format_llvm();
+                let current_instr = match to {
+                    Type::Double => format!("{} = sitofp {} {} to double", current_reg.format_llvm(), ty, src),
+                    Type::Int => format!(
+                        "{} = call {} {}(double {})", current_reg.format_llvm(), ty, double_to_int(int_width), src
+                    ),
+                };
+                values.insert(*dst, current_reg);
+                vec![current_instr]
             },
         }
     }
@@ -166,31 +411,89 @@ impl CompileLLVM for ast::Expr {
 impl FormatLLVM for ast::Opcode {
     fn format_llvm(&self) -> String {
         let op_str = match self {
-            ast::Opcode::Add => {"add i32"},
-            ast::Opcode::Sub => {"sub i32"},
-            ast::Opcode::Mul => {"mul i32"},
-            ast::Opcode::Div => {"sdiv i32"},
+            ast::Opcode::Add => {"add"},
+            ast::Opcode::Sub => {"sub"},
+            ast::Opcode::Mul => {"mul"},
+            ast::Opcode::Div => {"sdiv"},
+            ast::Opcode::Rem => {"srem"},
+            ast::Opcode::And => {"and"},
+            ast::Opcode::Or => {"or"},
+            ast::Opcode::Xor => {"xor"},
+            ast::Opcode::Shl => {"shl"},
+            ast::Opcode::Shr => {"ashr"},
+            ast::Opcode::Eq => {"icmp eq"},
+            ast::Opcode::Ne => {"icmp ne"},
+            ast::Opcode::Lt => {"icmp slt"},
+            ast::Opcode::Le => {"icmp sle"},
+            ast::Opcode::Gt => {"icmp sgt"},
+            ast::Opcode::Ge => {"icmp sge"},
         };
         String::from(op_str)
     }
 }
 
-pub fn compile_llvm(program: &ast::Prog) -> Result<Vec<String>, CompilationError> {
+/// compiles allocations of variables (with parameters stored in them) and the operations of the program
+fn compile_body(program: &ir::Program) -> Vec<String> {
+    let mut instructions = vec![];
+    let ty = int_type(program.int_width);
+
+    // all variables are allocated exactly once, at the beginning of the function
+    for (var, var_type) in program.variable_types.iter().enumerate() {
+        let var_type = value_type(*var_type, program.int_width);
+        instructions.push(format!("{} = alloca {}", variable_pointer(&program.variables, var as ir::Var), var_type));
+    }
+    for param in 0..program.params {
+        instructions.push(format!(
+            "store {} %arg{}, {}* {}", ty, param, ty, variable_pointer(&program.variables, param)
+        ));
+    }
+
+    let mut values: Values = HashMap::new();
+    let temp_types = program.temp_types();
+    for op in program.ops.iter() {
+        instructions.append(&mut op.compile_llvm(&mut values, program, &temp_types));
+    }
+    instructions
+}
+
+/// compiles the program to LLVM IR, with integers of the type of its integer width and doubles as `double`
+/// (`main` always returns i32, as its result is the exit code)
+pub fn compile_llvm(program: &ir::Program) -> Vec<String> {
+    let ty = int_type(program.int_width);
+    let (print, print_inline, read) = runtime_functions(program.int_width);
     let mut instructions = vec![
-        String::from("declare void @printInt(i32)"),
-        String::from("define i32 @main() {"),
+        format!("declare void {}({})", print, ty),
+        format!("declare void {}({})", print_inline, ty),
+        format!("declare {} {}()", ty, read),
     ];
-    let mut available_reg = 0 as u32;
-    let mut used_variables: HashSet<String> = HashSet::new();
-    let mut compilation_result = program.compile_llvm(
-        &mut available_reg,
-        &mut used_variables
-    )?;
-    instructions.append(&mut compilation_result.instructions);
+    if program.int_width == IntWidth::Unbounded {
+        instructions.extend(BIG_FUNCTIONS.iter().map(|declaration| declaration.to_string()));
+    } else {
+        let (div, rem) = division_functions(program.int_width);
+        instructions.push(format!("declare {} {}({}, {})", ty, div, ty, ty));
+        instructions.push(format!("declare {} {}({}, {})", ty, rem, ty, ty));
+    }
+    if program.uses_doubles() {
+        instructions.append(&mut vec![
+            String::from("declare void @printDouble(double)"),
+            String::from("declare void @printDoubleInline(double)"),
+            format!("declare {} {}(double)", ty, double_to_int(program.int_width)),
+        ]);
+    }
 
+    // every function body ends with a return, added when it is lowered
+    for function in program.functions.iter() {
+        let params: Vec<String> = (0..function.body.params).map(|param| format!("{} %arg{}", ty, param)).collect();
+        instructions.push(format!("define {} {}({}) {{", ty, function_name(&function.name), params.join(", ")));
+        instructions.append(&mut compile_body(&function.body));
+        instructions.push(String::from("}"));
+    }
+
+    instructions.push(String::from("define i32 @main() {"));
+    instructions.append(&mut compile_body(program));
     instructions.append(&mut vec![
         String::from("ret i32 0"),
         String::from("}"),
     ]);
-    Ok(instructions)
-}
+    instructions
+}
\ No newline at end of file
@@ -0,0 +1,233 @@
+mod algebraic;
+mod common_subexpressions;
+mod constant_folding;
+mod dead_code;
+mod dead_stores;
+
+use crate::ir;
+use crate::common::CompilationError;
+use algebraic::AlgebraicSimplification;
+use common_subexpressions::CommonSubexpressionElimination;
+use constant_folding::ConstantFolding;
+use dead_code::DeadCodeElimination;
+use dead_stores::DeadStoreElimination;
+
+use std::collections::HashSet;
+use std::str::FromStr;
+
+/// Optimization pass working on the intermediate representation
+pub trait Pass {
+    /// name used in command line flags (`--enable-pass`, `--disable-pass`, `--print-after`)
+    fn name(&self) -> &'static str;
+    /// transforms the program in place, returns true if anything was changed;
+    /// `report` receives human-readable descriptions of the changes, printed in verbose mode
+    fn run(&self, program: &mut ir::Program, report: &mut dyn FnMut(String)) -> bool;
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum OptLevel {
+    O0,
+    O1,
+    O2,
+}
+
+impl FromStr for OptLevel {
+    type Err = String;
+
+    fn from_str(level: &str) -> Result<Self, Self::Err> {
+        match level {
+            "0" => Ok(OptLevel::O0),
+            "1" => Ok(OptLevel::O1),
+            "2" => Ok(OptLevel::O2),
+            _ => Err(format!("unknown optimization level: {}", level)),
+        }
+    }
+}
+
+/// Optimization settings selected in the command line
+#[derive(Debug, Clone)]
+pub struct OptOptions {
+    pub level: OptLevel,
+    /// passes enabled regardless of the level
+    pub enabled: HashSet<String>,
+    /// passes disabled regardless of the level, takes precedence over `enabled`
+    pub disabled: HashSet<String>,
+    /// passes after which the program is dumped
+    pub print_after: HashSet<String>,
+    /// whether passes should report the changes they make
+    pub verbose: bool,
+}
+
+impl Default for OptOptions {
+    fn default() -> Self {
+        OptOptions {
+            level: OptLevel::O0,
+            enabled: HashSet::new(),
+            disabled: HashSet::new(),
+            print_after: HashSet::new(),
+            verbose: false,
+        }
+    }
+}
+
+impl OptOptions {
+    fn is_enabled(&self, name: &str, min_level: OptLevel) -> bool {
+        !self.disabled.contains(name) && (self.level >= min_level || self.enabled.contains(name))
+    }
+
+    /// whether the pass working on the abstract stack code is enabled
+    pub fn stack_pass_enabled(&self, name: &str) -> bool {
+        STACK_PASSES.iter().any(|(pass_name, min_level)| *pass_name == name && self.is_enabled(name, *min_level))
+    }
+}
+
+/// Pipeline of all available passes, in the order they are executed,
+/// with the minimal optimization level at which each of them is enabled
+fn registered_passes() -> Vec<(Box<dyn Pass>, OptLevel)> {
+    vec![
+        (Box::new(ConstantFolding), OptLevel::O1),
+        (Box::new(AlgebraicSimplification), OptLevel::O1),
+        (Box::new(CommonSubexpressionElimination), OptLevel::O1),
+        (Box::new(DeadStoreElimination), OptLevel::O1),
+        (Box::new(DeadCodeElimination), OptLevel::O1),
+    ]
+}
+
+/// Passes working on the abstract stack code instead of the intermediate representation,
+/// executed by the compilers after the stack compiler, see `OptOptions::stack_pass_enabled`
+const STACK_PASSES: &[(&str, OptLevel)] = &[
+    ("peephole", OptLevel::O1),
+];
+
+/// names of all available passes, in the order they are executed
+pub fn pass_names() -> Vec<&'static str> {
+    registered_passes().iter().map(|(pass, _)| pass.name())
+        .chain(STACK_PASSES.iter().map(|(name, _)| *name))
+        .collect()
+}
+
+/// Maximal number of pipeline repetitions at `-O2`, where passes are repeated until nothing changes
+const MAX_ITERATIONS: usize = 16;
+
+pub struct PassManager {
+    passes: Vec<Box<dyn Pass>>,
+    print_after: HashSet<String>,
+    verbose: bool,
+    iterations: usize,
+}
+
+impl PassManager {
+    /// selects passes from the registered pipeline according to the options
+    pub fn new(options: &OptOptions) -> Result<PassManager, CompilationError> {
+        let known_names = pass_names();
+        let flagged_names = options.enabled.iter()
+            .chain(options.disabled.iter())
+            .chain(options.print_after.iter());
+        for name in flagged_names {
+            if !known_names.contains(&name.as_str()) {
+                return Err(CompilationError::UnknownPass { name: name.clone() });
+            }
+        }
+
+        let passes = registered_passes().into_iter()
+            .filter(|(pass, min_level)| options.is_enabled(pass.name(), *min_level))
+            .map(|(pass, _)| pass)
+            .collect();
+        let iterations = if options.level == OptLevel::O2 { MAX_ITERATIONS } else { 1 };
+        Ok(PassManager {
+            passes,
+            print_after: options.print_after.clone(),
+            verbose: options.verbose,
+            iterations,
+        })
+    }
+
+    /// names of the selected passes, in the order they are executed
+    pub fn pass_names(&self) -> Vec<&'static str> {
+        self.passes.iter().map(|pass| pass.name()).collect()
+    }
+
+    /// runs the selected passes on the main program and every function, `dump` is called after every pass selected by `print_after`,
+    /// and `report` is called with the name of the pass and description of every change in verbose mode
+    pub fn run(
+        &self,
+        program: &mut ir::Program,
+        dump: &mut dyn FnMut(&str, &ir::Program),
+        report: &mut dyn FnMut(&str, &str),
+    ) {
+        for _ in 0..self.iterations {
+            let mut changed = false;
+            for pass in self.passes.iter() {
+                let mut report_change = |message: String| {
+                    if self.verbose {
+                        report(pass.name(), &message);
+                    }
+                };
+                // functions are optimized separately, each pass runs on all of them before the next pass
+                for function in program.functions.iter_mut() {
+                    changed |= pass.run(&mut function.body, &mut report_change);
+                }
+                changed |= pass.run(program, &mut report_change);
+                if let Err(e) = program.verify() {
+                    panic!("invalid program after {}: {}", pass.name(), e);
+                }
+                if self.print_after.contains(pass.name()) {
+                    dump(pass.name(), program);
+                }
+            }
+            if !changed {
+                break;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use crate::common::IntWidth;
+    use instant_parser::instant::ProgParser;
+
+    pub fn lower_source(source: &str) -> ir::Program {
+        ir::lower(&ProgParser::new().parse(source).unwrap(), IntWidth::I32).unwrap()
+    }
+
+    /// lowers the program and runs a single pass on it
+    pub fn optimize_source(pass: &dyn Pass, source: &str) -> ir::Program {
+        let mut program = lower_source(source);
+        pass.run(&mut program, &mut |_| {});
+        program.verify().unwrap();
+        program
+    }
+
+    #[test]
+    fn selects_passes_by_level_and_flags() {
+        let mut options = OptOptions::default();
+        assert!(PassManager::new(&options).unwrap().pass_names().is_empty());
+
+        options.enabled.insert(String::from("const-fold"));
+        assert_eq!(PassManager::new(&options).unwrap().pass_names(), vec!["const-fold"]);
+
+        options.level = OptLevel::O2;
+        options.disabled.insert(String::from("const-fold"));
+        assert_eq!(PassManager::new(&options).unwrap().pass_names(), vec!["simplify", "cse", "dse", "dce"]);
+        assert!(options.stack_pass_enabled("peephole"));
+    }
+
+    #[test]
+    fn parses_optimization_levels() {
+        assert_eq!("0".parse::<OptLevel>(), Ok(OptLevel::O0));
+        assert_eq!("2".parse::<OptLevel>(), Ok(OptLevel::O2));
+        assert!("3".parse::<OptLevel>().is_err());
+    }
+
+    #[test]
+    fn rejects_unknown_passes() {
+        let mut options = OptOptions::default();
+        options.disabled.insert(String::from("no-such-pass"));
+        assert!(matches!(
+            PassManager::new(&options),
+            Err(CompilationError::UnknownPass { .. })
+        ));
+    }
+}
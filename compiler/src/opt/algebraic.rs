@@ -0,0 +1,244 @@
+use crate::ir;
+use crate::interpreter::evaluate_binary;
+use super::Pass;
+
+use instant_parser::ast;
+
+use std::collections::HashMap;
+
+/// Applies algebraic identities (`x + 0`, `x - 0`, `x * 1`, `x / 1`, `x * 0`, `x - x`, `-(-x)`), replaces multiplication
+/// by powers of two with shifts, and reassociates additions so that constants from chains like `1 + (x + 2)`
+/// end up in a single operation, where they can be added together.
+/// Operands of removed operations are left for `dce`, which keeps divisions that may fail.
+pub struct AlgebraicSimplification;
+
+/// Result of simplification of a single operation
+enum Rewrite {
+    /// the result is equal to another temporary
+    Replace(ir::Temp),
+    /// the result is a constant
+    Constant(i64),
+    /// the operation is replaced by the given operations, which define the same temporary
+    Ops(Vec<ir::Op>),
+}
+
+struct Simplifier<'a> {
+    program: &'a mut ir::Program,
+    /// definitions of temporaries, after simplification
+    defs: HashMap<ir::Temp, ir::Op>,
+    use_counts: Vec<u32>,
+    /// versions of variables loaded to temporaries, versions change on every store
+    loads: HashMap<ir::Temp, (ir::Var, u32)>,
+}
+
+impl Simplifier<'_> {
+    fn constant(&self, temp: ir::Temp) -> Option<i64> {
+        match self.defs.get(&temp) {
+            Some(ir::Op::Const { val, .. }) => Some(*val),
+            _ => None,
+        }
+    }
+
+    /// whether both temporaries certainly have the same value
+    fn same_value(&self, lhs: ir::Temp, rhs: ir::Temp) -> bool {
+        lhs == rhs || matches!((self.loads.get(&lhs), self.loads.get(&rhs)), (Some(l), Some(r)) if l == r)
+    }
+
+    /// operands of the addition defining the temporary, if it is used only once
+    fn single_use_addition(&self, temp: ir::Temp) -> Option<(ir::Temp, ir::Temp)> {
+        match self.defs.get(&temp) {
+            Some(ir::Op::BinOp { op: ast::Opcode::Add, lhs, rhs, .. }) if self.use_counts[temp as usize] == 1 => {
+                Some((*lhs, *rhs))
+            },
+            _ => None,
+        }
+    }
+
+    /// splits the addition into non-constant and constant operand
+    fn addition_with_constant(&self, temp: ir::Temp) -> Option<(ir::Temp, i64)> {
+        let (lhs, rhs) = self.single_use_addition(temp)?;
+        match (self.constant(lhs), self.constant(rhs)) {
+            (None, Some(val)) => Some((lhs, val)),
+            (Some(val), None) => Some((rhs, val)),
+            _ => None,
+        }
+    }
+
+    fn new_const(&mut self, val: i64) -> (ir::Temp, ir::Op) {
+        let dst = self.program.new_temp();
+        (dst, ir::Op::Const { dst, val })
+    }
+
+    fn simplify_addition(&mut self, dst: ir::Temp, lhs: ir::Temp, rhs: ir::Temp) -> Option<Rewrite> {
+        let (lhs_val, rhs_val) = (self.constant(lhs), self.constant(rhs));
+        if rhs_val == Some(0) {
+            return Some(Rewrite::Replace(lhs));
+        }
+        if lhs_val == Some(0) {
+            return Some(Rewrite::Replace(rhs));
+        }
+        for (operand, other) in [(lhs, rhs), (rhs, lhs)] {
+            let (inner, inner_val) = match self.addition_with_constant(other) {
+                Some(inner_addition) => inner_addition,
+                None => continue,
+            };
+            let ops = match self.constant(operand) {
+                // c1 + (x + c2) => x + (c1 + c2), unless the sum of unbounded integers doesn't fit in a constant
+                Some(val) => {
+                    let sum = evaluate_binary(ast::Opcode::Add, val, inner_val, self.program.int_width).ok()?;
+                    let (constant, constant_op) = self.new_const(sum);
+                    vec![constant_op, ir::Op::BinOp { dst, op: ast::Opcode::Add, lhs: inner, rhs: constant }]
+                },
+                // y + (x + c) => (y + x) + c
+                None => {
+                    let sum = self.program.new_temp();
+                    let (constant, constant_op) = self.new_const(inner_val);
+                    vec![
+                        ir::Op::BinOp { dst: sum, op: ast::Opcode::Add, lhs: operand, rhs: inner },
+                        constant_op,
+                        ir::Op::BinOp { dst, op: ast::Opcode::Add, lhs: sum, rhs: constant },
+                    ]
+                },
+            };
+            return Some(Rewrite::Ops(ops));
+        }
+        None
+    }
+
+    fn simplify(&mut self, dst: ir::Temp, op: ast::Opcode, lhs: ir::Temp, rhs: ir::Temp) -> Option<Rewrite> {
+        let (lhs_val, rhs_val) = (self.constant(lhs), self.constant(rhs));
+        let int_width = self.program.int_width;
+        match op {
+            ast::Opcode::Add => self.simplify_addition(dst, lhs, rhs),
+            ast::Opcode::Sub if rhs_val == Some(0) => Some(Rewrite::Replace(lhs)),
+            ast::Opcode::Sub if self.same_value(lhs, rhs) => Some(Rewrite::Constant(0)),
+            ast::Opcode::Mul if rhs_val == Some(1) => Some(Rewrite::Replace(lhs)),
+            ast::Opcode::Mul if lhs_val == Some(1) => Some(Rewrite::Replace(rhs)),
+            ast::Opcode::Mul if lhs_val == Some(0) || rhs_val == Some(0) => Some(Rewrite::Constant(0)),
+            ast::Opcode::Mul => {
+                let (operand, val) = match (lhs_val, rhs_val) {
+                    (_, Some(val)) if int_width.is_power_of_two(val) => (lhs, val),
+                    (Some(val), _) if int_width.is_power_of_two(val) => (rhs, val),
+                    _ => return None,
+                };
+                // x * 2^k == x << k, also with wrapping around
+                let (amount, amount_op) = self.new_const(val.trailing_zeros() as i64);
+                Some(Rewrite::Ops(vec![
+                    amount_op,
+                    ir::Op::BinOp { dst, op: ast::Opcode::Shl, lhs: operand, rhs: amount },
+                ]))
+            },
+            ast::Opcode::Div if rhs_val == Some(1) => Some(Rewrite::Replace(lhs)),
+            _ => None,
+        }
+    }
+}
+
+impl Pass for AlgebraicSimplification {
+    fn name(&self) -> &'static str {
+        "simplify"
+    }
+
+    fn run(&self, program: &mut ir::Program, report: &mut dyn FnMut(String)) -> bool {
+        let use_counts = program.use_counts();
+        let original_ops: Vec<ir::Op> = program.ops.drain(..).collect();
+        let mut simplifier = Simplifier { program, defs: HashMap::new(), use_counts, loads: HashMap::new() };
+        let mut versions: Vec<u32> = vec![0; simplifier.program.variables.len()];
+        let mut replaced: HashMap<ir::Temp, ir::Temp> = HashMap::new();
+        let mut ops: Vec<ir::Op> = Vec::with_capacity(original_ops.len());
+        let mut changed = false;
+
+        for mut op in original_ops {
+            op.replace_operands(&|temp| replaced.get(&temp).copied().unwrap_or(temp));
+            let mut new_ops = match op {
+                ir::Op::BinOp { dst, op: opcode, lhs, rhs } => {
+                    let rewrite = simplifier.simplify(dst, opcode, lhs, rhs);
+                    changed |= rewrite.is_some();
+                    match rewrite {
+                        Some(Rewrite::Replace(temp)) => {
+                            report(format!("replaced t{} with t{}", dst, temp));
+                            replaced.insert(dst, temp);
+                            vec![]
+                        },
+                        Some(Rewrite::Constant(val)) => {
+                            report(format!("replaced t{} with constant {}", dst, val));
+                            vec![ir::Op::Const { dst, val }]
+                        },
+                        Some(Rewrite::Ops(new_ops)) => {
+                            report(format!("rewritten t{} ({} operations)", dst, new_ops.len()));
+                            new_ops
+                        },
+                        None => vec![op],
+                    }
+                },
+                ir::Op::Neg { dst, src } => match simplifier.defs.get(&src) {
+                    Some(ir::Op::Neg { src: inner, .. }) => {
+                        report(format!("replaced t{} with t{}", dst, inner));
+                        replaced.insert(dst, *inner);
+                        changed = true;
+                        vec![]
+                    },
+                    _ => vec![op],
+                },
+                ir::Op::Load { dst, var } => {
+                    simplifier.loads.insert(dst, (var, versions[var as usize]));
+                    vec![op]
+                },
+                ir::Op::Store { var, .. } => {
+                    versions[var as usize] += 1;
+                    vec![op]
+                },
+                ir::Op::Const { .. } | ir::Op::Print { .. } | ir::Op::Label { .. } | ir::Op::Jump { .. }
+                | ir::Op::Branch { .. } | ir::Op::Phi { .. } | ir::Op::Call { .. } | ir::Op::Return { .. }
+                | ir::Op::Read { .. } | ir::Op::DoubleConst { .. } | ir::Op::DoubleBinOp { .. }
+                | ir::Op::DoubleNeg { .. } | ir::Op::Convert { .. } => vec![op],
+            };
+            for new_op in new_ops.iter() {
+                if let Some(dst) = new_op.dst() {
+                    simplifier.defs.insert(dst, new_op.clone());
+                }
+            }
+            ops.append(&mut new_ops);
+        }
+
+        simplifier.program.ops = ops;
+        changed
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::opt::tests::optimize_source;
+    use crate::opt::dead_code::DeadCodeElimination;
+
+    fn simplify(source: &str) -> String {
+        let mut program = optimize_source(&AlgebraicSimplification, source);
+        DeadCodeElimination.run(&mut program, &mut |_| {});
+        program.to_string()
+    }
+
+    #[test]
+    fn applies_identities() {
+        assert_eq!(simplify("a = 1; (a + 0) * 1 - 0"), "t0 = 1\nstore a, t0\nt1 = load a\nprint t1\n");
+        assert_eq!(simplify("a = 1; a * 0 + (a - a)"), "t0 = 1\nstore a, t0\nt3 = 0\nprint t3\n");
+        assert_eq!(simplify("a = 1; a / 1"), "t0 = 1\nstore a, t0\nt1 = load a\nprint t1\n");
+    }
+
+    #[test]
+    fn keeps_divisions_which_may_fail() {
+        assert!(simplify("a = 0; (1 / a) * 0").contains("div"));
+    }
+
+    #[test]
+    fn replaces_multiplication_by_powers_of_two_with_shifts() {
+        assert_eq!(simplify("a = 1; 8 * a"), "t0 = 1\nstore a, t0\nt2 = load a\nt4 = 3\nt3 = shl t2, t4\nprint t3\n");
+        assert!(simplify("a = 1; a * 6").contains("mul"));
+    }
+
+    #[test]
+    fn reassociates_constants_in_addition_chains() {
+        let expected = "t0 = 1\nstore a, t0\nt2 = load a\nt6 = 3\nt5 = add t2, t6\nprint t5\n";
+        assert_eq!(simplify("a = 1; 1 + (a + 2)"), expected);
+    }
+}
@@ -0,0 +1,191 @@
+use crate::ir;
+use super::Pass;
+
+use instant_parser::ast;
+
+use std::collections::{HashMap, HashSet};
+
+/// Value of a temporary, as seen by value numbering
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+enum Value {
+    Const(i64),
+    /// value of the variable after its n-th store, versions change on every store
+    /// so that loads are equal only if the variable was not changed between them
+    Load(ir::Var, u32),
+    /// result of an operation, identified by the first temporary that computed it
+    Temp(ir::Temp),
+}
+
+type Tables = (HashMap<(ast::Opcode, Value, Value), ir::Temp>, HashMap<Value, ir::Temp>);
+
+/// Conditional whose branches are being numbered, with the tables from before the branch
+/// (or a loop, whose body is numbered as the only branch)
+struct Scope {
+    snapshot: Tables,
+    else_label: ir::Label,
+    /// label after the conditional, known after the end of the first branch
+    end_label: Option<ir::Label>,
+}
+
+/// Value numbering in the whole program: operations computing the same value as some earlier operation
+/// are removed, and their results are replaced with the result of the earlier operation.
+/// Results computed in a branch of a conditional are forgotten at the end of the branch,
+/// as they don't dominate the other branch nor the code after the conditional. Loops are also left
+/// from the header, so the results computed in the loop body are forgotten after it, and all variables
+/// stored in the loop get new versions at its header, since they may be changed by the previous iteration.
+/// Only arithmetic operations are eliminated - reusing constants or loads would not make the code shorter,
+/// but it would force the stack backend to store them in additional locals.
+pub struct CommonSubexpressionElimination;
+
+impl Pass for CommonSubexpressionElimination {
+    fn name(&self) -> &'static str {
+        "cse"
+    }
+
+    fn run(&self, program: &mut ir::Program, report: &mut dyn FnMut(String)) -> bool {
+        let mut values: HashMap<ir::Temp, Value> = HashMap::new();
+        let mut versions: Vec<u32> = vec![0; program.variables.len()];
+        let mut computed: HashMap<(ast::Opcode, Value, Value), ir::Temp> = HashMap::new();
+        let mut negated: HashMap<Value, ir::Temp> = HashMap::new();
+        let mut replaced: HashMap<ir::Temp, ir::Temp> = HashMap::new();
+        let mut scopes: Vec<Scope> = vec![];
+        let loops = program.loops();
+        let loop_exits: HashSet<ir::Label> = loops.values().map(|ir_loop| ir_loop.exit).collect();
+
+        let mut ops = Vec::with_capacity(program.ops.len());
+        for mut op in program.ops.drain(..) {
+            op.replace_operands(&|temp| replaced.get(&temp).copied().unwrap_or(temp));
+            match op {
+                ir::Op::Const { dst, val } => {
+                    values.insert(dst, Value::Const(val));
+                },
+                ir::Op::Load { dst, var } => {
+                    values.insert(dst, Value::Load(var, versions[var as usize]));
+                },
+                ir::Op::Store { var, .. } => {
+                    versions[var as usize] += 1;
+                },
+                ir::Op::BinOp { dst, op: opcode, lhs, rhs } => {
+                    let (mut lhs_value, mut rhs_value) = (values[&lhs], values[&rhs]);
+                    let is_commutative = matches!(
+                        opcode,
+                        ast::Opcode::Add | ast::Opcode::Mul | ast::Opcode::And | ast::Opcode::Or | ast::Opcode::Xor
+                        | ast::Opcode::Eq | ast::Opcode::Ne
+                    );
+                    if is_commutative && lhs_value > rhs_value {
+                        std::mem::swap(&mut lhs_value, &mut rhs_value);
+                    }
+                    match computed.get(&(opcode, lhs_value, rhs_value)) {
+                        Some(earlier) => {
+                            report(format!("replaced t{} with t{}", dst, earlier));
+                            replaced.insert(dst, *earlier);
+                            continue;
+                        },
+                        None => {
+                            computed.insert((opcode, lhs_value, rhs_value), dst);
+                            values.insert(dst, Value::Temp(dst));
+                        },
+                    }
+                },
+                ir::Op::Neg { dst, src } => {
+                    match negated.get(&values[&src]) {
+                        Some(earlier) => {
+                            report(format!("replaced t{} with t{}", dst, earlier));
+                            replaced.insert(dst, *earlier);
+                            continue;
+                        },
+                        None => {
+                            negated.insert(values[&src], dst);
+                            values.insert(dst, Value::Temp(dst));
+                        },
+                    }
+                },
+                ir::Op::Branch { else_label, .. } => {
+                    let snapshot = (computed.clone(), negated.clone());
+                    let end_label = Some(else_label).filter(|label| loop_exits.contains(label));
+                    scopes.push(Scope { snapshot, else_label, end_label });
+                },
+                ir::Op::Jump { label } if loops.contains_key(&label) => {},
+                ir::Op::Jump { label } => {
+                    if let Some(scope) = scopes.last_mut() {
+                        scope.end_label.get_or_insert(label);
+                    }
+                },
+                ir::Op::Label { label } => {
+                    if let Some(ir_loop) = loops.get(&label) {
+                        for var in ir_loop.stored.iter() {
+                            versions[*var as usize] += 1;
+                        }
+                    }
+                    if let Some(scope) = scopes.last() {
+                        if label == scope.else_label || Some(label) == scope.end_label {
+                            (computed, negated) = scope.snapshot.clone();
+                        }
+                        if Some(label) == scope.end_label {
+                            scopes.pop();
+                        }
+                    }
+                },
+                // calls may print values and reads consume the input, so their results are never reused
+                ir::Op::Phi { dst, .. } | ir::Op::Call { dst, .. } | ir::Op::Read { dst } => {
+                    values.insert(dst, Value::Temp(dst));
+                },
+                // operations on doubles are not numbered
+                ir::Op::DoubleConst { dst, .. } | ir::Op::DoubleBinOp { dst, .. } | ir::Op::DoubleNeg { dst, .. }
+                | ir::Op::Convert { dst, .. } => {
+                    values.insert(dst, Value::Temp(dst));
+                },
+                ir::Op::Print { .. } | ir::Op::Return { .. } => {},
+            }
+            ops.push(op);
+        }
+        program.ops = ops;
+        !replaced.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::opt::tests::optimize_source;
+
+    #[test]
+    fn reuses_repeated_subexpressions() {
+        let program = optimize_source(&CommonSubexpressionElimination, "a = 1; b = 2; (a + b) * (b + a)");
+        let expected = "t0 = 1\nstore a, t0\nt1 = 2\nstore b, t1\nt2 = load a\nt3 = load b\nt4 = add t2, t3\n\
+                        t5 = load b\nt6 = load a\nt8 = mul t4, t4\nprint t8\n";
+        assert_eq!(program.to_string(), expected);
+    }
+
+    #[test]
+    fn reuses_results_across_statements() {
+        let program = optimize_source(&CommonSubexpressionElimination, "a = 1; b = a / 3; a / 3");
+        assert_eq!(program.ops.last(), Some(&ir::Op::Print { srcs: vec![3] }));
+    }
+
+    #[test]
+    fn does_not_reuse_results_after_operands_change() {
+        let program = optimize_source(&CommonSubexpressionElimination, "a = 1; b = a - 3; a = 2; a - 3");
+        assert_eq!(program.ops.iter().filter(|op| matches!(op, ir::Op::BinOp { .. })).count(), 2);
+    }
+
+    #[test]
+    fn does_not_reuse_results_of_variables_stored_in_loops() {
+        let program = optimize_source(
+            &CommonSubexpressionElimination, "a = 1; a * 2; i = 0; while (i < 2) { a * 2; a = a + 1; i = i + 1 }; a * 2"
+        );
+        assert_eq!(program.ops.iter().filter(|op| matches!(op, ir::Op::BinOp { .. })).count(), 6);
+        let program = optimize_source(
+            &CommonSubexpressionElimination, "b = 2; b * 3; i = 0; while (i < 2) { b * 3; i = i + 1 }"
+        );
+        assert_eq!(program.ops.iter().filter(|op| matches!(op, ir::Op::BinOp { .. })).count(), 3);
+    }
+
+    #[test]
+    fn does_not_reuse_results_from_other_branches() {
+        let program = optimize_source(&CommonSubexpressionElimination, "a = 1; (if a then a * 2 else a * 2) + a * 2");
+        assert_eq!(program.ops.iter().filter(|op| matches!(op, ir::Op::BinOp { .. })).count(), 4);
+        let program = optimize_source(&CommonSubexpressionElimination, "a = 1; b = a * 2; if a then a * 2 else 0");
+        assert_eq!(program.ops.iter().filter(|op| matches!(op, ir::Op::BinOp { .. })).count(), 1);
+    }
+}
@@ -0,0 +1,145 @@
+use crate::ir;
+use crate::interpreter::{evaluate_binary, evaluate_neg};
+use super::Pass;
+
+use std::collections::HashMap;
+
+/// Evaluates operations on constants at compile time, and replaces loads of variables
+/// holding known constants with the constants. Operations which would fail at runtime
+/// (division by zero) are left unchanged, so the error still happens when the program is executed,
+/// and so are operations on unbounded integers whose results don't fit in a constant (i64).
+pub struct ConstantFolding;
+
+impl Pass for ConstantFolding {
+    fn name(&self) -> &'static str {
+        "const-fold"
+    }
+
+    fn run(&self, program: &mut ir::Program, _report: &mut dyn FnMut(String)) -> bool {
+        let mut changed = false;
+        let int_width = program.int_width;
+        let mut temp_values: HashMap<ir::Temp, i64> = HashMap::new();
+        let mut var_values: HashMap<ir::Var, i64> = HashMap::new();
+        let loops = program.loops();
+        let loop_exits: HashMap<ir::Label, &ir::Loop> = loops.values().map(|ir_loop| (ir_loop.exit, ir_loop)).collect();
+
+        for op in program.ops.iter_mut() {
+            let folded = match op {
+                ir::Op::Const { dst, val } => {
+                    temp_values.insert(*dst, *val);
+                    None
+                },
+                ir::Op::Load { dst, var } => {
+                    var_values.get(var).map(|val| ir::Op::Const { dst: *dst, val: *val })
+                },
+                ir::Op::BinOp { dst, op, lhs, rhs } => {
+                    match (temp_values.get(lhs), temp_values.get(rhs)) {
+                        (Some(lhs), Some(rhs)) => evaluate_binary(*op, *lhs, *rhs, int_width).ok()
+                            .map(|val| ir::Op::Const { dst: *dst, val }),
+                        _ => None,
+                    }
+                },
+                ir::Op::Neg { dst, src } => {
+                    temp_values.get(src).and_then(|val| evaluate_neg(*val, int_width).ok())
+                        .map(|val| ir::Op::Const { dst: *dst, val })
+                },
+                ir::Op::Store { var, src } => {
+                    match temp_values.get(src) {
+                        Some(val) => var_values.insert(*var, *val),
+                        None => var_values.remove(var),
+                    };
+                    None
+                },
+                // variables stored in a loop are unknown at its header, which is also reached from the end
+                // of the loop, and after the loop (which is left from the header)
+                ir::Op::Label { label } => {
+                    if let Some(ir_loop) = loops.get(label).or_else(|| loop_exits.get(label).copied()) {
+                        var_values.retain(|var, _| !ir_loop.stored.contains(var));
+                    }
+                    None
+                },
+                // values of variables are not changed inside conditional expressions,
+                // and temporaries are defined before all their uses, so folding ignores other control flow;
+                // called functions have their own variables, so calls don't change the known values either
+                ir::Op::Print { .. } | ir::Op::Jump { .. } | ir::Op::Branch { .. } | ir::Op::Phi { .. }
+                | ir::Op::Call { .. } | ir::Op::Return { .. } | ir::Op::Read { .. } => None,
+                // doubles are not folded
+                ir::Op::DoubleConst { .. } | ir::Op::DoubleBinOp { .. } | ir::Op::DoubleNeg { .. }
+                | ir::Op::Convert { .. } => None,
+            };
+            if let Some(folded) = folded {
+                if let ir::Op::Const { dst, val } = folded {
+                    temp_values.insert(dst, val);
+                }
+                *op = folded;
+                changed = true;
+            }
+        }
+        changed
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use instant_parser::ast;
+    use instant_parser::instant::ProgParser;
+    use crate::common::IntWidth;
+    use crate::opt::tests::optimize_source;
+
+    #[test]
+    fn folds_constants_through_variables() {
+        let program = optimize_source(&ConstantFolding, "a = 2 * 3; b = a - 1; a / b");
+        let expected = "t0 = 2\nt1 = 3\nt2 = 6\nstore a, t2\nt3 = 6\nt4 = 1\nt5 = 5\nstore b, t5\n\
+                        t6 = 6\nt7 = 5\nt8 = 1\nprint t8\n";
+        assert_eq!(program.to_string(), expected);
+    }
+
+    #[test]
+    fn wraps_around_on_overflow() {
+        let program = optimize_source(&ConstantFolding, "2147483647 + 1; (0 - 2147483647 - 1) / (0 - 1)");
+        assert!(program.ops.contains(&ir::Op::Const { dst: 2, val: i32::MIN as i64 }));
+        assert!(program.ops.contains(&ir::Op::Const { dst: 11, val: i32::MIN as i64 }));
+    }
+
+    #[test]
+    fn wraps_around_to_the_width_of_the_program() {
+        let source = "2147483647 + 1; 65536 * 65536; 1 << 35";
+        let mut program = ir::lower(&ProgParser::new().parse(source).unwrap(), IntWidth::I64).unwrap();
+        ConstantFolding.run(&mut program, &mut |_| {});
+        assert!(program.ops.contains(&ir::Op::Const { dst: 2, val: 2147483648 }));
+        assert!(program.ops.contains(&ir::Op::Const { dst: 5, val: 4294967296 }));
+        assert!(program.ops.contains(&ir::Op::Const { dst: 8, val: 34359738368 }));
+
+        let program = optimize_source(&ConstantFolding, source);
+        assert!(program.ops.contains(&ir::Op::Const { dst: 5, val: 0 }));
+        assert!(program.ops.contains(&ir::Op::Const { dst: 8, val: 8 }));
+    }
+
+    #[test]
+    fn folds_unbounded_integers_which_fit_in_constants() {
+        let source = "2147483647 + 1; 4294967296 * 4294967296; -9223372036854775807 - 1; -(-9223372036854775807 - 1)";
+        let mut program = ir::lower(&ProgParser::new().parse(source).unwrap(), IntWidth::Unbounded).unwrap();
+        ConstantFolding.run(&mut program, &mut |_| {});
+        assert!(program.ops.contains(&ir::Op::Const { dst: 2, val: 2147483648 }));
+        assert!(matches!(program.ops[6], ir::Op::BinOp { op: ast::Opcode::Mul, .. }));
+        assert!(program.ops.contains(&ir::Op::Const { dst: 8, val: i64::MIN }));
+        assert!(matches!(program.ops.iter().rev().nth(1), Some(ir::Op::Neg { .. })));
+    }
+
+    #[test]
+    fn does_not_propagate_variables_stored_in_loops() {
+        let program = optimize_source(
+            &ConstantFolding, "a = 1; b = 5; i = 0; while (i < 2) { a = a + b; i = i + 1 }; a + b"
+        );
+        // loads of `i` and `a` in the loop and after it are left, only `b` is known
+        let loads = program.ops.iter().filter(|op| matches!(op, ir::Op::Load { .. })).count();
+        assert_eq!(loads, 4);
+    }
+
+    #[test]
+    fn does_not_fold_division_by_zero() {
+        let program = optimize_source(&ConstantFolding, "a = 0; 1 / a");
+        assert!(matches!(program.ops[4], ir::Op::BinOp { op: ast::Opcode::Div, .. }));
+    }
+}
@@ -0,0 +1,98 @@
+use crate::ir;
+use super::Pass;
+
+use instant_parser::ast;
+
+use std::collections::HashSet;
+
+/// Removes operations defining temporaries which are never used. Divisions (and remainders) are kept
+/// unless the divisor is a known non-zero constant, as they may still fail at runtime.
+pub struct DeadCodeElimination;
+
+fn has_side_effects(op: &ir::Op, non_zero_constants: &HashSet<ir::Temp>) -> bool {
+    match op {
+        ir::Op::BinOp { op: ast::Opcode::Div | ast::Opcode::Rem, rhs, .. } => !non_zero_constants.contains(rhs),
+        ir::Op::Store { .. } | ir::Op::Print { .. } | ir::Op::Call { .. } | ir::Op::Return { .. } => true,
+        // unused values are still consumed from the input
+        ir::Op::Read { .. } => true,
+        // conditionals are kept whole, even if their value is unused - every conditional ends with a phi node,
+        // and the stack compiler removes unused conditionals which can't fail
+        ir::Op::Label { .. } | ir::Op::Jump { .. } | ir::Op::Branch { .. } | ir::Op::Phi { .. } => true,
+        ir::Op::Const { .. } | ir::Op::Load { .. } | ir::Op::BinOp { .. } | ir::Op::Neg { .. } => false,
+        // division of doubles results in an infinity or NaN instead of failing
+        ir::Op::DoubleConst { .. } | ir::Op::DoubleBinOp { .. } | ir::Op::DoubleNeg { .. } | ir::Op::Convert { .. } => false,
+    }
+}
+
+impl Pass for DeadCodeElimination {
+    fn name(&self) -> &'static str {
+        "dce"
+    }
+
+    fn run(&self, program: &mut ir::Program, _report: &mut dyn FnMut(String)) -> bool {
+        let non_zero_constants: HashSet<ir::Temp> = program.ops.iter()
+            .filter_map(|op| match op {
+                ir::Op::Const { dst, val } if *val != 0 => Some(*dst),
+                _ => None,
+            })
+            .collect();
+        let mut use_counts = program.use_counts();
+        let mut removed = vec![false; program.ops.len()];
+        // operations are visited backwards, so that whole unused expressions are removed in one run
+        for position in (0..program.ops.len()).rev() {
+            let op = &program.ops[position];
+            let is_unused = op.dst().is_some_and(|dst| use_counts[dst as usize] == 0);
+            if is_unused && !has_side_effects(op, &non_zero_constants) {
+                for temp in op.operands() {
+                    use_counts[temp as usize] -= 1;
+                }
+                removed[position] = true;
+            }
+        }
+
+        let mut position = 0;
+        program.ops.retain(|_| {
+            position += 1;
+            !removed[position - 1]
+        });
+        removed.contains(&true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::opt::tests::optimize_source;
+    use crate::opt::constant_folding::ConstantFolding;
+    use crate::common::IntWidth;
+
+    #[test]
+    fn removes_unused_temporaries() {
+        let mut program = optimize_source(&ConstantFolding, "a = 1 + 2; a * (a - 3)");
+        DeadCodeElimination.run(&mut program, &mut |_| {});
+        assert_eq!(program.to_string(), "t2 = 3\nstore a, t2\nt7 = 0\nprint t7\n");
+    }
+
+    #[test]
+    fn keeps_divisions_which_may_fail() {
+        // results of both divisions are unused, but only the second one is known to succeed
+        let mut program = ir::Program {
+            ops: vec![
+                ir::Op::Load { dst: 0, var: 0 },
+                ir::Op::Const { dst: 1, val: 1 },
+                ir::Op::BinOp { dst: 2, op: ast::Opcode::Div, lhs: 1, rhs: 0 },
+                ir::Op::Const { dst: 3, val: 2 },
+                ir::Op::BinOp { dst: 4, op: ast::Opcode::Div, lhs: 0, rhs: 3 },
+            ],
+            variables: vec![String::from("a")],
+            variable_types: vec![ast::Type::Int],
+            temps_count: 5,
+            labels_count: 0,
+            params: 0,
+            functions: vec![],
+            int_width: IntWidth::I32,
+        };
+        assert!(DeadCodeElimination.run(&mut program, &mut |_| {}));
+        assert_eq!(program.to_string(), "t0 = load a\nt1 = 1\nt2 = div t1, t0\n");
+    }
+}
@@ -0,0 +1,176 @@
+use crate::ir;
+use super::Pass;
+
+use instant_parser::ast::Type;
+
+use std::collections::HashMap;
+
+/// Removes stores to variables which are not live: never read afterwards, or overwritten before being read.
+/// Only the stores are removed - expressions computing the stored values are left for `dce`,
+/// which keeps divisions that may fail. Variables which are no longer accessed at all are removed
+/// from the program, so that they don't occupy locals in the compiled code.
+pub struct DeadStoreElimination;
+
+/// next accesses of variables at a branch: a variable is live if it is read in any of the successors
+fn merge_states(lhs: &HashMap<ir::Var, bool>, rhs: &HashMap<ir::Var, bool>) -> HashMap<ir::Var, bool> {
+    let mut merged = lhs.clone();
+    for (var, is_load) in rhs.iter() {
+        *merged.entry(*var).or_insert(*is_load) |= *is_load;
+    }
+    merged
+}
+
+impl DeadStoreElimination {
+    /// removes stores which are not live, with liveness computed in a single backward pass
+    fn remove_dead_stores(program: &mut ir::Program, report: &mut dyn FnMut(String)) -> bool {
+        // next access of every variable after the current operation: true if it is a load (variable is live),
+        // false if it is a store (variable is overwritten), no entry if the variable is not accessed anymore
+        let mut next_access_is_load: HashMap<ir::Var, bool> = HashMap::new();
+        let mut removed = vec![false; program.ops.len()];
+        // next accesses at the beginning of blocks, for the jumps to them
+        let mut label_states: HashMap<ir::Label, HashMap<ir::Var, bool>> = HashMap::new();
+        let loops = program.loops();
+
+        for (position, op) in program.ops.iter().enumerate().rev() {
+            match op {
+                ir::Op::Load { var, .. } => {
+                    next_access_is_load.insert(*var, true);
+                },
+                ir::Op::Store { var, src } => {
+                    let next_access = next_access_is_load.insert(*var, false);
+                    if next_access != Some(true) {
+                        let reason = match next_access {
+                            Some(_) => "overwritten before being read",
+                            None => "never read",
+                        };
+                        report(format!(
+                            "removed store of t{} to {} ({})", src, program.variables[*var as usize], reason
+                        ));
+                        removed[position] = true;
+                    }
+                },
+                ir::Op::Label { label } => {
+                    label_states.insert(*label, next_access_is_load.clone());
+                },
+                ir::Op::Jump { label } => match label_states.get(label) {
+                    Some(state) => next_access_is_load = state.clone(),
+                    // the jump at the end of a loop goes back to its header, which is followed by the code after
+                    // the loop (reached from the header) and the next iteration - which may read the variables
+                    // loaded anywhere in the loop
+                    None => {
+                        for var in loops[label].loaded.iter() {
+                            next_access_is_load.insert(*var, true);
+                        }
+                    },
+                },
+                ir::Op::Branch { then_label, else_label, .. } => {
+                    next_access_is_load = merge_states(&label_states[then_label], &label_states[else_label]);
+                },
+                // variables of a function are not accessed after it returns
+                ir::Op::Return { .. } => next_access_is_load.clear(),
+                ir::Op::Const { .. } | ir::Op::BinOp { .. } | ir::Op::Neg { .. } | ir::Op::Print { .. }
+                | ir::Op::Phi { .. } | ir::Op::Call { .. } | ir::Op::Read { .. } | ir::Op::DoubleConst { .. }
+                | ir::Op::DoubleBinOp { .. } | ir::Op::DoubleNeg { .. } | ir::Op::Convert { .. } => {},
+            }
+        }
+
+        let mut position = 0;
+        program.ops.retain(|_| {
+            position += 1;
+            !removed[position - 1]
+        });
+        removed.contains(&true)
+    }
+
+    /// removes variables which are not accessed by any operation (except parameters), renumbering the remaining ones
+    fn remove_unused_variables(program: &mut ir::Program, report: &mut dyn FnMut(String)) -> bool {
+        let mut is_used = vec![false; program.variables.len()];
+        // parameters are stored by the caller, so they keep their numbers even if they are unused
+        is_used[..program.params as usize].fill(true);
+        for op in program.ops.iter() {
+            if let ir::Op::Load { var, .. } | ir::Op::Store { var, .. } = op {
+                is_used[*var as usize] = true;
+            }
+        }
+        if !is_used.contains(&false) {
+            return false;
+        }
+
+        let mut renumbered: Vec<Option<ir::Var>> = vec![];
+        let mut variables: Vec<String> = vec![];
+        let mut variable_types: Vec<Type> = vec![];
+        for ((name, ty), is_used) in program.variables.drain(..).zip(program.variable_types.drain(..)).zip(is_used) {
+            if is_used {
+                renumbered.push(Some(variables.len() as ir::Var));
+                variables.push(name);
+                variable_types.push(ty);
+            } else {
+                report(format!("removed unused variable {}", name));
+                renumbered.push(None);
+            }
+        }
+        for op in program.ops.iter_mut() {
+            if let ir::Op::Load { var, .. } | ir::Op::Store { var, .. } = op {
+                *var = renumbered[*var as usize].unwrap();
+            }
+        }
+        program.variables = variables;
+        program.variable_types = variable_types;
+        true
+    }
+}
+
+impl Pass for DeadStoreElimination {
+    fn name(&self) -> &'static str {
+        "dse"
+    }
+
+    fn run(&self, program: &mut ir::Program, report: &mut dyn FnMut(String)) -> bool {
+        let removed_stores = DeadStoreElimination::remove_dead_stores(program, report);
+        let removed_variables = DeadStoreElimination::remove_unused_variables(program, report);
+        removed_stores || removed_variables
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::opt::tests::{lower_source, optimize_source};
+    use crate::opt::dead_code::DeadCodeElimination;
+
+    #[test]
+    fn removes_stores_which_are_not_read() {
+        let program = optimize_source(&DeadStoreElimination, "a = 1; b = 2; a = 3; b");
+        assert_eq!(program.to_string(), "t0 = 1\nt1 = 2\nstore b, t1\nt2 = 3\nt3 = load b\nprint t3\n");
+        assert_eq!(program.variables, vec![String::from("b")]);
+    }
+
+    #[test]
+    fn reports_removed_stores() {
+        let mut program = lower_source("a = 1; a = 2; b = 3; b = 4; b");
+        let mut messages = vec![];
+        DeadStoreElimination.run(&mut program, &mut |message| messages.push(message));
+        assert_eq!(messages, vec![
+            "removed store of t2 to b (overwritten before being read)",
+            "removed store of t1 to a (never read)",
+            "removed store of t0 to a (overwritten before being read)",
+            "removed unused variable a",
+        ]);
+    }
+
+    #[test]
+    fn keeps_stores_read_by_next_iterations_and_after_loops() {
+        // the first store is read if the loop is not executed, the second one by the next iteration
+        let program = optimize_source(&DeadStoreElimination, "a = 1; i = 0; while (i < 0) { b = a; a = 2; i = i + 1 }; a");
+        let stores = program.ops.iter().filter(|op| matches!(op, ir::Op::Store { .. })).count();
+        assert_eq!(stores, 4);
+        assert_eq!(program.variables, vec!["a", "i"]);
+    }
+
+    #[test]
+    fn keeps_divisions_which_may_fail() {
+        let mut program = optimize_source(&DeadStoreElimination, "a = 0; b = 1 / a; c = 2 * a");
+        DeadCodeElimination.run(&mut program, &mut |_| {});
+        assert_eq!(program.to_string(), "t0 = 0\nstore a, t0\nt1 = 1\nt2 = load a\nt3 = div t1, t2\n");
+    }
+}
@@ -0,0 +1,301 @@
+use instant_parser::ast;
+use crate::common::IntWidth;
+use crate::interpreter::{evaluate_binary, evaluate_neg};
+use crate::stack::{CompiledCode, CompiledProgram, DepthTracker, Instruction};
+
+use std::collections::VecDeque;
+
+/// Rewrite of a sequence of instructions, `depth` is the stack depth before the first of them,
+/// and values are integers of the width `int_width`.
+/// Returns instructions that replace the sequence, or None if the rule doesn't apply.
+type Rewrite = fn(window: &[Instruction], depth: u32, stack_limit: u32, int_width: IntWidth) -> Option<Vec<Instruction>>;
+
+struct Rule {
+    name: &'static str,
+    length: usize,
+    rewrite: Rewrite,
+}
+
+/// Rules of the peephole optimizer, tried in this order at every position.
+/// Every rule decreases the number of instructions or replaces LOAD / SWAP with cheaper instructions,
+/// so the optimization always terminates.
+const RULES: &[Rule] = &[
+    Rule { name: "fold-constants", length: 3, rewrite: fold_constants },
+    Rule { name: "fold-negation", length: 2, rewrite: fold_negation },
+    Rule { name: "remove-identity", length: 2, rewrite: remove_identity },
+    Rule { name: "remove-commutative-swap", length: 2, rewrite: remove_commutative_swap },
+    Rule { name: "remove-double-swap", length: 2, rewrite: remove_double_swap },
+    Rule { name: "swap-pushes", length: 3, rewrite: swap_pushes },
+    Rule { name: "remove-load-store", length: 2, rewrite: remove_load_store },
+    Rule { name: "store-load-to-dup", length: 2, rewrite: store_load_to_dup },
+    Rule { name: "compare-with-zero", length: 2, rewrite: compare_with_zero },
+];
+
+fn binary_opcode(instr: &Instruction) -> Option<ast::Opcode> {
+    match instr {
+        Instruction::ADD => Some(ast::Opcode::Add),
+        Instruction::SUB => Some(ast::Opcode::Sub),
+        Instruction::MUL => Some(ast::Opcode::Mul),
+        Instruction::DIV => Some(ast::Opcode::Div),
+        Instruction::REM => Some(ast::Opcode::Rem),
+        Instruction::AND => Some(ast::Opcode::And),
+        Instruction::OR => Some(ast::Opcode::Or),
+        Instruction::XOR => Some(ast::Opcode::Xor),
+        Instruction::SHL => Some(ast::Opcode::Shl),
+        Instruction::SHR => Some(ast::Opcode::Shr),
+        _ => None,
+    }
+}
+
+/// PUSH a, PUSH b, op => PUSH (a op b), unless the operation fails (division by zero)
+/// or its result doesn't fit in i64 (with unbounded integers)
+fn fold_constants(window: &[Instruction], _depth: u32, _stack_limit: u32, int_width: IntWidth) -> Option<Vec<Instruction>> {
+    match window {
+        [Instruction::PUSH { val: lhs }, Instruction::PUSH { val: rhs }, op] => {
+            let val = evaluate_binary(binary_opcode(op)?, *lhs, *rhs, int_width).ok()?;
+            Some(vec![Instruction::PUSH { val }])
+        },
+        _ => None,
+    }
+}
+
+/// PUSH a, NEG => PUSH -a, NEG, NEG => nothing
+fn fold_negation(window: &[Instruction], _depth: u32, _stack_limit: u32, int_width: IntWidth) -> Option<Vec<Instruction>> {
+    match window {
+        [Instruction::PUSH { val }, Instruction::NEG] => Some(vec![Instruction::PUSH { val: evaluate_neg(*val, int_width).ok()? }]),
+        [Instruction::NEG, Instruction::NEG] => Some(vec![]),
+        _ => None,
+    }
+}
+
+/// PUSH 0, ADD / SUB / OR / XOR / SHL / SHR => nothing, PUSH -1, AND => nothing, PUSH 1, MUL / DIV => nothing
+fn remove_identity(window: &[Instruction], _depth: u32, _stack_limit: u32, _int_width: IntWidth) -> Option<Vec<Instruction>> {
+    match window {
+        [Instruction::PUSH { val: 0 }, Instruction::ADD]
+        | [Instruction::PUSH { val: 0 }, Instruction::SUB]
+        | [Instruction::PUSH { val: 0 }, Instruction::OR]
+        | [Instruction::PUSH { val: 0 }, Instruction::XOR]
+        | [Instruction::PUSH { val: 0 }, Instruction::SHL]
+        | [Instruction::PUSH { val: 0 }, Instruction::SHR]
+        | [Instruction::PUSH { val: -1 }, Instruction::AND]
+        | [Instruction::PUSH { val: 1 }, Instruction::MUL]
+        | [Instruction::PUSH { val: 1 }, Instruction::DIV] => Some(vec![]),
+        _ => None,
+    }
+}
+
+/// SWAP, op => op - for commutative operations (ADD, MUL, AND, OR, XOR)
+fn remove_commutative_swap(window: &[Instruction], _depth: u32, _stack_limit: u32, _int_width: IntWidth) -> Option<Vec<Instruction>> {
+    match window {
+        [Instruction::SWAP, op @ (Instruction::ADD | Instruction::MUL | Instruction::AND | Instruction::OR | Instruction::XOR)] => {
+            Some(vec![op.clone()])
+        },
+        _ => None,
+    }
+}
+
+/// SWAP, SWAP => nothing
+fn remove_double_swap(window: &[Instruction], _depth: u32, _stack_limit: u32, _int_width: IntWidth) -> Option<Vec<Instruction>> {
+    match window {
+        [Instruction::SWAP, Instruction::SWAP] => Some(vec![]),
+        _ => None,
+    }
+}
+
+/// a, b, SWAP => b, a - for instructions a and b that only push a value
+fn swap_pushes(window: &[Instruction], _depth: u32, _stack_limit: u32, _int_width: IntWidth) -> Option<Vec<Instruction>> {
+    let is_push = |instr: &Instruction| matches!(instr, Instruction::PUSH { .. } | Instruction::LOAD { .. });
+    match window {
+        [first, second, Instruction::SWAP] if is_push(first) && is_push(second) => {
+            Some(vec![second.clone(), first.clone()])
+        },
+        _ => None,
+    }
+}
+
+/// LOAD x, STORE x => nothing
+fn remove_load_store(window: &[Instruction], _depth: u32, _stack_limit: u32, _int_width: IntWidth) -> Option<Vec<Instruction>> {
+    match window {
+        [Instruction::LOAD { addr: load_addr }, Instruction::STORE { addr: store_addr }] if load_addr == store_addr => {
+            Some(vec![])
+        },
+        _ => None,
+    }
+}
+
+/// STORE x, LOAD x => DUP, STORE x - only if the additional stack slot fits in the stack limit
+fn store_load_to_dup(window: &[Instruction], depth: u32, stack_limit: u32, _int_width: IntWidth) -> Option<Vec<Instruction>> {
+    match window {
+        [Instruction::STORE { addr: store_addr }, Instruction::LOAD { addr: load_addr }]
+        if store_addr == load_addr && depth < stack_limit => {
+            Some(vec![Instruction::DUP, Instruction::STORE { addr: *store_addr }])
+        },
+        _ => None,
+    }
+}
+
+/// PUSH 0, IF_CMP cond => IF cond
+fn compare_with_zero(window: &[Instruction], _depth: u32, _stack_limit: u32, _int_width: IntWidth) -> Option<Vec<Instruction>> {
+    match window {
+        [Instruction::PUSH { val: 0 }, Instruction::IF_CMP { cond, label }] => {
+            Some(vec![Instruction::IF { cond: *cond, label: *label }])
+        },
+        _ => None,
+    }
+}
+
+/// Number of applications of every rule, in the order of the rule table
+pub type Statistics = Vec<(&'static str, usize)>;
+
+/// optimizes the instructions in place, without increasing the stack limit, returns statistics of applied rules
+pub fn optimize(compiled_program: &mut CompiledCode, int_width: IntWidth) -> Statistics {
+    let mut statistics: Statistics = RULES.iter().map(|rule| (rule.name, 0)).collect();
+    let mut input: VecDeque<Instruction> = compiled_program.instructions.drain(..).collect();
+    let mut output: Vec<Instruction> = Vec::with_capacity(input.len());
+    // stack depth after every instruction of the output
+    let mut depths: Vec<u32> = Vec::with_capacity(input.len());
+    // rules never match jumps nor labels, so depths recorded at jumps stay valid after rewrites
+    let mut tracker = DepthTracker::default();
+
+    'instructions: while let Some(instr) = input.pop_front() {
+        tracker.depth = depths.last().copied().unwrap_or(0);
+        tracker.step(&instr);
+        depths.push(tracker.depth);
+        output.push(instr);
+
+        // rules are applied to the end of the output, and the replacement is optimized again
+        // as a part of the input, so that rewrites can enable further rewrites
+        for (rule, (_, count)) in RULES.iter().zip(statistics.iter_mut()) {
+            if output.len() < rule.length {
+                continue;
+            }
+            let start = output.len() - rule.length;
+            let depth_before = if start == 0 { 0 } else { depths[start - 1] };
+            if let Some(replacement) = (rule.rewrite)(&output[start..], depth_before, compiled_program.stack_limit, int_width) {
+                *count += 1;
+                output.truncate(start);
+                depths.truncate(start);
+                for instr in replacement.into_iter().rev() {
+                    input.push_front(instr);
+                }
+                continue 'instructions;
+            }
+        }
+    }
+
+    compiled_program.instructions = output;
+    statistics
+}
+
+/// optimizes the main program and every function separately, returns the sums of their statistics
+pub fn optimize_program(compiled_program: &mut CompiledProgram) -> Statistics {
+    let int_width = compiled_program.int_width;
+    let mut statistics = optimize(&mut compiled_program.main, int_width);
+    for function in compiled_program.functions.iter_mut() {
+        for ((_, total), (_, count)) in statistics.iter_mut().zip(optimize(&mut function.code, int_width)) {
+            *total += count;
+        }
+    }
+    statistics
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::Number;
+    use crate::stack::Condition;
+    use crate::vm::execute;
+
+    fn optimize_instructions(instructions: Vec<Instruction>, stack_limit: u32) -> (Vec<Instruction>, Statistics) {
+        let mut compiled_program = CompiledCode { instructions, stack_limit, locals_limit: 2 };
+        let statistics = optimize(&mut compiled_program, IntWidth::I32);
+        (compiled_program.instructions, statistics)
+    }
+
+    fn applications(statistics: &Statistics, rule_name: &str) -> usize {
+        statistics.iter().find(|(name, _)| *name == rule_name).unwrap().1
+    }
+
+    #[test]
+    fn cascades_rewrites() {
+        use Instruction::*;
+        // x + (2 * 3 - 6) == x
+        let (instructions, statistics) = optimize_instructions(
+            vec![LOAD { addr: 0 }, PUSH { val: 2 }, PUSH { val: 3 }, MUL, PUSH { val: 6 }, SUB, ADD, PRINT],
+            3,
+        );
+        assert_eq!(instructions, vec![LOAD { addr: 0 }, PRINT]);
+        assert_eq!(applications(&statistics, "fold-constants"), 2);
+        assert_eq!(applications(&statistics, "remove-identity"), 1);
+    }
+
+    #[test]
+    fn removes_unnecessary_swaps() {
+        use Instruction::*;
+        let (instructions, statistics) = optimize_instructions(
+            vec![
+                LOAD { addr: 0 }, LOAD { addr: 1 }, PUSH { val: 2 }, MUL, SWAP, ADD,
+                PUSH { val: 3 }, LOAD { addr: 1 }, SWAP, SUB, PRINT,
+            ],
+            3,
+        );
+        assert_eq!(instructions, vec![
+            LOAD { addr: 0 }, LOAD { addr: 1 }, PUSH { val: 2 }, MUL, ADD, LOAD { addr: 1 }, PUSH { val: 3 }, SUB, PRINT
+        ]);
+        assert_eq!(applications(&statistics, "remove-commutative-swap"), 1);
+        assert_eq!(applications(&statistics, "swap-pushes"), 1);
+    }
+
+    #[test]
+    fn compares_with_zero_without_pushing_it() {
+        use Instruction::*;
+        let (instructions, statistics) = optimize_instructions(
+            vec![
+                LOAD { addr: 0 }, PUSH { val: 0 }, IF_CMP { cond: Condition::Ge, label: 0 },
+                PUSH { val: 1 }, GOTO { label: 1 }, LABEL { label: 0 }, PUSH { val: 0 }, LABEL { label: 1 }, PRINT,
+            ],
+            3,
+        );
+        assert_eq!(instructions[..2], [LOAD { addr: 0 }, IF { cond: Condition::Ge, label: 0 }]);
+        assert_eq!(applications(&statistics, "compare-with-zero"), 1);
+    }
+
+    #[test]
+    fn folds_constants_wrapping_around_to_the_width() {
+        use Instruction::*;
+        let instructions = vec![PUSH { val: 65536 }, PUSH { val: 65536 }, MUL, PRINT];
+        let (optimized, _) = optimize_instructions(instructions.clone(), 3);
+        assert_eq!(optimized, vec![PUSH { val: 0 }, PRINT]);
+
+        let mut code = CompiledCode { instructions, stack_limit: 3, locals_limit: 0 };
+        optimize(&mut code, IntWidth::I64);
+        assert_eq!(code.instructions, vec![PUSH { val: 4294967296 }, PRINT]);
+    }
+
+    #[test]
+    fn keeps_division_by_zero() {
+        use Instruction::*;
+        let (instructions, _) = optimize_instructions(vec![PUSH { val: 1 }, PUSH { val: 0 }, DIV, PRINT], 3);
+        assert_eq!(instructions, vec![PUSH { val: 1 }, PUSH { val: 0 }, DIV, PRINT]);
+    }
+
+    #[test]
+    fn replaces_store_and_load_with_dup_within_stack_limit() {
+        use Instruction::*;
+        let instructions = vec![PUSH { val: 7 }, STORE { addr: 1 }, LOAD { addr: 1 }, LOAD { addr: 1 }, MUL, PRINT];
+        let (optimized, _) = optimize_instructions(instructions.clone(), 3);
+        assert_eq!(optimized, vec![PUSH { val: 7 }, DUP, DUP, STORE { addr: 1 }, MUL, PRINT]);
+
+        let main = CompiledCode { instructions: optimized, stack_limit: 3, locals_limit: 2 };
+        let mut compiled_program = CompiledProgram { main, functions: vec![], int_width: IntWidth::I32 };
+        let mut output = vec![];
+        execute(&compiled_program, &mut || 0, &mut |val, _| output.push(val)).unwrap();
+        assert_eq!(output, vec![Number::Int(49)]);
+
+        // without a free stack slot, the instructions are left unchanged
+        compiled_program.main.instructions = instructions.clone();
+        compiled_program.main.stack_limit = 1;
+        optimize_program(&mut compiled_program);
+        assert_eq!(compiled_program.main.instructions, instructions);
+    }
+}
@@ -0,0 +1,356 @@
+use crate::common::{CompilationError, IntWidth};
+use crate::stack::{CompiledCode, CompiledProgram, Condition, DepthTracker, Instruction};
+
+use std::collections::HashMap;
+
+/// Registers holding the topmost values of the abstract stack, indexed by stack depth.
+/// Deeper values (which are rare, as the stack compiler minimizes the stack depth)
+/// are spilled to the stack frame.
+const STACK_REGISTERS: &[&str] = &["t0", "t1", "t2", "t3", "a1", "a2", "a3", "a4", "a5", "a6", "a7"];
+
+/// Scratch registers, used for operations on spilled values and for swapping
+const SCRATCH_LHS: &str = "t4";
+const SCRATCH_RHS: &str = "t5";
+/// Scratch register for computing addresses that don't fit in 12-bit immediate offset
+const SCRATCH_ADDR: &str = "t6";
+
+const WORD_SIZE: u32 = 4;
+const DIV_BY_ZERO_LABEL: &str = ".Ldiv_by_zero";
+
+/// Layout of the stack frame of main and every function (offsets relative to sp):
+/// variables, followed by spilled stack values, followed by saved return address.
+/// Parameters are the first variables, they are stored by the caller just below its own frame,
+/// where the frame of the callee begins.
+struct Frame {
+    locals_limit: u32,
+    size: u32,
+}
+
+impl Frame {
+    fn new(compiled_program: &CompiledCode) -> Frame {
+        let spill_slots = compiled_program.stack_limit
+            .saturating_sub(STACK_REGISTERS.len() as u32);
+        let words = compiled_program.locals_limit + spill_slots + 1;
+        // RISC-V calling convention requires sp to be 16-byte aligned
+        let size = (words * WORD_SIZE).next_multiple_of(16);
+        Frame { locals_limit: compiled_program.locals_limit, size }
+    }
+
+    fn variable_offset(&self, addr: i32) -> i64 {
+        (addr as u32 * WORD_SIZE) as i64
+    }
+
+    fn spill_offset(&self, depth: u32) -> i64 {
+        ((self.locals_limit + depth - STACK_REGISTERS.len() as u32) * WORD_SIZE) as i64
+    }
+
+    fn return_address_offset(&self) -> i64 {
+        (self.size - WORD_SIZE) as i64
+    }
+}
+
+fn fits_immediate(val: i64) -> bool {
+    (-2048..2048).contains(&val)
+}
+
+fn memory_access(op: &str, reg: &str, offset: i64) -> Vec<String> {
+    if fits_immediate(offset) {
+        vec![format!("{} {}, {}(sp)", op, reg, offset)]
+    } else {
+        vec![
+            format!("li {}, {}", SCRATCH_ADDR, offset),
+            format!("add {}, {}, sp", SCRATCH_ADDR, SCRATCH_ADDR),
+            format!("{} {}, 0({})", op, reg, SCRATCH_ADDR),
+        ]
+    }
+}
+
+fn adjust_sp(delta: i64) -> Vec<String> {
+    if fits_immediate(delta) {
+        vec![format!("addi sp, sp, {}", delta)]
+    } else {
+        vec![
+            format!("li {}, {}", SCRATCH_ADDR, delta),
+            format!("add sp, sp, {}", SCRATCH_ADDR),
+        ]
+    }
+}
+
+/// Translates abstract stack instructions, keeping track of the current stack depth
+/// (which is known statically for every instruction) to map stack slots to registers.
+/// Labels of functions are prefixed with their names, since local labels are shared by the whole file.
+struct Translator<'a> {
+    frame: &'a Frame,
+    depth: u32,
+    label_prefix: &'a str,
+    frame_sizes: &'a HashMap<&'a str, u32>,
+}
+
+impl<'a> Translator<'a> {
+    /// Register that holds the value at given depth, if it is not spilled
+    fn slot_register(&self, depth: u32) -> Option<&'static str> {
+        STACK_REGISTERS.get(depth as usize).copied()
+    }
+
+    /// Makes value at given depth available in a register, using scratch if it was spilled
+    fn read_slot(&self, depth: u32, scratch: &'static str, instructions: &mut Vec<String>) -> &'static str {
+        match self.slot_register(depth) {
+            Some(reg) => reg,
+            None => {
+                instructions.append(&mut memory_access("lw", scratch, self.frame.spill_offset(depth)));
+                scratch
+            },
+        }
+    }
+
+    /// Register to which value of the given depth should be written,
+    /// `write_slot` has to be called after writing for spilled values
+    fn target_register(&self, depth: u32, scratch: &'static str) -> &'static str {
+        self.slot_register(depth).unwrap_or(scratch)
+    }
+
+    fn write_slot(&self, depth: u32, reg: &str, instructions: &mut Vec<String>) {
+        match self.slot_register(depth) {
+            Some(slot_reg) => {
+                if slot_reg != reg {
+                    instructions.push(format!("mv {}, {}", slot_reg, reg));
+                }
+            },
+            None => {
+                instructions.append(&mut memory_access("sw", reg, self.frame.spill_offset(depth)));
+            },
+        }
+    }
+
+    fn translate_binary(&mut self, op: &str, instructions: &mut Vec<String>) {
+        let lhs = self.read_slot(self.depth - 2, SCRATCH_LHS, instructions);
+        let rhs = self.read_slot(self.depth - 1, SCRATCH_RHS, instructions);
+        if op == "div" || op == "rem" {
+            instructions.push(format!("beqz {}, {}", rhs, DIV_BY_ZERO_LABEL));
+        }
+        let target = self.target_register(self.depth - 2, SCRATCH_LHS);
+        instructions.push(format!("{} {}, {}, {}", op, target, lhs, rhs));
+        self.write_slot(self.depth - 2, target, instructions);
+        self.depth -= 1;
+    }
+
+    fn label(&self, label: u32) -> String {
+        format!(".L{}{}", self.label_prefix, label)
+    }
+
+    /// loads the value to a0 and leaves the function
+    fn translate_return(&self, src: &str, instructions: &mut Vec<String>) {
+        if src != "a0" {
+            instructions.push(format!("mv a0, {}", src));
+        }
+        instructions.append(&mut memory_access("lw", "ra", self.frame.return_address_offset()));
+        instructions.append(&mut adjust_sp(self.frame.size as i64));
+        instructions.push(String::from("ret"));
+    }
+
+    /// jumps to the label if the condition holds for the registers, only beq, bne, blt and bge are used,
+    /// other conditions swap the registers
+    fn translate_jump(cond: &Condition, lhs: &str, rhs: &str, label: String, instructions: &mut Vec<String>) {
+        let (op, lhs, rhs) = match cond {
+            Condition::Eq => ("beq", lhs, rhs),
+            Condition::Ne => ("bne", lhs, rhs),
+            Condition::Lt => ("blt", lhs, rhs),
+            Condition::Ge => ("bge", lhs, rhs),
+            Condition::Gt => ("blt", rhs, lhs),
+            Condition::Le => ("bge", rhs, lhs),
+        };
+        instructions.push(format!("{} {}, {}, {}", op, lhs, rhs, label));
+    }
+
+    fn translate(&mut self, instr: &Instruction) -> Vec<String> {
+        let mut instructions: Vec<String> = vec![];
+        match instr {
+            Instruction::PUSH { val } => {
+                let target = self.target_register(self.depth, SCRATCH_LHS);
+                instructions.push(format!("li {}, {}", target, val));
+                self.write_slot(self.depth, target, &mut instructions);
+                self.depth += 1;
+            },
+            Instruction::LOAD { addr } => {
+                let target = self.target_register(self.depth, SCRATCH_LHS);
+                instructions.append(&mut memory_access("lw", target, self.frame.variable_offset(*addr)));
+                self.write_slot(self.depth, target, &mut instructions);
+                self.depth += 1;
+            },
+            Instruction::STORE { addr } => {
+                let src = self.read_slot(self.depth - 1, SCRATCH_LHS, &mut instructions);
+                instructions.append(&mut memory_access("sw", src, self.frame.variable_offset(*addr)));
+                self.depth -= 1;
+            },
+            Instruction::ADD => self.translate_binary("add", &mut instructions),
+            Instruction::SUB => self.translate_binary("sub", &mut instructions),
+            Instruction::MUL => self.translate_binary("mul", &mut instructions),
+            Instruction::DIV => self.translate_binary("div", &mut instructions),
+            // rem of i32::MIN by -1 is 0, as in JVM
+            Instruction::REM => self.translate_binary("rem", &mut instructions),
+            Instruction::AND => self.translate_binary("and", &mut instructions),
+            Instruction::OR => self.translate_binary("or", &mut instructions),
+            Instruction::XOR => self.translate_binary("xor", &mut instructions),
+            // sll and sra use only the lowest 5 bits of the shift amount, as JVM does
+            Instruction::SHL => self.translate_binary("sll", &mut instructions),
+            Instruction::SHR => self.translate_binary("sra", &mut instructions),
+            Instruction::NEG => {
+                let src = self.read_slot(self.depth - 1, SCRATCH_LHS, &mut instructions);
+                let target = self.target_register(self.depth - 1, SCRATCH_LHS);
+                instructions.push(format!("neg {}, {}", target, src));
+                self.write_slot(self.depth - 1, target, &mut instructions);
+            },
+            Instruction::DUP => {
+                let src = self.read_slot(self.depth - 1, SCRATCH_LHS, &mut instructions);
+                self.write_slot(self.depth, src, &mut instructions);
+                self.depth += 1;
+            },
+            Instruction::SWAP => {
+                let lower = self.read_slot(self.depth - 2, SCRATCH_LHS, &mut instructions);
+                let upper = self.read_slot(self.depth - 1, SCRATCH_RHS, &mut instructions);
+                if lower != SCRATCH_LHS {
+                    instructions.push(format!("mv {}, {}", SCRATCH_LHS, lower));
+                }
+                self.write_slot(self.depth - 2, upper, &mut instructions);
+                self.write_slot(self.depth - 1, SCRATCH_LHS, &mut instructions);
+            },
+            Instruction::PRINT | Instruction::PRINT_INLINE => {
+                // print is the last instruction of a statement, so no other values are live
+                // and caller-saved registers don't need to be preserved across the call
+                let src = self.read_slot(self.depth - 1, "a0", &mut instructions);
+                if src != "a0" {
+                    instructions.push(format!("mv a0, {}", src));
+                }
+                if *instr == Instruction::PRINT {
+                    instructions.push(String::from("call printInt"));
+                } else {
+                    instructions.push(String::from("call printIntInline"));
+                }
+                self.depth -= 1;
+            },
+            // the slot reserved for the print receiver is never written, printInt doesn't need it
+            Instruction::PRINT_BEGIN => {
+                self.depth += 1;
+            },
+            Instruction::PRINT_END => {
+                instructions.append(&mut self.translate(&Instruction::PRINT));
+                self.depth -= 1;
+            },
+            Instruction::LABEL { label } => {
+                instructions.push(format!("{}:", self.label(*label)));
+            },
+            Instruction::GOTO { label } => {
+                instructions.push(format!("j {}", self.label(*label)));
+            },
+            Instruction::IF { cond, label } => {
+                let src = self.read_slot(self.depth - 1, SCRATCH_LHS, &mut instructions);
+                Translator::translate_jump(cond, src, "zero", self.label(*label), &mut instructions);
+                self.depth -= 1;
+            },
+            Instruction::IF_CMP { cond, label } => {
+                let lhs = self.read_slot(self.depth - 2, SCRATCH_LHS, &mut instructions);
+                let rhs = self.read_slot(self.depth - 1, SCRATCH_RHS, &mut instructions);
+                Translator::translate_jump(cond, lhs, rhs, self.label(*label), &mut instructions);
+                self.depth -= 2;
+            },
+            Instruction::CALL { function, args } => {
+                // only the arguments are on the stack, so no other values have to be preserved across the call
+                let callee_frame_size = self.frame_sizes[function.as_str()] as i64;
+                for arg in 0..*args {
+                    let src = self.read_slot(arg, SCRATCH_LHS, &mut instructions);
+                    let offset = (arg * WORD_SIZE) as i64 - callee_frame_size;
+                    instructions.append(&mut memory_access("sw", src, offset));
+                }
+                instructions.push(format!("call {}", function_label(function)));
+                self.depth -= *args;
+                self.write_slot(self.depth, "a0", &mut instructions);
+                self.depth += 1;
+            },
+            Instruction::RETURN => {
+                let src = self.read_slot(self.depth - 1, "a0", &mut instructions);
+                self.translate_return(src, &mut instructions);
+                self.depth -= 1;
+            },
+            // the stack is empty, so as in calls, no values have to be preserved
+            Instruction::READ => {
+                instructions.push(String::from("call readInt"));
+                self.write_slot(self.depth, "a0", &mut instructions);
+                self.depth += 1;
+            },
+            // programs with doubles are rejected before translation
+            _ => unreachable!(),
+        };
+        instructions
+    }
+}
+
+/// Instant functions are prefixed, so that they don't collide with main and functions of the runtime
+fn function_label(function: &str) -> String {
+    format!("instant.{}", function)
+}
+
+/// translates the code of main or a function, which starts by allocating its frame
+fn translate_code(
+    code: &CompiledCode, frame: &Frame, label_prefix: &str, frame_sizes: &HashMap<&str, u32>
+) -> Vec<String> {
+    let mut translator = Translator { frame, depth: 0, label_prefix, frame_sizes };
+    let mut tracker = DepthTracker::default();
+
+    let mut body: Vec<String> = vec![];
+    body.append(&mut adjust_sp(-(frame.size as i64)));
+    body.append(&mut memory_access("sw", "ra", frame.return_address_offset()));
+    for instr in code.instructions.iter() {
+        // depth after a jump is given by the tracker, as the next instruction may be reached only by jumps
+        translator.depth = tracker.step(instr);
+        body.append(&mut translator.translate(instr));
+    }
+    body
+}
+
+fn indent(body: Vec<String>, riscv_representation: &mut Vec<String>) {
+    for instr in body {
+        if instr.ends_with(':') {
+            riscv_representation.push(instr);
+        } else {
+            riscv_representation.push(format!("    {}", instr));
+        }
+    }
+}
+
+/// translates the program to RV32IM assembly, printInt and instantDivByZero
+/// are expected to be provided by the runtime (see dist/runtime_rv32.s),
+/// only 32-bit integers are supported, as they fit in registers of RV32 (and doubles are not supported)
+pub fn translate(compiled_program: &CompiledProgram) -> Result<Vec<String>, CompilationError> {
+    if compiled_program.int_width != IntWidth::I32 {
+        return Err(CompilationError::UnsupportedIntWidth { width: compiled_program.int_width });
+    }
+    if compiled_program.uses_doubles() {
+        return Err(CompilationError::UnsupportedDoubles);
+    }
+    let frames: Vec<Frame> = compiled_program.functions.iter().map(|function| Frame::new(&function.code)).collect();
+    let frame_sizes: HashMap<&str, u32> = compiled_program.functions.iter().zip(frames.iter())
+        .map(|(function, frame)| (function.name.as_str(), frame.size))
+        .collect();
+
+    let frame = Frame::new(&compiled_program.main);
+    let mut body = translate_code(&compiled_program.main, &frame, "", &frame_sizes);
+    body.push(String::from("li a0, 0"));
+    let translator = Translator { frame: &frame, depth: 0, label_prefix: "", frame_sizes: &frame_sizes };
+    translator.translate_return("a0", &mut body);
+
+    let mut riscv_representation = vec![
+        String::from(".text"),
+        String::from(".globl main"),
+        String::from("main:"),
+    ];
+    indent(body, &mut riscv_representation);
+    // code of functions ends with RETURN
+    for (function, frame) in compiled_program.functions.iter().zip(frames.iter()) {
+        riscv_representation.push(format!("{}:", function_label(&function.name)));
+        let label_prefix = format!("{}.", function.name);
+        indent(translate_code(&function.code, frame, &label_prefix, &frame_sizes), &mut riscv_representation);
+    }
+    riscv_representation.push(format!("{}:", DIV_BY_ZERO_LABEL));
+    riscv_representation.push(String::from("    call instantDivByZero"));
+    Ok(riscv_representation)
+}
\ No newline at end of file
@@ -1,21 +1,201 @@
-use instant_parser::ast;
-use crate::common::CompilationError;
+use instant_parser::ast::{self, Type};
+use crate::ir;
+use crate::common::IntWidth;
 
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::cmp::{min, max};
 use std::fmt::Debug;
 
-#[derive(Debug)]
+/// Relation checked by conditional jumps
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Condition {
+    Eq,
+    Ne,
+    Lt,
+    Le,
+    Gt,
+    Ge,
+}
+
+impl Condition {
+    fn from_opcode(op: ast::Opcode) -> Option<Condition> {
+        match op {
+            ast::Opcode::Eq => Some(Condition::Eq),
+            ast::Opcode::Ne => Some(Condition::Ne),
+            ast::Opcode::Lt => Some(Condition::Lt),
+            ast::Opcode::Le => Some(Condition::Le),
+            ast::Opcode::Gt => Some(Condition::Gt),
+            ast::Opcode::Ge => Some(Condition::Ge),
+            _ => None,
+        }
+    }
+
+    /// condition that holds exactly when this one doesn't
+    pub fn negate(self) -> Condition {
+        match self {
+            Condition::Eq => Condition::Ne,
+            Condition::Ne => Condition::Eq,
+            Condition::Lt => Condition::Ge,
+            Condition::Le => Condition::Gt,
+            Condition::Gt => Condition::Le,
+            Condition::Ge => Condition::Lt,
+        }
+    }
+
+    /// condition with swapped operands: `a < b` is the same as `b > a`
+    pub fn mirror(self) -> Condition {
+        match self {
+            Condition::Eq => Condition::Eq,
+            Condition::Ne => Condition::Ne,
+            Condition::Lt => Condition::Gt,
+            Condition::Le => Condition::Ge,
+            Condition::Gt => Condition::Lt,
+            Condition::Ge => Condition::Le,
+        }
+    }
+
+    pub fn holds<T: PartialOrd>(self, lhs: T, rhs: T) -> bool {
+        match self {
+            Condition::Eq => lhs == rhs,
+            Condition::Ne => lhs != rhs,
+            Condition::Lt => lhs < rhs,
+            Condition::Le => lhs <= rhs,
+            Condition::Gt => lhs > rhs,
+            Condition::Ge => lhs >= rhs,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[allow(non_camel_case_types)]
 pub enum Instruction {
-    PUSH { val: i32 },
+    PUSH { val: i64 },
     ADD,
     SUB,
     MUL,
     DIV,
+    REM,
+    AND,
+    OR,
+    XOR,
+    SHL,
+    SHR,
+    NEG,
     PRINT,
+    /// prints the value followed by a space instead of a newline, so that the next value is printed in the same line
+    PRINT_INLINE,
+    /// reserves a stack slot for the print receiver (`System.out` in JVM) before the value is evaluated
+    PRINT_BEGIN,
+    /// prints the value and releases the slot reserved by `PRINT_BEGIN`
+    PRINT_END,
     STORE { addr: i32 },
     LOAD { addr: i32 },
     SWAP,
+    /// duplicates the value on top of the stack, produced only by the peephole optimizer
+    DUP,
+    /// target of jumps, the stack depth is the same on every path to the label - all jumps are forward,
+    /// except for the jumps at the end of loops (made with an empty stack)
+    LABEL { label: ir::Label },
+    GOTO { label: ir::Label },
+    /// pops a value and jumps if the condition holds when it is compared with 0
+    IF { cond: Condition, label: ir::Label },
+    /// pops two values and jumps if the condition holds for them (the lower one is the left operand)
+    IF_CMP { cond: Condition, label: ir::Label },
+    /// pops the arguments (the first one is the lowest) and pushes the result of the function,
+    /// made only with the arguments on the stack - the callee uses a separate stack
+    CALL { function: String, args: u32 },
+    /// pops the result and returns it from the function
+    RETURN,
+    /// pushes the next integer of the input, made only with an empty stack (as calls)
+    READ,
+    /// pushes the double given by its bits, so that instructions can still be compared
+    PUSH_DOUBLE { bits: u64 },
+    DADD,
+    DSUB,
+    DMUL,
+    DDIV,
+    DREM,
+    DNEG,
+    /// pops two doubles and pushes 1 if the condition holds for them (the lower one is the left operand), or 0
+    DCMP { cond: Condition },
+    /// converts the integer on top of the stack to a double
+    I2D,
+    /// truncates the double on top of the stack to an integer, saturating at the limits of the width (NaN is 0)
+    D2I,
+    DSTORE { addr: i32 },
+    DLOAD { addr: i32 },
+    DPRINT,
+    DPRINT_INLINE,
+    DPRINT_END,
+}
+
+impl Instruction {
+    /// change of the stack depth caused by the instruction
+    pub fn stack_effect(&self) -> i32 {
+        match self {
+            Instruction::PUSH { .. } | Instruction::LOAD { .. } | Instruction::DUP | Instruction::PRINT_BEGIN
+            | Instruction::READ => 1,
+            Instruction::SWAP | Instruction::NEG | Instruction::LABEL { .. } | Instruction::GOTO { .. } => 0,
+            Instruction::ADD | Instruction::SUB | Instruction::MUL | Instruction::DIV | Instruction::REM
+            | Instruction::AND | Instruction::OR | Instruction::XOR | Instruction::SHL | Instruction::SHR
+            | Instruction::STORE { .. } | Instruction::PRINT | Instruction::PRINT_INLINE | Instruction::IF { .. }
+            | Instruction::RETURN => -1,
+            Instruction::PRINT_END | Instruction::IF_CMP { .. } => -2,
+            Instruction::CALL { args, .. } => 1 - *args as i32,
+            Instruction::PUSH_DOUBLE { .. } | Instruction::DLOAD { .. } => 1,
+            Instruction::DNEG | Instruction::I2D | Instruction::D2I => 0,
+            Instruction::DADD | Instruction::DSUB | Instruction::DMUL | Instruction::DDIV | Instruction::DREM
+            | Instruction::DCMP { .. } | Instruction::DSTORE { .. } | Instruction::DPRINT
+            | Instruction::DPRINT_INLINE => -1,
+            Instruction::DPRINT_END => -2,
+        }
+    }
+
+    /// whether the instruction operates on doubles, which only some backends support
+    pub fn is_double(&self) -> bool {
+        matches!(
+            self,
+            Instruction::PUSH_DOUBLE { .. } | Instruction::DADD | Instruction::DSUB | Instruction::DMUL
+            | Instruction::DDIV | Instruction::DREM | Instruction::DNEG | Instruction::DCMP { .. } | Instruction::I2D
+            | Instruction::D2I | Instruction::DSTORE { .. } | Instruction::DLOAD { .. } | Instruction::DPRINT
+            | Instruction::DPRINT_INLINE | Instruction::DPRINT_END
+        )
+    }
+
+    /// label to which the instruction may jump
+    pub fn jump_target(&self) -> Option<ir::Label> {
+        match self {
+            Instruction::GOTO { label } | Instruction::IF { label, .. } | Instruction::IF_CMP { label, .. } => {
+                Some(*label)
+            },
+            _ => None,
+        }
+    }
+}
+
+/// Stack depth along the instructions: after GOTO, the next instruction (a label) is reachable only by jumps,
+/// so its depth is the depth recorded at the (earlier) jumps to it, instead of the depth after GOTO
+#[derive(Default)]
+pub struct DepthTracker {
+    pub depth: u32,
+    label_depths: HashMap<ir::Label, u32>,
+}
+
+impl DepthTracker {
+    /// updates the depth after the instruction, returns the depth before it
+    pub fn step(&mut self, instr: &Instruction) -> u32 {
+        if let Instruction::LABEL { label } = instr {
+            if let Some(depth) = self.label_depths.get(label) {
+                self.depth = *depth;
+            }
+        }
+        let depth_before = self.depth;
+        self.depth = (self.depth as i32 + instr.stack_effect()) as u32;
+        if let Some(label) = instr.jump_target() {
+            self.label_depths.insert(label, self.depth);
+        }
+        depth_before
+    }
 }
 
 #[derive(Debug)]
@@ -25,147 +205,1134 @@ pub struct CompiledCode {
     pub locals_limit: u32,
 }
 
-pub trait CompileStack {
-    fn compile_stack(&self, env: &mut HashMap<String, i32>) -> Result<CompiledCode, CompilationError>;
+/// Function compiled to a separate method, its parameters are the first `params` locals
+#[derive(Debug)]
+pub struct CompiledFunction {
+    pub name: String,
+    pub params: u32,
+    pub code: CompiledCode,
+}
+
+/// Main program and functions, each of them with its own stack and locals limits
+/// (counted in values, regardless of the width of integers and of doubles)
+#[derive(Debug)]
+pub struct CompiledProgram {
+    pub main: CompiledCode,
+    pub functions: Vec<CompiledFunction>,
+    pub int_width: IntWidth,
 }
 
-impl CompileStack for ast::Prog {
-    fn compile_stack(&self, env: &mut HashMap<String, i32>) -> Result<CompiledCode, CompilationError> {
+impl CompiledProgram {
+    fn codes(&self) -> impl Iterator<Item = &CompiledCode> {
+        self.functions.iter()
+            .map(|function| &function.code)
+            .chain(std::iter::once(&self.main))
+    }
+
+    /// whether any code of the program reads the input, so that backends add their reading helpers
+    /// only when they are needed
+    pub fn reads_input(&self) -> bool {
+        self.codes().any(|code| code.instructions.contains(&Instruction::READ))
+    }
+
+    /// whether any code of the program computes on doubles
+    pub fn uses_doubles(&self) -> bool {
+        self.codes().any(|code| code.instructions.iter().any(Instruction::is_double))
+    }
+}
+
+/// Branch of a conditional expression: temporaries used more than once are stored in locals
+/// at the beginning of the branch, before its result is evaluated
+#[derive(Debug)]
+struct Block {
+    evaluations: Vec<Evaluation>,
+    result: Box<ExprTree>,
+}
+
+impl Block {
+    fn min_depth(&self) -> u32 {
+        self.evaluations.iter().map(|evaluation| evaluation.min_depth()).fold(self.result.min_depth(), max)
+    }
+
+    fn may_fail(&self) -> bool {
+        self.result.may_fail() || self.evaluations.iter().flat_map(|evaluation| &evaluation.tree).any(ExprTree::may_fail)
+    }
+
+    fn has_side_effects(&self) -> bool {
+        self.result.has_side_effects() || self.evaluations.iter().flat_map(|evaluation| &evaluation.tree).any(ExprTree::has_side_effects)
+    }
+
+    fn compile(&self, depth_budget: u32) -> CompiledCode {
         let mut instructions: Vec<Instruction> = vec![];
         let mut stack_limit = 0;
-        for stmt in self.stmts.iter() {
-            let mut compiled_stmt = stmt.compile_stack(env)?;
-            instructions.append(&mut compiled_stmt.instructions);
-            stack_limit = max(stack_limit, compiled_stmt.stack_limit);
+        // evaluations in branches only store temporaries
+        for evaluation in self.evaluations.iter() {
+            let mut compiled = evaluation.tree.as_ref().unwrap().compile_stack(depth_budget);
+            stack_limit = max(stack_limit, compiled.stack_limit);
+            instructions.append(&mut compiled.instructions);
+            instructions.push(evaluation.instruction.clone());
         }
-        let locals_limit = env.len() as u32;
-        let compiled_program = CompiledCode { instructions, stack_limit, locals_limit };
-        Ok(compiled_program)
+        let mut result = self.result.compile_stack(depth_budget);
+        instructions.append(&mut result.instructions);
+        CompiledCode { instructions, stack_limit: max(stack_limit, result.stack_limit), locals_limit: 0 }
     }
 }
 
-impl CompileStack for ast::Stmt {
-    fn compile_stack(&self, env: &mut HashMap<String, i32>) -> Result<CompiledCode, CompilationError> {
+/// Expression tree rebuilt from the three-address code. Stack machine evaluates whole trees at once,
+/// which allows to choose evaluation order of operands that minimizes the stack depth.
+#[derive(Debug)]
+enum ExprTree {
+    Const { val: i64 },
+    DoubleConst { val: f64 },
+    /// variable, or temporary which is used more than once and has to be stored in a local
+    Local { addr: i32, ty: Type },
+    /// `min_depth` is the minimal stack depth needed to evaluate the tree (its Sethi-Ullman number)
+    Binary { left: Box<ExprTree>, op: ast::Opcode, right: Box<ExprTree>, min_depth: u32 },
+    /// arithmetic operation or comparison of doubles - there is no SWAP of doubles, so operands of operations
+    /// that can't be reordered are always evaluated left to right
+    DoubleBinary { left: Box<ExprTree>, op: ast::Opcode, right: Box<ExprTree>, min_depth: u32 },
+    /// negation of an integer or a double
+    Neg { expr: Box<ExprTree> },
+    Convert { expr: Box<ExprTree>, to: Type },
+    /// comparison evaluated to 0 or 1, by a conditional jump to `false_label`
+    Compare { left: Box<ExprTree>, cond: Condition, right: Box<ExprTree>, min_depth: u32, labels: (ir::Label, ir::Label) },
+    /// conditional expression, labels are the beginning of the `otherwise` branch and the end of the expression,
+    /// the stack depth needed by it is the maximum over both branches
+    If { cond: Box<ExprTree>, then: Block, otherwise: Block, labels: (ir::Label, ir::Label) },
+    /// function call with arguments evaluated from left to right, always stored in a local right away,
+    /// so that calls are executed in the order of the program, and never above other values on the stack
+    Call { function: String, args: Vec<ExprTree>, min_depth: u32 },
+    /// next integer of the input, also stored in a local right away, in the order of the program
+    Read,
+}
+
+impl ExprTree {
+    fn binary(left: ExprTree, op: ast::Opcode, right: ExprTree) -> ExprTree {
+        let (lhs_depth, rhs_depth) = (left.min_depth(), right.min_depth());
+        // the deeper operand is evaluated first, and the other one on top of its result
+        let min_depth = max(max(lhs_depth, rhs_depth), 1 + min(lhs_depth, rhs_depth));
+        ExprTree::Binary { left: Box::new(left), op, right: Box::new(right), min_depth }
+    }
+
+    fn double_binary(left: ExprTree, op: ast::Opcode, right: ExprTree) -> ExprTree {
+        let (lhs_depth, rhs_depth) = (left.min_depth(), right.min_depth());
+        let min_depth = if is_double_reorderable(op) {
+            max(max(lhs_depth, rhs_depth), 1 + min(lhs_depth, rhs_depth))
+        } else {
+            max(lhs_depth, rhs_depth + 1)
+        };
+        ExprTree::DoubleBinary { left: Box::new(left), op, right: Box::new(right), min_depth }
+    }
+
+    /// type of the value of the tree
+    fn ty(&self) -> Type {
         match self {
-            ast::Stmt::Expr {expr} => {
-                let mut compiled_expr = expr.compile_stack(env)?;
-                compiled_expr.instructions.push(Instruction::PRINT);
-
-                // stack limit is increased by 1 to account for the 1st argument to print
-                let compiled_stmt = CompiledCode {
-                    instructions: compiled_expr.instructions,
-                    stack_limit: 1 + compiled_expr.stack_limit,
-                    locals_limit: compiled_expr.locals_limit,
-                };
-                Ok(compiled_stmt)
-            },
-            ast::Stmt::Decl {var, expr} => {
-                let mut compiled_expr = expr.compile_stack(env)?;
-                let variable_location = match env.get(var) {
-                    Some(existing_location) => *existing_location,
-                    None => {
-                        let new_location = match env.values().max() {
-                            Some(last_used_location) => last_used_location + 1,
-                            None => 0,
-                        };
-                        env.insert(var.clone(), new_location);
-                        compiled_expr.locals_limit += 1;
-                        new_location
-                    }
-                };
+            ExprTree::Const { .. } | ExprTree::Binary { .. } | ExprTree::Compare { .. } | ExprTree::Call { .. }
+            | ExprTree::Read => Type::Int,
+            ExprTree::DoubleConst { .. } => Type::Double,
+            ExprTree::DoubleBinary { op, .. } if op.is_comparison() => Type::Int,
+            ExprTree::DoubleBinary { .. } => Type::Double,
+            ExprTree::Local { ty, .. } | ExprTree::Convert { to: ty, .. } => *ty,
+            ExprTree::Neg { expr } => expr.ty(),
+            // both branches have the same type
+            ExprTree::If { then, .. } => then.result.ty(),
+        }
+    }
 
-                let store_command = Instruction::STORE { addr: variable_location };
-                compiled_expr.instructions.push(store_command);
+    fn min_depth(&self) -> u32 {
+        match self {
+            ExprTree::Const { .. } | ExprTree::DoubleConst { .. } | ExprTree::Local { .. } | ExprTree::Read => 1,
+            ExprTree::Binary { min_depth, .. } | ExprTree::DoubleBinary { min_depth, .. }
+            | ExprTree::Compare { min_depth, .. } => *min_depth,
+            ExprTree::Neg { expr } | ExprTree::Convert { expr, .. } => expr.min_depth(),
+            ExprTree::If { cond, then, otherwise, .. } => max(cond.min_depth(), max(then.min_depth(), otherwise.min_depth())),
+            ExprTree::Call { min_depth, .. } => *min_depth,
+        }
+    }
 
-                let compiled_stmt = CompiledCode {
-                    instructions: compiled_expr.instructions,
-                    stack_limit: compiled_expr.stack_limit,
-                    locals_limit: compiled_expr.locals_limit
-                };
-                Ok(compiled_stmt)
-            }
+    /// whether the tree calls any function or reads the input, including branches of conditionals
+    fn has_side_effects(&self) -> bool {
+        match self {
+            ExprTree::Const { .. } | ExprTree::DoubleConst { .. } | ExprTree::Local { .. } => false,
+            ExprTree::Binary { left, right, .. } | ExprTree::DoubleBinary { left, right, .. }
+            | ExprTree::Compare { left, right, .. } => {
+                left.has_side_effects() || right.has_side_effects()
+            },
+            ExprTree::Neg { expr } | ExprTree::Convert { expr, .. } => expr.has_side_effects(),
+            ExprTree::If { cond, then, otherwise, .. } => cond.has_side_effects() || then.has_side_effects() || otherwise.has_side_effects(),
+            ExprTree::Call { .. } | ExprTree::Read => true,
+        }
+    }
+
+    /// whether evaluation of the tree may result in a runtime error (division by zero)
+    fn may_fail(&self) -> bool {
+        match self {
+            ExprTree::Const { .. } | ExprTree::DoubleConst { .. } | ExprTree::Local { .. } => false,
+            ExprTree::Binary { left, op, right, .. } => {
+                matches!(op, ast::Opcode::Div | ast::Opcode::Rem) || left.may_fail() || right.may_fail()
+            },
+            ExprTree::Neg { expr } | ExprTree::Convert { expr, .. } => expr.may_fail(),
+            // division of doubles results in an infinity or NaN instead of failing
+            ExprTree::Compare { left, right, .. } | ExprTree::DoubleBinary { left, right, .. } => {
+                left.may_fail() || right.may_fail()
+            },
+            ExprTree::If { cond, then, otherwise, .. } => cond.may_fail() || then.may_fail() || otherwise.may_fail(),
+            // called function may fail, or never return
+            ExprTree::Call { .. } => true,
+            ExprTree::Read => false,
         }
     }
+
+    /// evaluates operands of a comparison in the order that needs less stack, and jumps to the label
+    /// if the comparison doesn't hold - swapped operands are compared with the mirrored condition
+    fn compile_jump_unless(
+        left: &ExprTree, cond: Condition, right: &ExprTree, label: ir::Label, depth_budget: u32
+    ) -> CompiledCode {
+        let left_first = left.min_depth() >= right.min_depth();
+        let (first, second, cond) = if left_first { (left, right, cond) } else { (right, left, cond.mirror()) };
+        let mut first_code = first.compile_stack(depth_budget);
+        let mut second_code = second.compile_stack(depth_budget - 1);
+        let mut instructions: Vec<Instruction> = vec![];
+        instructions.append(&mut first_code.instructions);
+        instructions.append(&mut second_code.instructions);
+        instructions.push(Instruction::IF_CMP { cond: cond.negate(), label });
+        CompiledCode {
+            instructions,
+            stack_limit: max(first_code.stack_limit, 1 + second_code.stack_limit),
+            locals_limit: 0,
+        }
+    }
+}
+
+fn is_commutative(op: ast::Opcode) -> bool {
+    matches!(op, ast::Opcode::Add | ast::Opcode::Mul | ast::Opcode::And | ast::Opcode::Or | ast::Opcode::Xor)
+}
+
+/// whether operands of the operation on doubles can be evaluated in any order - comparisons are mirrored
+fn is_double_reorderable(op: ast::Opcode) -> bool {
+    matches!(op, ast::Opcode::Add | ast::Opcode::Mul) || op.is_comparison()
+}
+
+pub trait CompileStack {
+    /// compiles the tree using at most `depth_budget` stack slots, which has to be at least its `min_depth`
+    fn compile_stack(&self, depth_budget: u32) -> CompiledCode;
 }
 
-impl CompileStack for ast::Expr {
-    fn compile_stack(&self, env: &mut HashMap<String, i32>) -> Result<CompiledCode, CompilationError> {
+impl CompileStack for ExprTree {
+    fn compile_stack(&self, depth_budget: u32) -> CompiledCode {
         match self {
-            ast::Expr::Number {val} => {
+            ExprTree::Const {val} => {
                 let instruction = Instruction::PUSH {val: *val};
-                let compiled_code = CompiledCode {
+                CompiledCode {
                     instructions: vec![instruction],
                     stack_limit: 1,
                     locals_limit: 0
-                };
-                Ok(compiled_code)
+                }
             },
-            ast::Expr::Variable {var} => {
-                match env.get(var) {
-                    Option::None => {
-                        Err(CompilationError::UnidentifiedVariable { identifier: var.to_string() })
-                    },
-                    Option::Some(variable_address) => {
-                        let instruction = Instruction::LOAD {addr: *variable_address};
-                        let compiled_code = CompiledCode {
-                            instructions: vec![instruction],
-                            stack_limit: 1,
-                            locals_limit: 1,
-                        };
-                        Ok(compiled_code)
-                    }
+            ExprTree::DoubleConst {val} => {
+                let instruction = Instruction::PUSH_DOUBLE {bits: val.to_bits()};
+                CompiledCode {
+                    instructions: vec![instruction],
+                    stack_limit: 1,
+                    locals_limit: 0
                 }
             },
-            ast::Expr::Binary {left, op, right} => {
-                let mut lhs = left.compile_stack(env)?;
-                let mut rhs = right.compile_stack(env)?;
+            ExprTree::Local {addr, ty} => {
+                let instruction = match ty {
+                    Type::Int => Instruction::LOAD {addr: *addr},
+                    Type::Double => Instruction::DLOAD {addr: *addr},
+                };
+                CompiledCode {
+                    instructions: vec![instruction],
+                    stack_limit: 1,
+                    locals_limit: 0,
+                }
+            },
+            ExprTree::Binary {left, op, right, ..} => {
+                let (lhs_depth, rhs_depth) = (left.min_depth(), right.min_depth());
+                // operands of commutative operations are evaluated in the order that needs less stack,
+                // other operations evaluate the left operand first (so that SWAP is not needed),
+                // unless that exceeds the budget - only then the minimal depth is worth an extra SWAP
+                let left_first = if is_commutative(*op) {
+                    lhs_depth >= rhs_depth
+                } else {
+                    max(lhs_depth, rhs_depth + 1) <= depth_budget
+                };
+                let (first, second) = if left_first { (left, right) } else { (right, left) };
 
+                let mut first_code = first.compile_stack(depth_budget);
+                let mut second_code = second.compile_stack(depth_budget - 1);
                 let mut instructions: Vec<Instruction> = vec![];
-                if lhs.stack_limit >= rhs.stack_limit {
-                    instructions.append(&mut lhs.instructions);
-                    instructions.append(&mut rhs.instructions);
-                } else {
-                    instructions.append(&mut rhs.instructions);
-                    instructions.append(&mut lhs.instructions);
+                instructions.append(&mut first_code.instructions);
+                instructions.append(&mut second_code.instructions);
+                if !left_first && !is_commutative(*op) {
+                    instructions.push(Instruction::SWAP);
+                }
 
-                    if *op == ast::Opcode::Sub || *op == ast::Opcode::Div {
-                        instructions.push(Instruction::SWAP);
-                    }
+                let instruction = match op {
+                    ast::Opcode::Add => Instruction::ADD,
+                    ast::Opcode::Sub => Instruction::SUB,
+                    ast::Opcode::Mul => Instruction::MUL,
+                    ast::Opcode::Div => Instruction::DIV,
+                    ast::Opcode::Rem => Instruction::REM,
+                    ast::Opcode::And => Instruction::AND,
+                    ast::Opcode::Or => Instruction::OR,
+                    ast::Opcode::Xor => Instruction::XOR,
+                    ast::Opcode::Shl => Instruction::SHL,
+                    ast::Opcode::Shr => Instruction::SHR,
+                    // comparisons are built as `ExprTree::Compare`
+                    ast::Opcode::Eq | ast::Opcode::Ne | ast::Opcode::Lt | ast::Opcode::Le | ast::Opcode::Gt
+                    | ast::Opcode::Ge => unreachable!(),
+                };
+                instructions.push(instruction);
+
+                CompiledCode {
+                    instructions,
+                    stack_limit: max(first_code.stack_limit, 1 + second_code.stack_limit),
+                    locals_limit: 0,
                 }
+            },
+            ExprTree::DoubleBinary {left, op, right, ..} => {
+                let left_first = !is_double_reorderable(*op) || left.min_depth() >= right.min_depth();
+                let (first, second) = if left_first { (left, right) } else { (right, left) };
+                let mut first_code = first.compile_stack(depth_budget);
+                let mut second_code = second.compile_stack(depth_budget - 1);
+                let mut instructions: Vec<Instruction> = vec![];
+                instructions.append(&mut first_code.instructions);
+                instructions.append(&mut second_code.instructions);
 
-                match op {
-                    ast::Opcode::Add => {
-                        instructions.push(Instruction::ADD);
-                    },
-                    ast::Opcode::Sub => {
-                        instructions.push(Instruction::SUB);
+                let instruction = match (op, Condition::from_opcode(*op)) {
+                    (_, Some(cond)) if left_first => Instruction::DCMP { cond },
+                    (_, Some(cond)) => Instruction::DCMP { cond: cond.mirror() },
+                    (ast::Opcode::Add, _) => Instruction::DADD,
+                    (ast::Opcode::Sub, _) => Instruction::DSUB,
+                    (ast::Opcode::Mul, _) => Instruction::DMUL,
+                    (ast::Opcode::Div, _) => Instruction::DDIV,
+                    (ast::Opcode::Rem, _) => Instruction::DREM,
+                    // bitwise operations and shifts are rejected for doubles by the type checker
+                    _ => unreachable!(),
+                };
+                instructions.push(instruction);
+
+                CompiledCode {
+                    instructions,
+                    stack_limit: max(first_code.stack_limit, 1 + second_code.stack_limit),
+                    locals_limit: 0,
+                }
+            },
+            ExprTree::Neg { expr } => {
+                let mut compiled_expr = expr.compile_stack(depth_budget);
+                compiled_expr.instructions.push(match expr.ty() {
+                    Type::Int => Instruction::NEG,
+                    Type::Double => Instruction::DNEG,
+                });
+                compiled_expr
+            },
+            ExprTree::Convert { expr, to } => {
+                let mut compiled_expr = expr.compile_stack(depth_budget);
+                compiled_expr.instructions.push(match to {
+                    Type::Int => Instruction::D2I,
+                    Type::Double => Instruction::I2D,
+                });
+                compiled_expr
+            },
+            ExprTree::Compare { left, cond, right, labels: (false_label, end_label), .. } => {
+                let mut compiled = ExprTree::compile_jump_unless(left, *cond, right, *false_label, depth_budget);
+                compiled.instructions.append(&mut vec![
+                    Instruction::PUSH { val: 1 },
+                    Instruction::GOTO { label: *end_label },
+                    Instruction::LABEL { label: *false_label },
+                    Instruction::PUSH { val: 0 },
+                    Instruction::LABEL { label: *end_label },
+                ]);
+                compiled
+            },
+            ExprTree::If { cond, then, otherwise, labels: (else_label, end_label) } => {
+                // comparisons in conditions are not evaluated to 0 or 1, the branch depends directly on them
+                let mut compiled_cond = match cond.as_ref() {
+                    ExprTree::Compare { left, cond, right, .. } => {
+                        ExprTree::compile_jump_unless(left, *cond, right, *else_label, depth_budget)
                     },
-                    ast::Opcode::Mul => {
-                        instructions.push(Instruction::MUL);
+                    _ => {
+                        let mut compiled_cond = cond.compile_stack(depth_budget);
+                        compiled_cond.instructions.push(Instruction::IF { cond: Condition::Eq, label: *else_label });
+                        compiled_cond
                     },
-                    ast::Opcode::Div => {
-                        instructions.push(Instruction::DIV)
-                    }
                 };
-
-                let compiled_code = CompiledCode {
+                let mut compiled_then = then.compile(depth_budget);
+                let mut compiled_otherwise = otherwise.compile(depth_budget);
+                let mut instructions: Vec<Instruction> = vec![];
+                instructions.append(&mut compiled_cond.instructions);
+                instructions.append(&mut compiled_then.instructions);
+                instructions.push(Instruction::GOTO { label: *end_label });
+                instructions.push(Instruction::LABEL { label: *else_label });
+                instructions.append(&mut compiled_otherwise.instructions);
+                instructions.push(Instruction::LABEL { label: *end_label });
+                CompiledCode {
                     instructions,
-                    stack_limit: max(
-                        1 + min(lhs.stack_limit, rhs.stack_limit),
-                        max(lhs.stack_limit, rhs.stack_limit)
-                    ),
-                    locals_limit: env.len() as u32,
+                    stack_limit: max(compiled_cond.stack_limit, max(compiled_then.stack_limit, compiled_otherwise.stack_limit)),
+                    locals_limit: 0,
+                }
+            },
+            ExprTree::Call { function, args, .. } => {
+                // every argument is evaluated above the previous ones
+                let mut instructions: Vec<Instruction> = vec![];
+                let mut stack_limit = 1;
+                for (index, arg) in args.iter().enumerate() {
+                    let mut compiled_arg = arg.compile_stack(depth_budget - index as u32);
+                    stack_limit = max(stack_limit, index as u32 + compiled_arg.stack_limit);
+                    instructions.append(&mut compiled_arg.instructions);
+                }
+                instructions.push(Instruction::CALL { function: function.clone(), args: args.len() as u32 });
+                CompiledCode { instructions, stack_limit, locals_limit: 0 }
+            },
+            ExprTree::Read => CompiledCode { instructions: vec![Instruction::READ], stack_limit: 1, locals_limit: 0 },
+        }
+    }
+}
+
+/// instruction storing the value of the type in the local
+fn store(addr: i32, ty: Type) -> Instruction {
+    match ty {
+        Type::Int => Instruction::STORE { addr },
+        Type::Double => Instruction::DSTORE { addr },
+    }
+}
+
+/// Tree evaluated by a single statement: the result is consumed by the final instruction (STORE, PRINT, RETURN,
+/// or IF which exits a loop), labels and jumps of loops are statements without a tree
+#[derive(Debug)]
+struct Evaluation {
+    tree: Option<ExprTree>,
+    instruction: Instruction,
+}
+
+impl Evaluation {
+    /// minimal stack depth needed by the statement - print needs a slot for its receiver,
+    /// which in the worst case is loaded after the value (and swapped with it)
+    fn min_depth(&self) -> u32 {
+        let tree_depth = self.tree.as_ref().map_or(0, ExprTree::min_depth);
+        match self.instruction {
+            Instruction::PRINT | Instruction::PRINT_INLINE | Instruction::DPRINT | Instruction::DPRINT_INLINE => {
+                max(tree_depth, 2)
+            },
+            _ => tree_depth,
+        }
+    }
+
+    fn compile(&self, stack_limit: u32) -> Vec<Instruction> {
+        let tree = match (&self.tree, &self.instruction) {
+            (None, instruction) => return vec![instruction.clone()],
+            // loop conditions jump directly on comparisons, as conditions of conditional expressions
+            (Some(ExprTree::Compare { left, cond, right, .. }), Instruction::IF { cond: Condition::Eq, label }) => {
+                return ExprTree::compile_jump_unless(left, *cond, right, *label, stack_limit).instructions;
+            },
+            (Some(tree), _) => tree,
+        };
+        let mut instructions = tree.compile_stack(stack_limit).instructions;
+        let print_end = match self.instruction {
+            Instruction::PRINT if tree.min_depth() < stack_limit => Instruction::PRINT_END,
+            Instruction::DPRINT if tree.min_depth() < stack_limit => Instruction::DPRINT_END,
+            _ => {
+                instructions.push(self.instruction.clone());
+                return instructions;
+            },
+        };
+        // when the value fits above the receiver, the receiver can be loaded first, which saves a SWAP
+        // in JVM - this layout is chosen unless evaluation with one slot less needs an extra SWAP
+        let mut receiver_first = tree.compile_stack(stack_limit - 1).instructions;
+        if receiver_first.len() > instructions.len() {
+            instructions.push(self.instruction.clone());
+            return instructions;
+        }
+        receiver_first.insert(0, Instruction::PRINT_BEGIN);
+        receiver_first.push(print_end);
+        receiver_first
+    }
+}
+
+/// Code of a single branch being translated (or of the whole program, which is the outermost frame)
+#[derive(Default)]
+struct Frame {
+    evaluations: Vec<Evaluation>,
+    /// trees of temporaries that were not used yet, with the position of their definition
+    pending: HashMap<ir::Temp, (usize, ExprTree)>,
+}
+
+/// Conditional expression whose branches are being translated
+struct Conditional {
+    cond: ExprTree,
+    else_label: ir::Label,
+    /// label after the conditional, known after the end of the first branch
+    end_label: Option<ir::Label>,
+    /// frames of the branches that were already translated
+    branches: Vec<Frame>,
+}
+
+/// State of the translation from three-address code: temporaries are collected into expression trees
+/// until they are used by a statement (store or print). Trees are compiled to instructions only after
+/// the whole program is translated, when the stack limit of the program is known.
+/// Branches of conditional expressions are translated in separate frames, and their trees become parts
+/// of a single `ExprTree::If`, built when the phi node at the end of the conditional is reached.
+struct StackBuilder {
+    frames: Vec<Frame>,
+    conditionals: Vec<Conditional>,
+    locals_limit: u32,
+    use_counts: Vec<u32>,
+    temp_types: Vec<Type>,
+    /// locals of temporaries that are used more than once
+    temp_locals: HashMap<ir::Temp, i32>,
+    /// labels used by the stack code in addition to the labels of the program
+    labels_count: u32,
+}
+
+impl StackBuilder {
+    fn take_tree(&mut self, temp: ir::Temp) -> ExprTree {
+        let innermost = self.frames.len() - 1;
+        for index in (0..self.frames.len()).rev() {
+            if let Some((_, tree)) = self.frames[index].pending.remove(&temp) {
+                if index == innermost {
+                    return tree;
+                }
+                // trees from outside of a branch have to be evaluated before it, as in the original program
+                self.materialize(index, temp, tree);
+                break;
+            }
+        }
+        ExprTree::Local { addr: self.temp_locals[&temp], ty: self.temp_types[temp as usize] }
+    }
+
+    fn evaluate(&mut self, tree: Option<ExprTree>, instruction: Instruction) {
+        self.frames.last_mut().unwrap().evaluations.push(Evaluation { tree, instruction });
+    }
+
+    /// evaluates the tree immediately (at the end of the given frame) and stores the result in a new local
+    fn materialize(&mut self, frame: usize, temp: ir::Temp, tree: ExprTree) {
+        let addr = self.locals_limit as i32;
+        self.locals_limit += 1;
+        let instruction = store(addr, tree.ty());
+        self.frames[frame].evaluations.push(Evaluation { tree: Some(tree), instruction });
+        self.temp_locals.insert(temp, addr);
+    }
+
+    /// evaluates trees which were not used until a statement (or the end of a branch), so that errors
+    /// (division by zero) happen before any other statement is executed, exactly as in the original program
+    fn materialize_pending(&mut self) {
+        self.materialize_pending_in(self.frames.len() - 1);
+    }
+
+    fn materialize_pending_in(&mut self, frame: usize) {
+        let mut pending: Vec<(ir::Temp, (usize, ExprTree))> = self.frames[frame].pending.drain().collect();
+        pending.sort_by_key(|(_, (position, _))| *position);
+        for (temp, (_, tree)) in pending {
+            self.materialize(frame, temp, tree);
+        }
+    }
+
+    /// evaluates the call (or read) as a statement: trees defined before it (also outside of the current branch)
+    /// are evaluated first, since the called function may print values or fail
+    fn evaluate_in_order(&mut self, temp: ir::Temp, tree: ExprTree) {
+        for frame in 0..self.frames.len() {
+            self.materialize_pending_in(frame);
+        }
+        self.materialize(self.frames.len() - 1, temp, tree);
+    }
+
+    /// prints the values in a single line - values which may fail are evaluated before the first value
+    /// is printed, as in the original program, while the other ones are evaluated right before they are printed
+    fn print(&mut self, srcs: &[ir::Temp], trees: Vec<ExprTree>) {
+        let mut values = vec![];
+        for (index, (src, tree)) in srcs.iter().zip(trees).enumerate() {
+            if index > 0 && tree.may_fail() {
+                let tree_type = tree.ty();
+                self.materialize(self.frames.len() - 1, *src, tree);
+                values.push(ExprTree::Local { addr: self.temp_locals[src], ty: tree_type });
+            } else {
+                values.push(tree);
+            }
+        }
+        let last = values.len() - 1;
+        for (index, value) in values.into_iter().enumerate() {
+            let instruction = match (index == last, value.ty()) {
+                (true, Type::Int) => Instruction::PRINT,
+                (false, Type::Int) => Instruction::PRINT_INLINE,
+                (true, Type::Double) => Instruction::DPRINT,
+                (false, Type::Double) => Instruction::DPRINT_INLINE,
+            };
+            self.evaluate(Some(value), instruction);
+        }
+    }
+
+    fn call(&mut self, temp: ir::Temp, function: &str, args: Vec<ExprTree>) {
+        let min_depth = args.iter().enumerate()
+            .map(|(index, arg)| index as u32 + arg.min_depth())
+            .fold(1, max);
+        self.evaluate_in_order(temp, ExprTree::Call { function: String::from(function), args, min_depth });
+    }
+
+    fn define(&mut self, position: usize, temp: ir::Temp, tree: ExprTree) {
+        let use_count = self.use_counts[temp as usize];
+        if use_count == 1 {
+            self.frames.last_mut().unwrap().pending.insert(temp, (position, tree));
+        } else if use_count > 1 || tree.may_fail() {
+            // unused temporaries are also evaluated if they may still cause an error
+            self.materialize(self.frames.len() - 1, temp, tree);
+        }
+    }
+
+    fn new_label(&mut self) -> ir::Label {
+        self.labels_count += 1;
+        self.labels_count - 1
+    }
+
+    fn binary(&mut self, left: ExprTree, op: ast::Opcode, right: ExprTree) -> ExprTree {
+        match Condition::from_opcode(op) {
+            Some(cond) => {
+                let (lhs_depth, rhs_depth) = (left.min_depth(), right.min_depth());
+                let min_depth = max(max(lhs_depth, rhs_depth), 1 + min(lhs_depth, rhs_depth));
+                let labels = (self.new_label(), self.new_label());
+                ExprTree::Compare { left: Box::new(left), cond, right: Box::new(right), min_depth, labels }
+            },
+            None => ExprTree::binary(left, op, right),
+        }
+    }
+
+    /// finishes translation of the branch which ends with a jump to the end of the conditional
+    fn end_branch(&mut self, end_label: ir::Label) {
+        let frame = self.frames.pop().unwrap();
+        let conditional = self.conditionals.last_mut().unwrap();
+        conditional.end_label = Some(end_label);
+        conditional.branches.push(frame);
+    }
+
+    /// builds the conditional expression from the translated branches and their results
+    fn end_conditional(&mut self, incoming: &[(ir::Temp, ir::Label)]) -> ExprTree {
+        let conditional = self.conditionals.pop().unwrap();
+        let mut blocks: Vec<Block> = vec![];
+        for (frame, (temp, _)) in conditional.branches.into_iter().zip(incoming) {
+            self.frames.push(frame);
+            let result = self.take_tree(*temp);
+            self.materialize_pending();
+            let frame = self.frames.pop().unwrap();
+            blocks.push(Block { evaluations: frame.evaluations, result: Box::new(result) });
+        }
+        let otherwise = blocks.pop().unwrap();
+        let then = blocks.pop().unwrap();
+        let labels = (conditional.else_label, conditional.end_label.unwrap());
+        ExprTree::If { cond: Box::new(conditional.cond), then, otherwise, labels }
+    }
+}
+
+/// compiles the main program and every function to a separate list of instructions on abstract stack-based machine
+pub fn compile_stack(program: &ir::Program) -> CompiledProgram {
+    let functions = program.functions.iter()
+        .map(|function| CompiledFunction {
+            name: function.name.clone(),
+            params: function.body.params,
+            code: compile_body(&function.body),
+        })
+        .collect();
+    CompiledProgram { main: compile_body(program), functions, int_width: program.int_width }
+}
+
+/// compiles the operations of a single program (or function body), with stack and locals limits of its own
+fn compile_body(program: &ir::Program) -> CompiledCode {
+    let mut builder = StackBuilder {
+        frames: vec![Frame::default()],
+        conditionals: vec![],
+        locals_limit: program.variables.len() as u32,
+        use_counts: program.use_counts(),
+        temp_types: program.temp_types(),
+        temp_locals: HashMap::new(),
+        labels_count: program.labels_count,
+    };
+    let loops = program.loops();
+    let loop_exits: HashSet<ir::Label> = loops.values().map(|ir_loop| ir_loop.exit).collect();
+
+    for (position, op) in program.ops.iter().enumerate() {
+        match op {
+            ir::Op::Const { dst, val } => {
+                builder.define(position, *dst, ExprTree::Const { val: *val });
+            },
+            ir::Op::Load { dst, var } => {
+                let ty = program.variable_types[*var as usize];
+                builder.define(position, *dst, ExprTree::Local { addr: *var as i32, ty });
+            },
+            ir::Op::BinOp { dst, op, lhs, rhs } => {
+                let left = builder.take_tree(*lhs);
+                let right = builder.take_tree(*rhs);
+                let tree = builder.binary(left, *op, right);
+                builder.define(position, *dst, tree);
+            },
+            ir::Op::Neg { dst, src } => {
+                let expr = builder.take_tree(*src);
+                builder.define(position, *dst, ExprTree::Neg { expr: Box::new(expr) });
+            },
+            ir::Op::DoubleConst { dst, val } => {
+                builder.define(position, *dst, ExprTree::DoubleConst { val: *val });
+            },
+            // comparisons of doubles are evaluated to 0 or 1 without jumps
+            ir::Op::DoubleBinOp { dst, op, lhs, rhs } => {
+                let left = builder.take_tree(*lhs);
+                let right = builder.take_tree(*rhs);
+                builder.define(position, *dst, ExprTree::double_binary(left, *op, right));
+            },
+            ir::Op::DoubleNeg { dst, src } => {
+                let expr = builder.take_tree(*src);
+                builder.define(position, *dst, ExprTree::Neg { expr: Box::new(expr) });
+            },
+            ir::Op::Convert { dst, src, to } => {
+                let expr = builder.take_tree(*src);
+                builder.define(position, *dst, ExprTree::Convert { expr: Box::new(expr), to: *to });
+            },
+            ir::Op::Store { var, src } => {
+                let tree = builder.take_tree(*src);
+                builder.materialize_pending();
+                let instruction = store(*var as i32, program.variable_types[*var as usize]);
+                builder.evaluate(Some(tree), instruction);
+            },
+            ir::Op::Print { srcs } => {
+                let trees: Vec<ExprTree> = srcs.iter().map(|src| builder.take_tree(*src)).collect();
+                builder.materialize_pending();
+                builder.print(srcs, trees);
+            },
+            // loops are statements, so they are translated in the outermost frame, as a sequence of evaluations
+            ir::Op::Branch { cond, else_label, .. } if loop_exits.contains(else_label) => {
+                let cond = builder.take_tree(*cond);
+                builder.materialize_pending();
+                builder.evaluate(Some(cond), Instruction::IF { cond: Condition::Eq, label: *else_label });
+            },
+            ir::Op::Branch { cond, else_label, .. } => {
+                let cond = builder.take_tree(*cond);
+                builder.conditionals.push(Conditional { cond, else_label: *else_label, end_label: None, branches: vec![] });
+                builder.frames.push(Frame::default());
+            },
+            ir::Op::Label { label } if loops.contains_key(label) || loop_exits.contains(label) => {
+                builder.materialize_pending();
+                builder.evaluate(None, Instruction::LABEL { label: *label });
+            },
+            ir::Op::Label { label } => {
+                // the first branch starts right after the branch instruction, the end label is followed by a phi
+                if builder.conditionals.last().is_some_and(|conditional| conditional.else_label == *label) {
+                    builder.frames.push(Frame::default());
+                }
+            },
+            // the loop is entered by falling through to its header
+            ir::Op::Jump { label } if loops.get(label).is_some_and(|ir_loop| ir_loop.start > position) => {},
+            ir::Op::Jump { label } if loops.contains_key(label) => {
+                builder.materialize_pending();
+                builder.evaluate(None, Instruction::GOTO { label: *label });
+            },
+            ir::Op::Jump { label } => builder.end_branch(*label),
+            ir::Op::Phi { dst, incoming } => {
+                let tree = builder.end_conditional(incoming);
+                if tree.has_side_effects() {
+                    // calls and reads in the branches can't be reordered with other operations
+                    builder.materialize(builder.frames.len() - 1, *dst, tree);
+                } else {
+                    builder.define(position, *dst, tree);
+                }
+            },
+            ir::Op::Call { dst, function, args } => {
+                let args = args.iter().map(|arg| builder.take_tree(*arg)).collect();
+                builder.call(*dst, function, args);
+            },
+            ir::Op::Read { dst } => builder.evaluate_in_order(*dst, ExprTree::Read),
+            // returns are statements, in the outermost frame
+            ir::Op::Return { src } => {
+                let tree = builder.take_tree(*src);
+                builder.materialize_pending();
+                builder.evaluate(Some(tree), Instruction::RETURN);
+            },
+        }
+    }
+    builder.materialize_pending();
+
+    // the minimal stack limit of the program, every tree can use all of it to avoid SWAP instructions
+    let evaluations = builder.frames.pop().unwrap().evaluations;
+    let stack_limit = evaluations.iter()
+        .map(|evaluation| evaluation.min_depth())
+        .max()
+        .unwrap_or(0);
+    let mut instructions: Vec<Instruction> = vec![];
+    for evaluation in evaluations {
+        instructions.append(&mut evaluation.compile(stack_limit));
+    }
+
+    let mut compiled_program = CompiledCode {
+        instructions,
+        stack_limit,
+        locals_limit: builder.locals_limit,
+    };
+    reuse_locals(&mut compiled_program, program.params);
+    compiled_program
+}
+
+/// Loops of the compiled code which are not nested in other loops, as ranges of positions
+/// from the label of the loop to the backward jump to it
+fn outermost_loops(instructions: &[Instruction]) -> Vec<(usize, usize)> {
+    let mut label_positions: HashMap<ir::Label, usize> = HashMap::new();
+    let mut loops: Vec<(usize, usize)> = vec![];
+    for (position, instr) in instructions.iter().enumerate() {
+        match instr {
+            Instruction::LABEL { label } => {
+                label_positions.insert(*label, position);
+            },
+            Instruction::GOTO { label } if label_positions.contains_key(label) => {
+                let start = label_positions[label];
+                // loops nested in this one end before it, so they are the last ones on the list
+                while loops.last().is_some_and(|(inner_start, _)| *inner_start > start) {
+                    loops.pop();
+                }
+                loops.push((start, position));
+            },
+            _ => {},
+        }
+    }
+    loops
+}
+
+/// Reassigns locals so that values with disjoint live ranges share the same local.
+/// Every store starts a new value, which is live until its last load, so after a backward pass that marks
+/// last loads, a single forward pass releases a local at the last load of its value (or right after the store,
+/// if it is never loaded) and reuses released locals for next stores. Jumps of conditional expressions are
+/// forward, so they don't change this order - only loops do: locals loaded in a loop may be loaded again
+/// in its next iteration, so they are live during the whole loop, and released at its end (if at all).
+/// Parameters of a function are stored by its caller, so they keep their locals until their last use.
+/// A local may hold an integer and later a double (backends reserve the same space for both).
+fn reuse_locals(compiled_program: &mut CompiledCode, params: u32) {
+    let locals_count = compiled_program.locals_limit as usize;
+    let instructions = &compiled_program.instructions;
+    // locals loaded in every loop, and the end of the loop, by the position of its beginning
+    let mut loop_locals: HashMap<usize, (usize, Vec<usize>)> = HashMap::new();
+    let mut pinned: Vec<Option<usize>> = vec![None; instructions.len()];
+    for (start, end) in outermost_loops(instructions) {
+        let mut locals: Vec<usize> = instructions[start..end].iter()
+            .filter_map(|instr| match instr {
+                Instruction::LOAD { addr } | Instruction::DLOAD { addr } => Some(*addr as usize),
+                _ => None,
+            })
+            .collect();
+        locals.sort_unstable();
+        locals.dedup();
+        pinned[start..=end].fill(Some(start));
+        loop_locals.insert(start, (end, locals));
+    }
+    let is_pinned = |position: usize, addr: usize| {
+        pinned[position].is_some_and(|start| loop_locals[&start].1.contains(&addr))
+    };
+
+    let mut live = vec![false; locals_count];
+    let mut releases = vec![false; instructions.len()];
+    let mut loop_releases: HashMap<usize, Vec<usize>> = HashMap::new();
+    let mut live_after_loop: Vec<bool> = vec![];
+    for (position, instr) in instructions.iter().enumerate().rev() {
+        match instr {
+            Instruction::LOAD { addr } | Instruction::DLOAD { addr } => {
+                releases[position] = !live[*addr as usize] && !is_pinned(position, *addr as usize);
+                live[*addr as usize] = true;
+            },
+            Instruction::STORE { addr } | Instruction::DSTORE { addr } => {
+                releases[position] = !live[*addr as usize] && !is_pinned(position, *addr as usize);
+                live[*addr as usize] = false;
+            },
+            Instruction::GOTO { .. } | Instruction::LABEL { .. } => {
+                // only the beginning and the end of the loop, other labels and jumps in it are forward
+                let (end, locals) = match pinned[position] {
+                    Some(start) if start == position || loop_locals[&start].0 == position => &loop_locals[&start],
+                    _ => continue,
                 };
-                Ok(compiled_code)
+                if position == *end {
+                    // end of the loop: locals which are not used after it are released by the backward jump
+                    let released = locals.iter().copied().filter(|addr| !live[*addr]).collect();
+                    loop_releases.insert(position, released);
+                    live_after_loop = live.clone();
+                } else {
+                    // the code after the loop is also reached from its beginning, without executing the body
+                    for (is_live, is_live_after_loop) in live.iter_mut().zip(live_after_loop.iter()) {
+                        *is_live |= *is_live_after_loop;
+                    }
+                }
+                for addr in locals.iter() {
+                    live[*addr] = true;
+                }
             },
+            _ => {},
+        }
+    }
+
+    let mut assigned: Vec<Option<i32>> = vec![None; locals_count];
+    for (param, local) in assigned.iter_mut().take(params as usize).enumerate() {
+        *local = Some(param as i32);
+    }
+    let mut free: Vec<i32> = vec![];
+    let mut locals_limit = params;
+    for (position, instr) in compiled_program.instructions.iter_mut().enumerate() {
+        if let Some(released) = loop_releases.get(&position) {
+            for addr in released.iter() {
+                if let Some(local) = assigned[*addr].take() {
+                    free.push(local);
+                }
+            }
+        }
+        let addr = match instr {
+            Instruction::LOAD { addr } | Instruction::STORE { addr } | Instruction::DLOAD { addr }
+            | Instruction::DSTORE { addr } => addr,
+            _ => continue,
+        };
+        let original = *addr as usize;
+        // previous value of the variable is always released before the next store, at its last load
+        let local = *assigned[original].get_or_insert_with(|| free.pop().unwrap_or_else(|| {
+            locals_limit += 1;
+            locals_limit as i32 - 1
+        }));
+        *addr = local;
+        if releases[position] {
+            assigned[original] = None;
+            free.push(local);
         }
     }
+    compiled_program.locals_limit = locals_limit;
 }
 
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::{Number, RuntimeError};
+    use crate::vm::execute;
+    use crate::ir::lower;
+    use instant_parser::instant::ProgParser;
+
+    fn compile_source(source_code: &str) -> CompiledProgram {
+        compile_stack(&lower(&ProgParser::new().parse(source_code).unwrap(), IntWidth::I32).unwrap())
+    }
+
+    fn count_swaps(compiled_program: &CompiledCode) -> usize {
+        compiled_program.instructions.iter().filter(|instr| matches!(instr, Instruction::SWAP)).count()
+    }
+
+    /// number of SWAP instructions emitted by the previous scheduler, which always evaluated
+    /// the deeper operand first (and swapped the results of non-commutative operations)
+    fn naive_swaps(tree: &ExprTree) -> usize {
+        match tree {
+            ExprTree::Const { .. } | ExprTree::Local { .. } => 0,
+            ExprTree::Neg { expr } => naive_swaps(expr),
+            ExprTree::Binary { left, op, right, .. } => {
+                let swap = !is_commutative(*op) && left.min_depth() < right.min_depth();
+                swap as usize + naive_swaps(left) + naive_swaps(right)
+            },
+            ExprTree::Compare { .. } | ExprTree::If { .. } | ExprTree::Call { .. } | ExprTree::Read => {
+                unreachable!("random trees have no control flow nor side effects")
+            },
+            ExprTree::DoubleConst { .. } | ExprTree::DoubleBinary { .. } | ExprTree::Convert { .. } => {
+                unreachable!("random trees have no doubles")
+            },
+        }
+    }
+
+    /// minimal stack depth over all evaluation orders of operands, computed by exhaustive search
+    fn optimal_depth(tree: &ExprTree) -> u32 {
+        match tree {
+            ExprTree::Const { .. } | ExprTree::Local { .. } => 1,
+            ExprTree::Neg { expr } => optimal_depth(expr),
+            ExprTree::Binary { left, right, .. } => {
+                let (lhs_depth, rhs_depth) = (optimal_depth(left), optimal_depth(right));
+                min(max(lhs_depth, rhs_depth + 1), max(rhs_depth, lhs_depth + 1))
+            },
+            ExprTree::Compare { .. } | ExprTree::If { .. } | ExprTree::Call { .. } | ExprTree::Read => {
+                unreachable!("random trees have no control flow nor side effects")
+            },
+            ExprTree::DoubleConst { .. } | ExprTree::DoubleBinary { .. } | ExprTree::Convert { .. } => {
+                unreachable!("random trees have no doubles")
+            },
+        }
+    }
+
+    fn random_tree(state: &mut u64, depth: u32) -> ExprTree {
+        *state = state.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+        let choice = (*state >> 33) % 8;
+        if depth == 0 || choice == 0 {
+            return ExprTree::Local { addr: (choice % 3) as i32, ty: Type::Int };
+        }
+        let op = [ast::Opcode::Add, ast::Opcode::Sub, ast::Opcode::Mul, ast::Opcode::Div][(choice % 4) as usize];
+        ExprTree::binary(random_tree(state, depth - 1), op, random_tree(state, depth - 1))
+    }
+
+    #[test]
+    fn stack_limit_of_trees_is_optimal() {
+        let mut state = 42;
+        for _ in 0..500 {
+            let tree = random_tree(&mut state, 8);
+            assert_eq!(tree.min_depth(), optimal_depth(&tree));
+            let compiled_tree = tree.compile_stack(tree.min_depth());
+            assert_eq!(compiled_tree.stack_limit, tree.min_depth());
+            assert!(count_swaps(&compiled_tree) <= naive_swaps(&tree));
+        }
+    }
+
+    #[test]
+    fn swaps_are_avoided_when_stack_limit_allows() {
+        // the first statement needs 3 slots, so the second one can be evaluated
+        // left to right without SWAP, while the previous scheduler used one SWAP
+        let compiled_program = compile_source("a = 1; (a + a) * (a + a) * a; a - a * a");
+        assert_eq!(compiled_program.main.stack_limit, 3);
+        assert_eq!(count_swaps(&compiled_program.main), 0);
+
+        // with no room for the left operand, SWAP is still needed to keep the minimal stack limit
+        let compiled_program = compile_source("a = 1; b = a - a * a");
+        assert_eq!(compiled_program.main.stack_limit, 2);
+        assert_eq!(count_swaps(&compiled_program.main), 1);
+    }
+
+    #[test]
+    fn print_receiver_is_loaded_first_when_it_fits() {
+        let compiled_program = compile_source("a = 1; b = (a + a) * (a + a); a - a; a * (a + a) * (a + a)");
+        assert_eq!(compiled_program.main.stack_limit, 3);
+        let prints: Vec<&Instruction> = compiled_program.main.instructions.iter()
+            .filter(|instr| matches!(instr, Instruction::PRINT | Instruction::PRINT_BEGIN))
+            .collect();
+        // the last tree needs the whole stack, so its receiver has to be loaded after the value
+        assert_eq!(prints, vec![&Instruction::PRINT_BEGIN, &Instruction::PRINT]);
+        assert_eq!(count_swaps(&compiled_program.main), 0);
+
+        let mut output = vec![];
+        execute(&compiled_program, &mut || 0, &mut |val, _| output.push(val)).unwrap();
+        assert_eq!(output, vec![Number::Int(0), Number::Int(4)]);
+    }
+
+    #[test]
+    fn commutative_operations_do_not_need_swaps() {
+        let compiled_program = compile_source("a = 1; b = a + a * a; b = a * (a + (a - a) * a)");
+        assert_eq!(compiled_program.main.stack_limit, 2);
+        assert_eq!(count_swaps(&compiled_program.main), 0);
+    }
+
+    #[test]
+    fn variables_with_disjoint_live_ranges_share_locals() {
+        let compiled_program = compile_source("a = 1; b = a + 1; c = b * 2; d = c - a; d; e = 5; e; d");
+        assert_eq!(compiled_program.main.locals_limit, 2);
+        let mut output = vec![];
+        execute(&compiled_program, &mut || 0, &mut |val, _| output.push(val)).unwrap();
+        assert_eq!(output, vec![Number::Int(3), Number::Int(5), Number::Int(3)]);
+
+        // variables which are never read still need a local for their store, but only until the next store
+        let compiled_program = compile_source("a = 1; b = 2; c = 3; a = b + c; a");
+        assert_eq!(compiled_program.main.locals_limit, 2);
+    }
+
+    #[test]
+    fn locals_loaded_in_loops_are_live_during_the_whole_loop() {
+        let compiled_program = compile_source("a = 1; i = 0; while (i < 3) { b = a + i; a = b * 2; i = i + 1 }; a");
+        assert_eq!(compiled_program.main.locals_limit, 3);
+        let mut output = vec![];
+        execute(&compiled_program, &mut || 0, &mut |val, _| output.push(val)).unwrap();
+        assert_eq!(output, vec![Number::Int(16)]);
+
+        // the code after the loop is reached also when the body is not executed at all
+        let compiled_program = compile_source("b = 7; i = 0; while (i < 0) { c = 1; b = c; i = i + 1 }; b");
+        let mut output = vec![];
+        execute(&compiled_program, &mut || 0, &mut |val, _| output.push(val)).unwrap();
+        assert_eq!(output, vec![Number::Int(7)]);
+    }
+
+    #[test]
+    fn conditions_jump_directly_on_comparisons() {
+        let compiled_program = compile_source("a = 1; b = 2; if a < b then a else b; a >= b");
+        let jumps: Vec<&Instruction> = compiled_program.main.instructions.iter()
+            .filter(|instr| instr.jump_target().is_some())
+            .collect();
+        // the comparison in the condition doesn't need its own jumps to compute 0 or 1
+        // (labels 3 and 4 were reserved for them, but are not used)
+        assert_eq!(jumps, vec![
+            &Instruction::IF_CMP { cond: Condition::Ge, label: 1 },
+            &Instruction::GOTO { label: 2 },
+            &Instruction::IF_CMP { cond: Condition::Lt, label: 5 },
+            &Instruction::GOTO { label: 6 },
+        ]);
+        let mut output = vec![];
+        execute(&compiled_program, &mut || 0, &mut |val, _| output.push(val)).unwrap();
+        assert_eq!(output, vec![Number::Int(1), Number::Int(0)]);
+    }
+
+    #[test]
+    fn stack_limit_of_conditionals_is_the_maximum_over_branches() {
+        // only the second branch needs 3 slots, and the conditional is evaluated above another value
+        let compiled_program = compile_source("a = 1; b = (a - a) * (a - a) - (if a then a else (a - a) * (a - a))");
+        assert_eq!(compiled_program.main.stack_limit, 4);
+        let compiled_program = compile_source("a = 1; b = (a - a) * (a - a) - (if a then a else a - a)");
+        assert_eq!(compiled_program.main.stack_limit, 3);
+
+        // temporaries used more than once in a branch are stored in locals in this branch only
+        let compiled_program = compile_source("a = 0; if a then 1 / a else (a + 1) * (a + 1)");
+        let mut output = vec![];
+        execute(&compiled_program, &mut || 0, &mut |val, _| output.push(val)).unwrap();
+        assert_eq!(output, vec![Number::Int(1)]);
+    }
+
+    #[test]
+    fn temporaries_used_more_than_once_are_stored_in_locals() {
+        // print (7 - 2) * (7 - 2); print 7 - 2
+        let program = ir::Program {
+            ops: vec![
+                ir::Op::Const { dst: 0, val: 7 },
+                ir::Op::Const { dst: 1, val: 2 },
+                ir::Op::BinOp { dst: 2, op: ast::Opcode::Sub, lhs: 0, rhs: 1 },
+                ir::Op::BinOp { dst: 3, op: ast::Opcode::Mul, lhs: 2, rhs: 2 },
+                ir::Op::Print { srcs: vec![3] },
+                ir::Op::Print { srcs: vec![2] },
+            ],
+            variables: vec![],
+            variable_types: vec![],
+            temps_count: 4,
+            labels_count: 0,
+            params: 0,
+            functions: vec![],
+            int_width: IntWidth::I32,
+        };
+        let compiled_program = compile_stack(&program);
+        assert_eq!(compiled_program.main.locals_limit, 1);
 
-/// compiles the program to a list of instructions on abstract stack-based machine
-pub fn compile_stack(program: &ast::Prog) -> Result<CompiledCode, CompilationError> {
-    let mut env: HashMap<String, i32> = HashMap::new();
-    let compiled_program = program.compile_stack(&mut env)?;
-    Ok(compiled_program)
+        let mut output = vec![];
+        execute(&compiled_program, &mut || 0, &mut |val, _| output.push(val)).unwrap();
+        assert_eq!(output, vec![Number::Int(25), Number::Int(5)]);
+    }
+
+    #[test]
+    fn values_printed_in_a_line_are_evaluated_before_printing() {
+        // the division is evaluated before the first value is printed, while the other values are evaluated
+        // right before they are printed
+        let compiled_program = compile_source("a = 0; print a + 1, 1 / a, -a");
+        let instructions = &compiled_program.main.instructions;
+        let first_print = instructions.iter().position(|instr| *instr == Instruction::PRINT_INLINE).unwrap();
+        assert!(instructions[..first_print].contains(&Instruction::DIV));
+        assert_eq!(instructions.iter().filter(|instr| **instr == Instruction::PRINT_INLINE).count(), 2);
+        assert_eq!(compiled_program.main.locals_limit, 2);
+
+        let mut output = vec![];
+        let result = execute(&compiled_program, &mut || 0, &mut |val, newline| output.push((val, newline)));
+        assert!(matches!(result, Err(RuntimeError::DivisionByZero)));
+        assert!(output.is_empty());
+
+        let compiled_program = compile_source("a = 1; print a + 1, 1 / a, -a");
+        let mut output = vec![];
+        execute(&compiled_program, &mut || 0, &mut |val, newline| output.push((val, newline))).unwrap();
+        assert_eq!(output, vec![(Number::Int(2), false), (Number::Int(1), false), (Number::Int(-1), true)]);
+    }
+
+    #[test]
+    fn functions_have_their_own_limits() {
+        let compiled_program = compile_source("fn f(a, b, c) = (a - b) * (b - c) - (c - a) * a; a = 1; f(a, a + 1, f(a, a, a))");
+        let function = &compiled_program.functions[0];
+        assert_eq!((function.params, function.code.locals_limit, function.code.stack_limit), (3, 3, 3));
+        // arguments of the outer call evaluated before the inner call are stored in locals,
+        // so that every call is executed with only its own arguments on the stack
+        assert_eq!(compiled_program.main.stack_limit, 3);
+        assert_eq!(compiled_program.main.locals_limit, 3);
+        assert_eq!(function.code.instructions.last(), Some(&Instruction::RETURN));
+
+        let mut output = vec![];
+        execute(&compiled_program, &mut || 0, &mut |val, _| output.push(val)).unwrap();
+        assert_eq!(output, vec![Number::Int(-1)]);
+    }
 }
+
@@ -0,0 +1,108 @@
+//! Typing rules of expressions, shared by the lowering to IR (which checks the program and inserts conversions)
+//! and the interpreter (which computes types of conditional expressions before the program is executed,
+//! as only one of their branches is evaluated).
+//! Integers are converted to doubles implicitly: in arithmetic and comparisons with a double, in branches
+//! of a conditional whose other branch is a double, and in assignments to double variables.
+//! Doubles are converted to integers only explicitly, with `int(...)`.
+
+use instant_parser::ast::{self, Type};
+use crate::common::CompilationError;
+
+/// checks that the value can be used where a value of the expected type is needed, without any conversion
+pub fn expect(expected: Type, found: Type) -> Result<(), CompilationError> {
+    if expected == found {
+        Ok(())
+    } else {
+        Err(CompilationError::TypeMismatch { expected, found })
+    }
+}
+
+/// type to which values of both types are converted: a double, unless both of them are integers
+pub fn join(first: Type, second: Type) -> Type {
+    if first == second { first } else { Type::Double }
+}
+
+/// type of the result of the binary operation - comparisons result in integers (0 or 1),
+/// while bitwise operations and shifts are defined only on integers
+pub fn binary_type(op: ast::Opcode, lhs: Type, rhs: Type) -> Result<Type, CompilationError> {
+    match op {
+        ast::Opcode::And | ast::Opcode::Or | ast::Opcode::Xor | ast::Opcode::Shl | ast::Opcode::Shr => {
+            expect(Type::Int, lhs)?;
+            expect(Type::Int, rhs)?;
+            Ok(Type::Int)
+        },
+        _ if op.is_comparison() => Ok(Type::Int),
+        _ => Ok(join(lhs, rhs)),
+    }
+}
+
+/// type of the expression, given types of the variables visible in it - conditions, arguments of calls
+/// and results of functions are integers (values of parameters and results are never doubles)
+pub fn infer(expr: &ast::Expr, variable_type: &dyn Fn(&str) -> Option<Type>) -> Result<Type, CompilationError> {
+    match expr {
+        ast::Expr::Binary { left, op, right } => {
+            binary_type(*op, infer(left, variable_type)?, infer(right, variable_type)?)
+        },
+        ast::Expr::If { cond, then, otherwise } => {
+            expect(Type::Int, infer(cond, variable_type)?)?;
+            Ok(join(infer(then, variable_type)?, infer(otherwise, variable_type)?))
+        },
+        ast::Expr::Neg { expr } => infer(expr, variable_type),
+        ast::Expr::Number { .. } | ast::Expr::BigNumber { .. } | ast::Expr::Read => Ok(Type::Int),
+        ast::Expr::Double { .. } => Ok(Type::Double),
+        ast::Expr::Variable { var } => {
+            variable_type(var).ok_or_else(|| CompilationError::UnidentifiedVariable { identifier: var.clone() })
+        },
+        ast::Expr::Call { args, .. } => {
+            for arg in args.iter() {
+                expect(Type::Int, infer(arg, variable_type)?)?;
+            }
+            Ok(Type::Int)
+        },
+        ast::Expr::Convert { to, expr } => {
+            infer(expr, variable_type)?;
+            Ok(*to)
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use instant_parser::instant::ProgParser;
+
+    fn infer_source(source: &str) -> Result<Type, CompilationError> {
+        let program = ProgParser::new().parse(source).unwrap();
+        let expr = match program.stmts[0].as_ref() {
+            ast::Stmt::Print { exprs } => &exprs[0],
+            _ => unreachable!(),
+        };
+        infer(expr, &|var| match var {
+            "i" => Some(Type::Int),
+            "d" => Some(Type::Double),
+            _ => None,
+        })
+    }
+
+    #[test]
+    fn integers_are_converted_to_doubles_implicitly() {
+        assert_eq!(infer_source("i + 1").unwrap(), Type::Int);
+        assert_eq!(infer_source("i * 2.5").unwrap(), Type::Double);
+        assert_eq!(infer_source("-d / i").unwrap(), Type::Double);
+        assert_eq!(infer_source("if i < d then 1 else d").unwrap(), Type::Double);
+        assert_eq!(infer_source("d >= 0.5").unwrap(), Type::Int);
+        assert_eq!(infer_source("int(d) % 2").unwrap(), Type::Int);
+        assert_eq!(infer_source("double(read())").unwrap(), Type::Double);
+    }
+
+    #[test]
+    fn doubles_are_rejected_where_integers_are_needed() {
+        assert!(matches!(
+            infer_source("d << 1"),
+            Err(CompilationError::TypeMismatch { expected: Type::Int, found: Type::Double })
+        ));
+        assert!(matches!(infer_source("if d then 1 else 2"), Err(CompilationError::TypeMismatch { .. })));
+        assert!(matches!(infer_source("f(1, 0.5)"), Err(CompilationError::TypeMismatch { .. })));
+        assert!(matches!(infer_source("x + 1.0"), Err(CompilationError::UnidentifiedVariable { .. })));
+    }
+}
@@ -0,0 +1,234 @@
+use instant_parser::ast;
+use crate::common::{IntWidth, Number, RuntimeError, MAX_CALL_DEPTH};
+use crate::interpreter::{evaluate_binary, evaluate_double_binary, evaluate_neg, Value};
+use crate::ir;
+use crate::stack::{CompiledCode, CompiledFunction, CompiledProgram, Instruction};
+
+use std::collections::HashMap;
+
+/// Executes instructions of the abstract stack machine (with the same semantics as JVM),
+/// on integers of the width of the program (values read from `input` are wrapped around to it) -
+/// unbounded integers are computed only as long as they fit in i64, otherwise execution fails with `IntegerOverflow`.
+/// Doubles are kept on the stack and in locals as their bits.
+/// Panics if the code exceeds the declared stack or locals limit, so it can be used
+/// to check correctness of the stack compiler, independently of the target platform
+/// (including the same stack depth on all paths to every label).
+pub fn execute(
+    compiled_program: &CompiledProgram, input: &mut dyn FnMut() -> i64, output: &mut dyn FnMut(Number<i64>, bool)
+) -> Result<(), RuntimeError> {
+    let mut machine = Machine {
+        functions: compiled_program.functions.iter().map(|function| (function.name.as_str(), function)).collect(),
+        int_width: compiled_program.int_width,
+        input,
+        output,
+    };
+    machine.run(&compiled_program.main)
+}
+
+/// State shared by all calls
+struct Machine<'p, 'o> {
+    functions: HashMap<&'p str, &'p CompiledFunction>,
+    int_width: IntWidth,
+    input: &'o mut dyn FnMut() -> i64,
+    output: &'o mut dyn FnMut(Number<i64>, bool),
+}
+
+/// Code executed by a single call, with its own stack and locals
+struct Frame<'p> {
+    code: &'p CompiledCode,
+    position: usize,
+    stack: Vec<i64>,
+    locals: Vec<i64>,
+    label_positions: HashMap<ir::Label, usize>,
+    label_depths: HashMap<ir::Label, usize>,
+}
+
+impl<'p> Frame<'p> {
+    /// frame of the code with the given arguments in its first locals
+    fn new(code: &'p CompiledCode, args: Vec<i64>) -> Self {
+        let mut locals: Vec<i64> = vec![0; code.locals_limit as usize];
+        assert!(args.len() <= locals.len(), "parameters exceed the locals limit");
+        locals[..args.len()].copy_from_slice(&args);
+        let label_positions: HashMap<ir::Label, usize> = code.instructions.iter().enumerate()
+            .filter_map(|(position, instr)| match instr {
+                Instruction::LABEL { label } => Some((*label, position)),
+                _ => None,
+            })
+            .collect();
+        Frame {
+            code,
+            position: 0,
+            stack: Vec::with_capacity(code.stack_limit as usize),
+            locals,
+            label_positions,
+            label_depths: HashMap::new(),
+        }
+    }
+
+    /// position of the label, checking that the stack has the same depth on all paths to it
+    fn jump_to(&mut self, label: ir::Label) -> usize {
+        let depth = self.stack.len();
+        assert_eq!(*self.label_depths.entry(label).or_insert(depth), depth, "stack depth differs between paths");
+        self.label_positions[&label]
+    }
+}
+
+impl<'p> Machine<'p, '_> {
+    /// runs the main program until it reaches its end - frames of the called functions are kept on the heap
+    /// (in `callers`, except the current one), so the depth of nested calls is not limited by the native stack
+    fn run(&mut self, main: &'p CompiledCode) -> Result<(), RuntimeError> {
+        let mut frame = Frame::new(main, vec![]);
+        let mut callers: Vec<Frame> = vec![];
+        loop {
+            let code = frame.code;
+            let instr = match code.instructions.get(frame.position) {
+                Some(instr) => instr,
+                // functions end with RETURN, so only the main program reaches the end of its code
+                None => break,
+            };
+            frame.position += 1;
+            match instr {
+                Instruction::PUSH { val } => frame.stack.push(*val),
+                Instruction::LOAD { addr } => frame.stack.push(frame.locals[*addr as usize]),
+                Instruction::STORE { addr } => {
+                    frame.locals[*addr as usize] = frame.stack.pop().expect("stack underflow");
+                },
+                Instruction::ADD | Instruction::SUB | Instruction::MUL | Instruction::DIV | Instruction::REM
+                | Instruction::AND | Instruction::OR | Instruction::XOR | Instruction::SHL | Instruction::SHR => {
+                    let rhs = frame.stack.pop().expect("stack underflow");
+                    let lhs = frame.stack.pop().expect("stack underflow");
+                    let op = match instr {
+                        Instruction::ADD => ast::Opcode::Add,
+                        Instruction::SUB => ast::Opcode::Sub,
+                        Instruction::MUL => ast::Opcode::Mul,
+                        Instruction::DIV => ast::Opcode::Div,
+                        Instruction::REM => ast::Opcode::Rem,
+                        Instruction::AND => ast::Opcode::And,
+                        Instruction::OR => ast::Opcode::Or,
+                        Instruction::XOR => ast::Opcode::Xor,
+                        Instruction::SHL => ast::Opcode::Shl,
+                        _ => ast::Opcode::Shr,
+                    };
+                    let result = evaluate_binary(op, lhs, rhs, self.int_width)?;
+                    frame.stack.push(result);
+                },
+                Instruction::NEG => {
+                    let top = frame.stack.last_mut().expect("stack underflow");
+                    *top = evaluate_neg(*top, self.int_width)?;
+                },
+                Instruction::DUP => {
+                    let top = *frame.stack.last().expect("stack underflow");
+                    frame.stack.push(top);
+                },
+                Instruction::SWAP => {
+                    let len = frame.stack.len();
+                    assert!(len >= 2, "stack underflow");
+                    frame.stack.swap(len - 1, len - 2);
+                },
+                Instruction::PRINT | Instruction::PRINT_INLINE => {
+                    // the JVM print sequence needs an extra slot for System.out
+                    assert!(frame.stack.len() < frame.code.stack_limit as usize, "stack limit exceeded");
+                    (self.output)(Number::Int(frame.stack.pop().expect("stack underflow")), *instr == Instruction::PRINT);
+                },
+                // the print receiver is represented by a placeholder value
+                Instruction::PRINT_BEGIN => frame.stack.push(0),
+                Instruction::PRINT_END => {
+                    (self.output)(Number::Int(frame.stack.pop().expect("stack underflow")), true);
+                    frame.stack.pop().expect("stack underflow");
+                },
+                Instruction::PUSH_DOUBLE { bits } => frame.stack.push(*bits as i64),
+                Instruction::DLOAD { addr } => frame.stack.push(frame.locals[*addr as usize]),
+                Instruction::DSTORE { addr } => {
+                    frame.locals[*addr as usize] = frame.stack.pop().expect("stack underflow");
+                },
+                Instruction::DADD | Instruction::DSUB | Instruction::DMUL | Instruction::DDIV | Instruction::DREM => {
+                    let rhs = to_double(frame.stack.pop().expect("stack underflow"));
+                    let lhs = to_double(frame.stack.pop().expect("stack underflow"));
+                    let op = match instr {
+                        Instruction::DADD => ast::Opcode::Add,
+                        Instruction::DSUB => ast::Opcode::Sub,
+                        Instruction::DMUL => ast::Opcode::Mul,
+                        Instruction::DDIV => ast::Opcode::Div,
+                        _ => ast::Opcode::Rem,
+                    };
+                    frame.stack.push(match evaluate_double_binary::<i64>(op, lhs, rhs) {
+                        Number::Double(result) => result.to_bits() as i64,
+                        Number::Int(result) => result,
+                    });
+                },
+                Instruction::DCMP { cond } => {
+                    let rhs = to_double(frame.stack.pop().expect("stack underflow"));
+                    let lhs = to_double(frame.stack.pop().expect("stack underflow"));
+                    frame.stack.push(cond.holds(lhs, rhs) as i64);
+                },
+                Instruction::DNEG => {
+                    let top = frame.stack.last_mut().expect("stack underflow");
+                    *top = (-to_double(*top)).to_bits() as i64;
+                },
+                Instruction::I2D => {
+                    let top = frame.stack.last_mut().expect("stack underflow");
+                    *top = (*top as f64).to_bits() as i64;
+                },
+                Instruction::D2I => {
+                    let top = frame.stack.last_mut().expect("stack underflow");
+                    *top = i64::from_f64(to_double(*top), self.int_width);
+                },
+                Instruction::DPRINT | Instruction::DPRINT_INLINE => {
+                    assert!(frame.stack.len() < frame.code.stack_limit as usize, "stack limit exceeded");
+                    let val = to_double(frame.stack.pop().expect("stack underflow"));
+                    (self.output)(Number::Double(val), *instr == Instruction::DPRINT);
+                },
+                Instruction::DPRINT_END => {
+                    (self.output)(Number::Double(to_double(frame.stack.pop().expect("stack underflow"))), true);
+                    frame.stack.pop().expect("stack underflow");
+                },
+                Instruction::LABEL { label } => {
+                    frame.jump_to(*label);
+                },
+                Instruction::GOTO { label } => {
+                    frame.position = frame.jump_to(*label);
+                },
+                Instruction::IF { cond, label } => {
+                    let val = frame.stack.pop().expect("stack underflow");
+                    if cond.holds(val, 0) {
+                        frame.position = frame.jump_to(*label);
+                    }
+                },
+                Instruction::IF_CMP { cond, label } => {
+                    let rhs = frame.stack.pop().expect("stack underflow");
+                    let lhs = frame.stack.pop().expect("stack underflow");
+                    if cond.holds(lhs, rhs) {
+                        frame.position = frame.jump_to(*label);
+                    }
+                },
+                Instruction::CALL { function, args } => {
+                    let function = self.functions[function.as_str()];
+                    assert_eq!(function.params, *args, "wrong number of arguments");
+                    assert!(frame.stack.len() == *args as usize, "values left on the stack below the arguments");
+                    if callers.len() == MAX_CALL_DEPTH {
+                        return Err(RuntimeError::StackOverflow);
+                    }
+                    let args = std::mem::take(&mut frame.stack);
+                    callers.push(std::mem::replace(&mut frame, Frame::new(&function.code, args)));
+                },
+                Instruction::RETURN => {
+                    let result = frame.stack.pop().expect("stack underflow");
+                    assert!(frame.stack.is_empty(), "values left on the stack");
+                    frame = callers.pop().expect("return from the main program");
+                    frame.stack.push(result);
+                },
+                Instruction::READ => {
+                    assert!(frame.stack.is_empty(), "values left on the stack below the read value");
+                    frame.stack.push(self.int_width.wrap((self.input)()));
+                },
+            }
+            assert!(frame.stack.len() <= frame.code.stack_limit as usize, "stack limit exceeded");
+        }
+        assert!(frame.stack.is_empty(), "values left on the stack");
+        Ok(())
+    }
+}
+
+fn to_double(bits: i64) -> f64 {
+    f64::from_bits(bits as u64)
+}
@@ -0,0 +1,342 @@
+//! Differential tests of optimizations: random programs are executed by the interpreter (on the abstract
+//! syntax tree, without any optimizations) and by the stack machine after optimizations,
+//! and both executions have to print the same values and fail in the same way.
+use instant_parser::instant;
+use instant_compiler::bigint::BigInt;
+use instant_compiler::common::{IntWidth, Number, RuntimeError, MAX_CALL_DEPTH};
+use instant_compiler::interpreter::interpret;
+use instant_compiler::ir::lower;
+use instant_compiler::opt::{pass_names, OptLevel, OptOptions, PassManager};
+use instant_compiler::stack::compile_stack;
+use instant_compiler::{peephole, vm};
+
+const VARIABLES: &[&str] = &["a", "b", "c", "d"];
+const LOOP_COUNTERS: &[&str] = &["i", "j"];
+const CONSTANTS: &[i32] = &[0, 1, 2, 3, 7, 8, 16, 1024, 65536, 2147483647, -1, -2147483648];
+
+/// Linear congruential generator, so that tests are deterministic and don't need external crates
+struct Random {
+    state: u64,
+}
+
+impl Random {
+    fn next(&mut self, bound: usize) -> usize {
+        self.state = self.state.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+        ((self.state >> 33) % bound as u64) as usize
+    }
+}
+
+fn random_expr(random: &mut Random, defined: &[&str], depth: u32) -> String {
+    if depth == 0 || random.next(4) == 0 {
+        return match random.next(2) {
+            0 if !defined.is_empty() => String::from(defined[random.next(defined.len())]),
+            _ => CONSTANTS[random.next(CONSTANTS.len())].to_string(),
+        };
+    }
+    if random.next(8) == 0 {
+        return format!("-{}", random_expr(random, defined, depth - 1));
+    }
+    if random.next(8) == 0 {
+        let cond = random_expr(random, defined, depth - 1);
+        let then = random_expr(random, defined, depth - 1);
+        let otherwise = random_expr(random, defined, depth - 1);
+        return format!("(if {} then {} else {})", cond, then, otherwise);
+    }
+    let op = [
+        "+", "-", "*", "/", "%", "&", "|", "^", "<<", ">>", "==", "!=", "<", "<=", ">", ">="
+    ][random.next(16)];
+    let left = random_expr(random, defined, depth - 1);
+    // repeated operands exercise identities like x - x and common subexpressions
+    let right = match random.next(4) {
+        0 => left.clone(),
+        _ => random_expr(random, defined, depth - 1),
+    };
+    format!("({} {} {})", left, op, right)
+}
+
+/// random statements, including blocks and bounded loops nested at most `depth` times -
+/// variables first assigned inside them are not visible after them
+fn random_stmts(random: &mut Random, defined: &mut Vec<&'static str>, count: usize, depth: usize) -> Vec<String> {
+    let mut stmts: Vec<String> = vec![];
+    for _ in 0..count {
+        if depth > 0 && random.next(6) == 0 {
+            let body = random_stmts(random, &mut defined.clone(), 3, depth - 1);
+            stmts.push(format!("{{ {} }}", body.join("; ")));
+            continue;
+        }
+        if depth > 0 && random.next(6) == 0 {
+            // loop counters are not assigned by other statements, so that every loop ends
+            let counter = LOOP_COUNTERS[depth - 1];
+            stmts.push(format!("{} = 0", counter));
+            let mut body_defined = defined.clone();
+            body_defined.push(counter);
+            let body = random_stmts(random, &mut body_defined, 3, depth - 1);
+            let iterations = random.next(4);
+            stmts.push(format!("while ({} < {}) {{ {}; {} = {} + 1 }}", counter, iterations, body.join("; "), counter, counter));
+            continue;
+        }
+        let expr = random_expr(random, defined, 4);
+        if random.next(2) == 0 {
+            let var = VARIABLES[random.next(VARIABLES.len())];
+            if !defined.contains(&var) {
+                defined.push(var);
+            }
+            stmts.push(format!("{} = {}", var, expr));
+        } else {
+            stmts.push(expr);
+        }
+    }
+    stmts
+}
+
+fn random_program(seed: u64) -> String {
+    let mut random = Random { state: seed };
+    random_stmts(&mut random, &mut vec![], 8, LOOP_COUNTERS.len()).join(";\n")
+}
+
+/// Input of the executed programs, the same sequence of values for every execution
+fn test_input() -> impl FnMut() -> i64 {
+    let mut count = 0;
+    move || {
+        count += 1;
+        count * 7 % 11 - 5
+    }
+}
+
+fn assert_same_as_interpreter(source_code: &str, options: &OptOptions, int_width: IntWidth) {
+    let parsed_program = instant::ProgParser::new().parse(source_code).unwrap();
+    let mut expected: Vec<(Number<i64>, bool)> = vec![];
+    let expected_result = interpret(
+        &parsed_program, int_width, &mut test_input(), &mut |val, newline| expected.push((val, newline)),
+    );
+
+    let mut program = lower(&parsed_program, int_width).unwrap();
+    PassManager::new(options).unwrap().run(&mut program, &mut |_, _| {}, &mut |_, _| {});
+    let mut actual: Vec<(Number<i64>, bool)> = vec![];
+    let mut compiled_program = compile_stack(&program);
+    if options.stack_pass_enabled("peephole") {
+        peephole::optimize_program(&mut compiled_program);
+    }
+    let actual_result = vm::execute(&compiled_program, &mut test_input(), &mut |val, newline| actual.push((val, newline)));
+
+    assert_eq!(expected, actual, "invalid output with {:?} and {:?} of:\n{}", options, int_width, source_code);
+    assert_eq!(
+        expected_result.is_ok(), actual_result.is_ok(),
+        "invalid result with {:?} and {:?} of:\n{}", options, int_width, source_code
+    );
+}
+
+fn optimization_options() -> Vec<OptOptions> {
+    let mut all_options: Vec<OptOptions> = pass_names().into_iter()
+        .map(|name| {
+            let mut options = OptOptions::default();
+            options.enabled.insert(String::from(name));
+            options
+        })
+        .collect();
+    for level in &[OptLevel::O1, OptLevel::O2] {
+        all_options.push(OptOptions { level: *level, ..OptOptions::default() });
+    }
+    all_options
+}
+
+#[test]
+fn optimized_programs_behave_as_interpreted() {
+    let all_options = optimization_options();
+    for seed in 0..300 {
+        let source_code = random_program(seed);
+        for options in all_options.iter() {
+            assert_same_as_interpreter(&source_code, options, IntWidth::I32);
+        }
+    }
+}
+
+#[test]
+fn wide_programs_behave_as_interpreted() {
+    let all_options = optimization_options();
+    for seed in 0..100 {
+        let source_code = random_program(seed);
+        for options in all_options.iter() {
+            assert_same_as_interpreter(&source_code, options, IntWidth::I64);
+        }
+    }
+    let sources = &[
+        "a = 9223372036854775807; a + 1; a * 2; a * 1024; 4294967296 * a * 4294967296; 1 + (a + 1)",
+        "a = -9223372036854775808; a / -1; a % -1; a * -1; -a; a - 1; a >> 63; 1 << 63; 3 << 40",
+        "a = 2147483647; a + 1; a * a; b = a * 4; b / 3; b < 2147483647; if b > a then b else a",
+    ];
+    for source_code in sources {
+        for options in all_options.iter() {
+            assert_same_as_interpreter(source_code, options, IntWidth::I64);
+        }
+    }
+}
+
+/// The VM computes unbounded integers as i64, so it fails with `IntegerOverflow` when a result doesn't fit in it,
+/// after printing a prefix of the output of the interpreter computing on `BigInt` (or even where the result
+/// is not needed, as computations of unused values may be removed by optimizations)
+fn assert_unbounded_as_interpreted(source_code: &str, options: &OptOptions) {
+    let parsed_program = instant::ProgParser::new().parse(source_code).unwrap();
+    let mut expected: Vec<(String, bool)> = vec![];
+    let mut input = test_input();
+    let expected_result = interpret(
+        &parsed_program, IntWidth::Unbounded, &mut || BigInt::from(input()),
+        &mut |val, newline| expected.push((val.to_string(), newline)),
+    );
+
+    let mut program = lower(&parsed_program, IntWidth::Unbounded).unwrap();
+    PassManager::new(options).unwrap().run(&mut program, &mut |_, _| {}, &mut |_, _| {});
+    let mut actual: Vec<(String, bool)> = vec![];
+    let mut compiled_program = compile_stack(&program);
+    if options.stack_pass_enabled("peephole") {
+        peephole::optimize_program(&mut compiled_program);
+    }
+    let actual_result = vm::execute(
+        &compiled_program, &mut test_input(), &mut |val, newline| actual.push((val.to_string(), newline)),
+    );
+
+    if let Err(RuntimeError::IntegerOverflow) = actual_result {
+        assert!(expected.starts_with(&actual), "invalid output with {:?} of:\n{}", options, source_code);
+    } else {
+        assert_eq!(expected, actual, "invalid output with {:?} of:\n{}", options, source_code);
+        assert_eq!(
+            expected_result.is_ok(), actual_result.is_ok(), "invalid result with {:?} of:\n{}", options, source_code
+        );
+    }
+}
+
+#[test]
+fn unbounded_programs_behave_as_interpreted() {
+    let sources = &[
+        "a = 9223372036854775807; a - 1; -a; a / 3 * 3; b = a >> 62; b << 62; a & -a; (a ^ -1) | 1; a + 1; 5",
+        "a = 2147483647; a * a * 2; 1 << 62; -1 >> 100; 5 << -2; 1 << 4294967298; -(-a * a); a * a * a",
+        "a = -9223372036854775808; a + 1; a / 2; a >> 63; a / -1; 7",
+        "a = 18446744073709551616; a / 65536 / 65536; a >> 33; b = a - 1; b & 65535; c = a * a; read()",
+        "fn f(n) = if n < 2 then 1 else n * f(n - 1); i = 0; while (i < 25) { f(i); i = i + 1 }; f(22) / f(21)",
+        "a = read(); b = a * 4611686018427387904; b / a; c = b * -2; c / 4; d = 0 - c; 3",
+    ];
+    for source_code in sources {
+        for options in optimization_options().iter() {
+            assert_unbounded_as_interpreted(source_code, options);
+        }
+    }
+}
+
+#[test]
+fn unbounded_shifts_are_limited_as_in_interpreter() {
+    let fails = |source_code: &str| {
+        let program = instant::ProgParser::new().parse(source_code).unwrap();
+        let result = interpret(&program, IntWidth::Unbounded, &mut || BigInt::from(0), &mut |_, _| {});
+        matches!(result, Err(RuntimeError::ShiftOverflow))
+    };
+    assert!(fails("1 << 2147483647"));
+    assert!(fails("a = 3; a << 2147483646"));
+    assert!(fails("(1 >> -2147483648) & 5"));
+    assert!(!fails("0 << 2147483647; -1 >> 2147483647; 1 << -2147483648; 1 << 4294967295; 5"));
+    let source_code = "a = 3; a << 30; (1 >> -2147483648) & 5; 7";
+    for options in optimization_options().iter() {
+        assert_unbounded_as_interpreted(source_code, options);
+    }
+}
+
+#[test]
+fn algebraic_identities_behave_as_interpreted() {
+    let sources = &[
+        "a = 7; a + 0; 0 + a; a - 0; a * 1; 1 * a; a / 1; a * 0; a - a",
+        "a = 2147483647; a * 2; a * 1024; 65536 * a * 65536; 1 + (a + 1); 1 + (2 + (a + (3 + (a + 4))))",
+        "a = 0; (1 / a) * 0",
+        "a = 0; b = (a / a) - (a / a); 5",
+        "a = 0; b = if a then 1 / a else a; if a == 0 then 2 else 3 / a; c = if a then 1 else 1 / a; 4",
+    ];
+    for source_code in sources {
+        for options in optimization_options().iter() {
+            assert_same_as_interpreter(source_code, options, IntWidth::I32);
+        }
+    }
+}
+
+#[test]
+fn functions_behave_as_interpreted() {
+    let sources = &[
+        "fn f(x, y) = x * 2 - y; a = 3; f(a, 1) + f(f(a, a), a * a); f(1, 2) * (a + f(a + 1, 0))",
+        "fn fib(n) = if n < 2 then n else fib(n - 1) + fib(n - 2); i = 0; while (i < 12) { fib(i); i = i + 1 }",
+        "fn p(x) { x; return x + 1 }; a = p(1) + p(2) * p(3); a; p(a) - p(a)",
+        "fn d(x) = 10 / x; a = 1; d(a); b = if a then d(a - 1) else 0; 7",
+        "fn even(n) = if n == 0 then 1 else odd(n - 1); fn odd(n) = if n == 0 then 0 else even(n - 1); even(7); odd(7)",
+        "fn g(n) { s = 0; while (n) { s = s + n; n = n - 1; if s > 20 then 0 else 1 }; return s }; g(3); g(10); g(g(2))",
+    ];
+    for source_code in sources {
+        for options in optimization_options().iter() {
+            assert_same_as_interpreter(source_code, options, IntWidth::I32);
+        }
+    }
+}
+
+#[test]
+fn reads_behave_as_interpreted() {
+    let sources = &[
+        "read(); a = read(); b = read(); a - b; read() - read(); read() * 0; a + read()",
+        "a = read(); b = 0; c = read() + 0; b; c; a - a; read()",
+        "fn r(x) = read() * x; r(1) - r(2); a = if read() < 0 then read() else r(3); a; read()",
+        "a = 0; b = 1 / a + read(); read()",
+        "i = 0; while (read() != 0) { i = i + 1 }; i; read()",
+    ];
+    for source_code in sources {
+        for options in optimization_options().iter() {
+            assert_same_as_interpreter(source_code, options, IntWidth::I32);
+        }
+    }
+}
+
+#[test]
+fn print_statements_behave_as_interpreted() {
+    let sources = &[
+        "a = 3; print a, a + 1, a * a; a; print -a",
+        "fn f(x) { print x, x * 2; return x + 1 }; a = 1; print f(a), f(f(a)), a",
+        "a = 0; print 1, 2 / a, 3",
+        "a = 0; print 1 / a, 2; 3",
+        "pragma explicit_print; fn f(x) { x; print x; return 1 / x }; a = 2; f(a); a + f(a); print a, f(a) + 1; f(0); 5",
+        "pragma explicit_print; i = 0; while (i < 3) { i; print i, i * i; i = i + 1 }; print read(), read()",
+    ];
+    for source_code in sources {
+        for options in optimization_options().iter() {
+            assert_same_as_interpreter(source_code, options, IntWidth::I32);
+        }
+    }
+}
+
+#[test]
+fn doubles_behave_as_interpreted() {
+    let sources = &[
+        "a = 1.5; b = a * 2 - 0.25; print a, b, -b, a / 0.0, a % 0.4; int(b * 10) / 3; double(7) / 2",
+        "a = 2; b = a + 0.5; a < b; b >= 2.5; b == a; b != 2.5; c = if a > 1 then b else a; c; int(-c)",
+        "a = 0.1; i = 0; while (i < 5) { a = a * 3 - 0.05; i = i + 1 }; a; b = 3.0; b = 2; b + 0.5",
+        "fn f(x) = int(double(x) * 1.5); a = 10000000000.0 * 10000000000.0; f(3) + f(-3); int(a); int(-a); 1000000000.5 * 3",
+        "a = 2.5; a - (1.5 - (a - 0.5) / (a * 2)); (a + 1) * (a - (a - 0.5) % 2); print int(a), read() + a",
+        // the type of a conditional depends on the variables visible in its scope
+        "{ b = 0.5; if b > 1 then 1 else b }; { b = 3; if b > 2 then b else 1 }; fn f(b) = if b then b else 0; f(2)",
+        "i = 0; while (i < 3) { x = if i then (if i > 1 then 2.5 else i) else 0; print x, if x > 1 then 1 else x; i = i + 1 }",
+    ];
+    for source_code in sources {
+        for options in optimization_options().iter() {
+            assert_same_as_interpreter(source_code, options, IntWidth::I32);
+            assert_same_as_interpreter(source_code, options, IntWidth::I64);
+        }
+    }
+}
+
+#[test]
+fn call_depth_is_limited_as_in_interpreter() {
+    // down(n) makes n + 1 nested calls, so the second call fails right after reaching the limit
+    let source_code = format!(
+        "fn down(n) = if n then down(n - 1) + 1 else 0; down({}); down({}); 5", MAX_CALL_DEPTH - 1, MAX_CALL_DEPTH
+    );
+    let mut output: Vec<Number<i64>> = vec![];
+    let program = instant::ProgParser::new().parse(&source_code).unwrap();
+    let result = interpret(&program, IntWidth::I32, &mut test_input(), &mut |val, _| output.push(val));
+    assert_eq!(output, vec![Number::Int(MAX_CALL_DEPTH as i64 - 1)]);
+    assert!(matches!(result, Err(RuntimeError::StackOverflow)));
+    for options in optimization_options().iter() {
+        assert_same_as_interpreter(&source_code, options, IntWidth::I32);
+    }
+}
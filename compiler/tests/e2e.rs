@@ -0,0 +1,180 @@
+use std::{env, fs, path::Path, path::PathBuf, process::Command, process::Stdio};
+use instant_parser::{ast, parse_program};
+use instant_compiler::bigint::BigInt;
+use instant_compiler::common::{IntReader, IntWidth};
+use instant_compiler::interpreter::interpret;
+use instant_compiler::ir::lower;
+use instant_compiler::opt::{OptLevel, OptOptions, PassManager};
+use instant_compiler::stack::compile_stack;
+use instant_compiler::{cil, peephole, riscv, vm};
+
+fn e2e_test_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("../e2e_test")
+}
+
+fn golden_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/golden")
+}
+
+fn parse_test_program(test_name: &str) -> ast::Prog {
+    let source_path = e2e_test_dir().join(test_name).with_extension("ins");
+    let source_code = fs::read_to_string(source_path).unwrap();
+    parse_program(&source_code).unwrap()
+}
+
+/// width of integers selected by `--int-width=<bits>` in the optional file with extra compiler flags of the test
+fn test_int_width(test_name: &str) -> IntWidth {
+    let flags = fs::read_to_string(e2e_test_dir().join(test_name).with_extension("flags")).unwrap_or_default();
+    flags.split_whitespace()
+        .find_map(|flag| flag.strip_prefix("--int-width="))
+        .map(|bits| bits.parse().unwrap())
+        .unwrap_or_default()
+}
+
+fn e2e_test_names() -> Vec<String> {
+    let mut test_names: Vec<String> = fs::read_dir(e2e_test_dir()).unwrap()
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| path.extension().is_some_and(|ext| ext == "ins"))
+        .map(|path| String::from(path.file_stem().unwrap().to_str().unwrap()))
+        .collect();
+    test_names.sort();
+    test_names
+}
+
+/// compares output with the golden file, golden files can be updated by running tests with UPDATE_GOLDEN=1
+fn assert_golden(golden_file_name: &str, output: &[String]) {
+    let golden_path = golden_dir().join(golden_file_name);
+    let output = output.join("\n") + "\n";
+    if env::var_os("UPDATE_GOLDEN").is_some() {
+        fs::write(&golden_path, &output).unwrap();
+    }
+    let expected = fs::read_to_string(&golden_path).unwrap();
+    assert_eq!(expected, output, "output differs from {:?}", golden_path);
+}
+
+fn assert_stack_vm_output(level: OptLevel) {
+    let options = OptOptions { level, ..OptOptions::default() };
+    let pass_manager = PassManager::new(&options).unwrap();
+    for test_name in e2e_test_names() {
+        // values of the VM are i64, so results of unbounded operations which don't fit in it can't be computed
+        if test_int_width(&test_name) == IntWidth::Unbounded {
+            continue;
+        }
+        let mut program = lower(&parse_test_program(&test_name), test_int_width(&test_name)).unwrap();
+        pass_manager.run(&mut program, &mut |_, _| {}, &mut |_, _| {});
+        let mut compiled_program = compile_stack(&program);
+        if options.stack_pass_enabled("peephole") {
+            peephole::optimize_program(&mut compiled_program);
+        }
+        let input = fs::read(e2e_test_dir().join(&test_name).with_extension("input")).unwrap_or_default();
+        let mut reader = IntReader::new(input.as_slice());
+        let mut output = String::new();
+        vm::execute(&compiled_program, &mut || reader.read(), &mut |val, newline| {
+            output.push_str(&format!("{}{}", val, if newline { "\n" } else { " " }))
+        }).unwrap();
+
+        let expected_path = e2e_test_dir().join(&test_name).with_extension("output");
+        let expected = fs::read_to_string(expected_path).unwrap();
+        assert_eq!(expected, output, "invalid output of {} at {:?}", test_name, level);
+    }
+}
+
+#[test]
+fn stack_vm_output() {
+    assert_stack_vm_output(OptLevel::O0);
+}
+
+#[test]
+fn stack_vm_output_optimized() {
+    assert_stack_vm_output(OptLevel::O1);
+    assert_stack_vm_output(OptLevel::O2);
+}
+
+#[test]
+fn unbounded_interpreter_output() {
+    for test_name in e2e_test_names().iter().filter(|test_name| test_int_width(test_name) == IntWidth::Unbounded) {
+        let input = fs::read(e2e_test_dir().join(test_name).with_extension("input")).unwrap_or_default();
+        let mut reader = IntReader::new(input.as_slice());
+        let mut output = String::new();
+        interpret::<BigInt>(&parse_test_program(test_name), IntWidth::Unbounded, &mut || reader.read_big(), &mut |val, newline| {
+            output.push_str(&format!("{}{}", val, if newline { "\n" } else { " " }))
+        }).unwrap();
+
+        let expected_path = e2e_test_dir().join(test_name).with_extension("output");
+        let expected = fs::read_to_string(expected_path).unwrap();
+        assert_eq!(expected, output, "invalid output of {}", test_name);
+    }
+}
+
+#[test]
+fn cil_golden() {
+    for test_name in &["test02", "test07", "test13"] {
+        let compiled_program = compile_stack(&lower(&parse_test_program(test_name), IntWidth::I32).unwrap());
+        let cil_output = cil::translate(&compiled_program, test_name).unwrap();
+        assert_golden(&format!("{}.il", test_name), &cil_output);
+    }
+}
+
+#[test]
+fn riscv_golden() {
+    for test_name in &["test02", "test07", "test13", "test16"] {
+        let compiled_program = compile_stack(&lower(&parse_test_program(test_name), IntWidth::I32).unwrap());
+        let riscv_output = riscv::translate(&compiled_program).unwrap();
+        assert_golden(&format!("{}.s", test_name), &riscv_output);
+    }
+}
+
+/// true if the command can be executed (with the given argument, which shouldn't have any side effects)
+fn command_exists(command: &str, arg: &str) -> bool {
+    Command::new(command).arg(arg).stdout(Stdio::null()).stderr(Stdio::null()).status().is_ok()
+}
+
+/// Executes RV32IM programs assembled by the cross-compiler in `qemu-riscv32` user-mode emulator,
+/// the test is skipped if any of them is not installed (the compiler can be changed by setting `RISCV_COMPILER`).
+#[test]
+fn riscv_emulator_output() {
+    let riscv_compiler = env::var("RISCV_COMPILER").unwrap_or_else(|_| String::from("riscv64-unknown-elf-gcc"));
+    if !command_exists(&riscv_compiler, "--version") || !command_exists("qemu-riscv32", "--version") {
+        eprintln!("skipping RISC-V emulator tests: {} or qemu-riscv32 is not installed", riscv_compiler);
+        return;
+    }
+    let runtime_path = Path::new(env!("CARGO_MANIFEST_DIR")).join("../dist/runtime_rv32.s");
+    let output_dir = Path::new(env!("CARGO_TARGET_TMPDIR"));
+    for level in [OptLevel::O0, OptLevel::O2] {
+        let options = OptOptions { level, ..OptOptions::default() };
+        let pass_manager = PassManager::new(&options).unwrap();
+        for test_name in e2e_test_names() {
+            // RISC-V compiler supports only 32-bit integers
+            if test_int_width(&test_name) != IntWidth::I32 {
+                continue;
+            }
+            let mut program = lower(&parse_test_program(&test_name), IntWidth::I32).unwrap();
+            if program.uses_doubles() {
+                continue;
+            }
+            pass_manager.run(&mut program, &mut |_, _| {}, &mut |_, _| {});
+            let mut compiled_program = compile_stack(&program);
+            if options.stack_pass_enabled("peephole") {
+                peephole::optimize_program(&mut compiled_program);
+            }
+            let assembly_path = output_dir.join(&test_name).with_extension("s");
+            let binary_path = output_dir.join(&test_name).with_extension("rv32");
+            fs::write(&assembly_path, riscv::translate(&compiled_program).unwrap().join("\n") + "\n").unwrap();
+            let compilation_status = Command::new(&riscv_compiler)
+                .args(["-march=rv32im", "-mabi=ilp32", "-nostdlib", "-static", "-o"])
+                .arg(&binary_path).arg(&runtime_path).arg(&assembly_path)
+                .status().unwrap();
+            assert!(compilation_status.success(), "failed to compile {}", test_name);
+
+            let input_path = e2e_test_dir().join(&test_name).with_extension("input");
+            let input = match fs::File::open(input_path) {
+                Ok(file) => Stdio::from(file),
+                Err(_) => Stdio::null(),
+            };
+            let execution = Command::new("qemu-riscv32").arg(&binary_path).stdin(input).output().unwrap();
+            let expected_path = e2e_test_dir().join(&test_name).with_extension("output");
+            let expected = fs::read_to_string(expected_path).unwrap();
+            assert_eq!(expected, String::from_utf8_lossy(&execution.stdout), "invalid output of {} at {:?}", test_name, level);
+        }
+    }
+}
@@ -0,0 +1,16 @@
+.text
+.globl main
+main:
+    addi sp, sp, -16
+    sw ra, 12(sp)
+    li t0, 44
+    li t1, 2
+    sub t0, t0, t1
+    mv a0, t0
+    call printInt
+    li a0, 0
+    lw ra, 12(sp)
+    addi sp, sp, 16
+    ret
+.Ldiv_by_zero:
+    call instantDivByZero
@@ -0,0 +1,61 @@
+.text
+.globl main
+main:
+    addi sp, sp, -16
+    sw ra, 12(sp)
+    li t0, 2147483647
+    sw t0, 0(sp)
+    lw t1, 0(sp)
+    li t2, 1
+    add t1, t1, t2
+    mv a0, t1
+    call printInt
+    lw t1, 0(sp)
+    li t2, 2
+    mul t1, t1, t2
+    mv a0, t1
+    call printInt
+    li t1, 0
+    lw t2, 0(sp)
+    sub t1, t1, t2
+    li t2, 2
+    sub t1, t1, t2
+    mv a0, t1
+    call printInt
+    li t1, 65535
+    li t2, 65537
+    mul t1, t1, t2
+    mv a0, t1
+    call printInt
+    li t0, 0
+    li t1, 2
+    sub t0, t0, t1
+    li t1, 0
+    lw t2, 0(sp)
+    mul t1, t1, t2
+    add t0, t0, t1
+    li t1, 0
+    lw t2, 0(sp)
+    sub t1, t1, t2
+    li t2, 1
+    sub t1, t1, t2
+    mv t4, t0
+    mv t0, t1
+    mv t1, t4
+    beqz t1, .Ldiv_by_zero
+    div t0, t0, t1
+    li t1, 1
+    add t0, t0, t1
+    mv a0, t0
+    call printInt
+    li t1, 40000
+    li t2, 300
+    add t1, t1, t2
+    mv a0, t1
+    call printInt
+    li a0, 0
+    lw ra, 12(sp)
+    addi sp, sp, 16
+    ret
+.Ldiv_by_zero:
+    call instantDivByZero
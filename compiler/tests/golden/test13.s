@@ -0,0 +1,235 @@
+.text
+.globl main
+main:
+    addi sp, sp, -16
+    sw ra, 12(sp)
+    li t0, 3
+    sw t0, 0(sp)
+    li t0, -5
+    sw t0, 4(sp)
+    li t0, 0
+    sw t0, 8(sp)
+    lw t1, 0(sp)
+    li t2, 3
+    bne t1, t2, .L24
+    li t1, 1
+    j .L25
+.L24:
+    li t1, 0
+.L25:
+    mv a0, t1
+    call printInt
+    lw t1, 0(sp)
+    li t2, 3
+    beq t1, t2, .L26
+    li t1, 1
+    j .L27
+.L26:
+    li t1, 0
+.L27:
+    mv a0, t1
+    call printInt
+    lw t1, 4(sp)
+    lw t2, 0(sp)
+    bge t1, t2, .L28
+    li t1, 1
+    j .L29
+.L28:
+    li t1, 0
+.L29:
+    mv a0, t1
+    call printInt
+    lw t1, 0(sp)
+    lw t2, 4(sp)
+    blt t2, t1, .L30
+    li t1, 1
+    j .L31
+.L30:
+    li t1, 0
+.L31:
+    mv a0, t1
+    call printInt
+    lw t1, 4(sp)
+    li t2, -6
+    bge t2, t1, .L32
+    li t1, 1
+    j .L33
+.L32:
+    li t1, 0
+.L33:
+    mv a0, t1
+    call printInt
+    li t1, -5
+    lw t2, 4(sp)
+    blt t1, t2, .L34
+    li t1, 1
+    j .L35
+.L34:
+    li t1, 0
+.L35:
+    mv a0, t1
+    call printInt
+    lw t1, 0(sp)
+    lw t2, 4(sp)
+    bge t1, t2, .L1
+    lw t1, 0(sp)
+    j .L2
+.L1:
+    lw t1, 4(sp)
+.L2:
+    mv a0, t1
+    call printInt
+    lw t1, 4(sp)
+    beq t1, zero, .L4
+    li t1, 10
+    j .L5
+.L4:
+    li t1, 20
+.L5:
+    mv a0, t1
+    call printInt
+    lw t1, 8(sp)
+    beq t1, zero, .L7
+    li t1, 10
+    lw t2, 8(sp)
+    beqz t2, .Ldiv_by_zero
+    div t1, t1, t2
+    j .L8
+.L7:
+    li t1, 1
+    lw t2, 0(sp)
+    beqz t2, .Ldiv_by_zero
+    div t1, t1, t2
+.L8:
+    mv a0, t1
+    call printInt
+    lw t1, 0(sp)
+    li t2, 0
+    bge t2, t1, .L10
+    lw t1, 4(sp)
+    li t2, 0
+    bge t2, t1, .L13
+    li t1, 1
+    j .L14
+.L13:
+    li t1, 2
+.L14:
+    j .L11
+.L10:
+    li t1, 3
+.L11:
+    mv a0, t1
+    call printInt
+    lw t0, 0(sp)
+    lw t1, 4(sp)
+    bne t0, t1, .L16
+    lw t0, 0(sp)
+    lw t1, 4(sp)
+    mul t0, t0, t1
+    j .L17
+.L16:
+    lw t0, 0(sp)
+    lw t1, 4(sp)
+    sub t0, t0, t1
+    lw t1, 0(sp)
+    lw t2, 4(sp)
+    add t1, t1, t2
+    mul t0, t0, t1
+.L17:
+    lw t1, 0(sp)
+    lw t2, 4(sp)
+    add t1, t1, t2
+    mul t0, t0, t1
+    lw t1, 4(sp)
+    lw t2, 0(sp)
+    bge t1, t2, .L44
+    li t1, 1
+    j .L45
+.L44:
+    li t1, 0
+.L45:
+    lw t2, 0(sp)
+    lw t3, 4(sp)
+    bge t2, t3, .L46
+    li t2, 1
+    j .L47
+.L46:
+    li t2, 0
+.L47:
+    add t1, t1, t2
+    add t0, t0, t1
+    sw t0, 8(sp)
+    lw t1, 8(sp)
+    mv a0, t1
+    call printInt
+    lw t0, 8(sp)
+    li t1, 0
+    blt t0, t1, .L19
+    lw t0, 8(sp)
+    li t1, 7
+    beqz t1, .Ldiv_by_zero
+    rem t0, t0, t1
+    j .L20
+.L19:
+    lw t0, 8(sp)
+    neg t0, t0
+.L20:
+    sw t0, 8(sp)
+    li t1, 1
+    li t2, 1
+    bne t1, t2, .L50
+    li t1, 1
+    j .L51
+.L50:
+    li t1, 0
+.L51:
+    lw t2, 0(sp)
+    and t1, t1, t2
+    beq t1, zero, .L22
+    lw t1, 0(sp)
+    li t2, 1
+    add t1, t1, t2
+    lw t2, 0(sp)
+    mul t1, t1, t2
+    j .L23
+.L22:
+    li t1, 0
+.L23:
+    lw t2, 0(sp)
+    lw t3, 4(sp)
+    bge t2, t3, .L52
+    li t2, 1
+    j .L53
+.L52:
+    li t2, 0
+.L53:
+    li t3, 100
+    mul t2, t2, t3
+    sub t1, t1, t2
+    lw t2, 8(sp)
+    add t1, t1, t2
+    mv a0, t1
+    call printInt
+    li t1, 1
+    li t2, 2
+    bge t1, t2, .L54
+    li t1, 1
+    j .L55
+.L54:
+    li t1, 0
+.L55:
+    li t2, 1
+    bne t1, t2, .L56
+    li t1, 1
+    j .L57
+.L56:
+    li t1, 0
+.L57:
+    mv a0, t1
+    call printInt
+    li a0, 0
+    lw ra, 12(sp)
+    addi sp, sp, 16
+    ret
+.Ldiv_by_zero:
+    call instantDivByZero
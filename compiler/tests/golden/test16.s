@@ -0,0 +1,149 @@
+.text
+.globl main
+main:
+    addi sp, sp, -32
+    sw ra, 28(sp)
+    call readInt
+    mv t0, a0
+    sw t0, 0(sp)
+    lw t0, 0(sp)
+    sw t0, 0(sp)
+    call readInt
+    mv t0, a0
+    sw t0, 4(sp)
+    lw t0, 4(sp)
+    sw t0, 4(sp)
+    lw t0, 0(sp)
+    lw t1, 4(sp)
+    sub t0, t0, t1
+    mv a0, t0
+    call printInt
+    call readInt
+    mv t0, a0
+    sw t0, 4(sp)
+    lw t0, 4(sp)
+    sw t0, 4(sp)
+    call readInt
+    mv t0, a0
+    sw t0, 4(sp)
+    call readInt
+    mv t0, a0
+    sw t0, 0(sp)
+    lw t0, 4(sp)
+    lw t1, 0(sp)
+    sub t0, t0, t1
+    mv a0, t0
+    call printInt
+    call instant.twice
+    mv t0, a0
+    sw t0, 0(sp)
+    lw t1, 0(sp)
+    mv a0, t1
+    call printInt
+    li t0, 0
+    sw t0, -16(sp)
+    call instant.read_if
+    mv t0, a0
+    sw t0, 0(sp)
+    lw t1, 0(sp)
+    mv a0, t1
+    call printInt
+    li t0, 1
+    sw t0, -16(sp)
+    call instant.read_if
+    mv t0, a0
+    sw t0, 0(sp)
+    lw t1, 0(sp)
+    mv a0, t1
+    call printInt
+    li t0, 0
+    sw t0, 0(sp)
+    li t0, 0
+    sw t0, 4(sp)
+    call readInt
+    mv t0, a0
+    sw t0, 8(sp)
+    lw t0, 8(sp)
+    sw t0, 8(sp)
+.L0:
+    lw t0, 8(sp)
+    beq t0, zero, .L2
+    lw t0, 0(sp)
+    lw t1, 8(sp)
+    add t0, t0, t1
+    sw t0, 0(sp)
+    lw t0, 4(sp)
+    li t1, 1
+    add t0, t0, t1
+    sw t0, 4(sp)
+    call readInt
+    mv t0, a0
+    sw t0, 12(sp)
+    lw t0, 12(sp)
+    sw t0, 8(sp)
+    j .L0
+.L2:
+    lw t1, 0(sp)
+    mv a0, t1
+    call printInt
+    lw t1, 4(sp)
+    mv a0, t1
+    call printInt
+    call readInt
+    mv t0, a0
+    sw t0, 4(sp)
+    lw t0, 4(sp)
+    li t1, 1
+    add t0, t0, t1
+    mv a0, t0
+    call printInt
+    li a0, 0
+    lw ra, 28(sp)
+    addi sp, sp, 32
+    ret
+instant.twice:
+    addi sp, sp, -16
+    sw ra, 12(sp)
+    call readInt
+    mv t0, a0
+    sw t0, 0(sp)
+    lw t0, 0(sp)
+    li t1, 2
+    mul t0, t0, t1
+    mv a0, t0
+    lw ra, 12(sp)
+    addi sp, sp, 16
+    ret
+    li t0, 0
+    mv a0, t0
+    lw ra, 12(sp)
+    addi sp, sp, 16
+    ret
+instant.read_if:
+    addi sp, sp, -16
+    sw ra, 12(sp)
+    lw t0, 0(sp)
+    beq t0, zero, .Lread_if.1
+    call readInt
+    mv t0, a0
+    sw t0, 0(sp)
+    lw t0, 0(sp)
+    j .Lread_if.2
+.Lread_if.1:
+    li t0, 0
+    li t1, 1
+    sub t0, t0, t1
+.Lread_if.2:
+    sw t0, 0(sp)
+    lw t0, 0(sp)
+    mv a0, t0
+    lw ra, 12(sp)
+    addi sp, sp, 16
+    ret
+    li t0, 0
+    mv a0, t0
+    lw ra, 12(sp)
+    addi sp, sp, 16
+    ret
+.Ldiv_by_zero:
+    call instantDivByZero
@@ -0,0 +1,21 @@
+use instant_compiler::common::IntWidth;
+use instant_compiler::ir::lower;
+use instant_compiler::jasmin::translate;
+use instant_compiler::stack::compile_stack;
+use instant_parser::parse_program;
+
+/// instructions of the Jasmin program which push integer constants
+fn pushed_constants(source: &str) -> Vec<String> {
+    let program = lower(&parse_program(source).unwrap(), IntWidth::I32).unwrap();
+    translate(&compile_stack(&program), &String::from("Constants")).into_iter()
+        .filter(|line| ["iconst", "bipush", "sipush", "ldc"].iter().any(|prefix| line.starts_with(prefix)))
+        .collect()
+}
+
+#[test]
+fn constants_are_encoded_by_their_range() {
+    let constants = pushed_constants("5; 6; 127; 128; 32767; 32768; 40000; 2147483647");
+    assert_eq!(constants, vec![
+        "iconst_5", "bipush 6", "bipush 127", "sipush 128", "sipush 32767", "ldc 32768", "ldc 40000", "ldc 2147483647",
+    ]);
+}
@@ -1,6 +1,11 @@
 @dnl = internal constant [4 x i8] c"%d\0A\00"
+@dsp = internal constant [4 x i8] c"%d \00"
 @fnl = internal constant [6 x i8] c"%.1f\0A\00"
+@fsp = internal constant [6 x i8] c"%.1f \00"
 @d   = internal constant [3 x i8] c"%d\00"	
+@lldnl = internal constant [6 x i8] c"%lld\0A\00"
+@lldsp = internal constant [6 x i8] c"%lld \00"
+@lld = internal constant [5 x i8] c"%lld\00"
 @lf  = internal constant [4 x i8] c"%lf\00"	
 
 declare i32 @printf(i8*, ...) 
@@ -13,9 +18,45 @@ define void @printInt(i32 %x) {
        ret void
 }
 
+define void @printIntInline(i32 %x) {
+       %t0 = getelementptr [4 x i8], [4 x i8]* @dsp, i32 0, i32 0
+       call i32 (i8*, ...) @printf(i8* %t0, i32 %x) 
+       ret void
+}
+
+define void @printLong(i64 %x) {
+       %t0 = getelementptr [6 x i8], [6 x i8]* @lldnl, i32 0, i32 0
+       call i32 (i8*, ...) @printf(i8* %t0, i64 %x) 
+       ret void
+}
+
+define void @printLongInline(i64 %x) {
+       %t0 = getelementptr [6 x i8], [6 x i8]* @lldsp, i32 0, i32 0
+       call i32 (i8*, ...) @printf(i8* %t0, i64 %x) 
+       ret void
+}
+
+; printf prints the sign of NaN, so it is cleared to print NaN always as "nan"
+define double @clearNaNSign(double %x) {
+entry:	%nan = fcmp uno double %x, 0.0
+	%abs = call double @llvm.fabs.f64(double %x)
+	%res = select i1 %nan, double %abs, double %x
+	ret double %res
+}
+
+declare double @llvm.fabs.f64(double)
+
 define void @printDouble(double %x) {
 entry: %t0 = getelementptr [6 x i8], [6 x i8]* @fnl, i32 0, i32 0
-	call i32 (i8*, ...) @printf(i8* %t0, double %x) 
+	%t1 = call double @clearNaNSign(double %x)
+	call i32 (i8*, ...) @printf(i8* %t0, double %t1) 
+	ret void
+}
+
+define void @printDoubleInline(double %x) {
+entry: %t0 = getelementptr [6 x i8], [6 x i8]* @fsp, i32 0, i32 0
+	%t1 = call double @clearNaNSign(double %x)
+	call i32 (i8*, ...) @printf(i8* %t0, double %t1) 
 	ret void
 }
 
@@ -26,12 +67,22 @@ entry:  call i32 @puts(i8* %s)
 
 define i32 @readInt() {
 entry:	%res = alloca i32
+	store i32 0, i32* %res
         %t1 = getelementptr [3 x i8], [3 x i8]* @d, i32 0, i32 0
 	call i32 (i8*, ...) @scanf(i8* %t1, i32* %res)
 	%t2 = load i32, i32* %res
 	ret i32 %t2
 }
 
+define i64 @readLong() {
+entry:	%res = alloca i64
+	store i64 0, i64* %res
+        %t1 = getelementptr [5 x i8], [5 x i8]* @lld, i32 0, i32 0
+	call i32 (i8*, ...) @scanf(i8* %t1, i64* %res)
+	%t2 = load i64, i64* %res
+	ret i64 %t2
+}
+
 define double @readDouble() {
 entry:	%res = alloca double
         %t1 = getelementptr [4 x i8],[4 x i8]* @lf, i32 0, i32 0
@@ -40,3 +91,605 @@ entry:	%res = alloca double
 	ret double %t2
 }
 
+
+; Division and remainder round towards zero, division by zero prints an exception and exits with code 1,
+; the minimal value divided by -1 is the minimal value (and the remainder is 0), as in JVM
+
+@divzero = internal constant [29 x i8] c"Exception: division by zero\0A\00"
+
+define void @divisionByZero() {
+entry:	%err = load i8*, i8** @stderr
+	%msg = getelementptr [29 x i8], [29 x i8]* @divzero, i32 0, i32 0
+	call i32 @fputs(i8* %msg, i8* %err)
+	call void @exit(i32 1)
+	unreachable
+}
+
+define i32 @divInt(i32 %a, i32 %b) {
+entry:	%zero = icmp eq i32 %b, 0
+	br i1 %zero, label %error, label %check
+check:	%minus = icmp eq i32 %b, -1
+	br i1 %minus, label %negate, label %divide
+negate:	%neg = sub i32 0, %a
+	ret i32 %neg
+divide:	%res = sdiv i32 %a, %b
+	ret i32 %res
+error:	call void @divisionByZero()
+	unreachable
+}
+
+define i32 @remInt(i32 %a, i32 %b) {
+entry:	%zero = icmp eq i32 %b, 0
+	br i1 %zero, label %error, label %check
+check:	%minus = icmp eq i32 %b, -1
+	br i1 %minus, label %done, label %divide
+done:	ret i32 0
+divide:	%res = srem i32 %a, %b
+	ret i32 %res
+error:	call void @divisionByZero()
+	unreachable
+}
+
+define i64 @divLong(i64 %a, i64 %b) {
+entry:	%zero = icmp eq i64 %b, 0
+	br i1 %zero, label %error, label %check
+check:	%minus = icmp eq i64 %b, -1
+	br i1 %minus, label %negate, label %divide
+negate:	%neg = sub i64 0, %a
+	ret i64 %neg
+divide:	%res = sdiv i64 %a, %b
+	ret i64 %res
+error:	call void @divisionByZero()
+	unreachable
+}
+
+define i64 @remLong(i64 %a, i64 %b) {
+entry:	%zero = icmp eq i64 %b, 0
+	br i1 %zero, label %error, label %check
+check:	%minus = icmp eq i64 %b, -1
+	br i1 %minus, label %done, label %divide
+done:	ret i64 0
+divide:	%res = srem i64 %a, %b
+	ret i64 %res
+error:	call void @divisionByZero()
+	unreachable
+}
+
+; Unbounded integers are pointers to arrays of i32: the number of limbs is followed by the limbs
+; (from the least significant one) of the value in two's complement, results are never freed
+
+@bigdivzero = internal constant [18 x i8] c"division by zero\0A\00"
+@bigshift = internal constant [20 x i8] c"shift out of range\0A\00"
+@bigu = internal constant [3 x i8] c"%u\00"
+@bigu9 = internal constant [5 x i8] c"%09u\00"
+
+@stdin = external global i8*
+@stderr = external global i8*
+
+declare i8* @calloc(i64, i64)
+declare i32 @getchar()
+declare i32 @ungetc(i32, i8*)
+declare i32 @putchar(i32)
+declare i32 @fputs(i8*, i8*)
+declare void @exit(i32)
+declare i32 @llvm.ctlz.i32(i32, i1)
+
+define internal i32* @bigAlloc(i32 %n) {
+entry:	%n1 = add i32 %n, 1
+	%size = zext i32 %n1 to i64
+	%mem = call i8* @calloc(i64 %size, i64 4)
+	%a = bitcast i8* %mem to i32*
+	store i32 %n, i32* %a
+	ret i32* %a
+}
+
+; limbs above the length are the sign extension of the value, and limbs below 0 are zeros
+define internal i32 @bigLimb(i32* %a, i32 %i) {
+entry:	%n = load i32, i32* %a
+	%below = icmp slt i32 %i, 0
+	br i1 %below, label %zero, label %check
+zero:	ret i32 0
+check:	%inside = icmp slt i32 %i, %n
+	br i1 %inside, label %limb, label %sign
+limb:	%i1 = add i32 %i, 1
+	%p = getelementptr i32, i32* %a, i32 %i1
+	%x = load i32, i32* %p
+	ret i32 %x
+sign:	%tp = getelementptr i32, i32* %a, i32 %n
+	%top = load i32, i32* %tp
+	%s = ashr i32 %top, 31
+	ret i32 %s
+}
+
+define internal void @bigSetLimb(i32* %a, i32 %i, i32 %x) {
+entry:	%i1 = add i32 %i, 1
+	%p = getelementptr i32, i32* %a, i32 %i1
+	store i32 %x, i32* %p
+	ret void
+}
+
+define internal i32 @bigMax(i32 %x, i32 %y) {
+entry:	%c = icmp sgt i32 %x, %y
+	%m = select i1 %c, i32 %x, i32 %y
+	ret i32 %m
+}
+
+; limbs joined into a 64-bit value
+define internal i64 @bigPair(i32 %hi, i32 %lo) {
+entry:	%h = zext i32 %hi to i64
+	%l = zext i32 %lo to i64
+	%hs = shl i64 %h, 32
+	%p = or i64 %hs, %l
+	ret i64 %p
+}
+
+; drops the top limbs which are the sign extension of the limbs below them
+define internal i32* @bigTrim(i32* %a) {
+entry:	br label %loop
+loop:	%n = load i32, i32* %a
+	%single = icmp eq i32 %n, 1
+	br i1 %single, label %done, label %check
+check:	%ti = sub i32 %n, 1
+	%top = call i32 @bigLimb(i32* %a, i32 %ti)
+	%bi = sub i32 %n, 2
+	%below = call i32 @bigLimb(i32* %a, i32 %bi)
+	%ext = ashr i32 %below, 31
+	%redundant = icmp eq i32 %top, %ext
+	br i1 %redundant, label %shrink, label %done
+shrink:	store i32 %ti, i32* %a
+	br label %loop
+done:	ret i32* %a
+}
+
+define i32* @bigFromLong(i64 %x) {
+entry:	%a = call i32* @bigAlloc(i32 2)
+	%lo = trunc i64 %x to i32
+	%xs = lshr i64 %x, 32
+	%hi = trunc i64 %xs to i32
+	call void @bigSetLimb(i32* %a, i32 0, i32 %lo)
+	call void @bigSetLimb(i32* %a, i32 1, i32 %hi)
+	%t = call i32* @bigTrim(i32* %a)
+	ret i32* %t
+}
+
+; a + (b xor mask) + carry, with one limb more than the longer operand, so that the sum fits
+define internal i32* @bigAddWith(i32* %a, i32* %b, i32 %mask, i64 %carry) {
+entry:	%na = load i32, i32* %a
+	%nb = load i32, i32* %b
+	%nm = call i32 @bigMax(i32 %na, i32 %nb)
+	%n = add i32 %nm, 1
+	%r = call i32* @bigAlloc(i32 %n)
+	br label %loop
+loop:	%i = phi i32 [0, %entry], [%i.next, %body]
+	%c = phi i64 [%carry, %entry], [%c.next, %body]
+	%cont = icmp slt i32 %i, %n
+	br i1 %cont, label %body, label %done
+body:	%x = call i32 @bigLimb(i32* %a, i32 %i)
+	%y0 = call i32 @bigLimb(i32* %b, i32 %i)
+	%y = xor i32 %y0, %mask
+	%x64 = zext i32 %x to i64
+	%y64 = zext i32 %y to i64
+	%s0 = add i64 %x64, %y64
+	%s = add i64 %s0, %c
+	%limb = trunc i64 %s to i32
+	call void @bigSetLimb(i32* %r, i32 %i, i32 %limb)
+	%c.next = lshr i64 %s, 32
+	%i.next = add i32 %i, 1
+	br label %loop
+done:	%t = call i32* @bigTrim(i32* %r)
+	ret i32* %t
+}
+
+define i32* @bigAdd(i32* %a, i32* %b) {
+entry:	%r = call i32* @bigAddWith(i32* %a, i32* %b, i32 0, i64 0)
+	ret i32* %r
+}
+
+define i32* @bigSub(i32* %a, i32* %b) {
+entry:	%r = call i32* @bigAddWith(i32* %a, i32* %b, i32 -1, i64 1)
+	ret i32* %r
+}
+
+define i32* @bigNeg(i32* %a) {
+entry:	%z = call i32* @bigFromLong(i64 0)
+	%r = call i32* @bigAddWith(i32* %z, i32* %a, i32 -1, i64 1)
+	ret i32* %r
+}
+
+; -1, 0 or 1
+define i32 @bigSign(i32* %a) {
+entry:	%n = load i32, i32* %a
+	%ti = sub i32 %n, 1
+	%top = call i32 @bigLimb(i32* %a, i32 %ti)
+	%neg = icmp slt i32 %top, 0
+	br i1 %neg, label %negative, label %loop
+negative:	ret i32 -1
+loop:	%i = phi i32 [0, %entry], [%i.next, %next]
+	%cont = icmp slt i32 %i, %n
+	br i1 %cont, label %body, label %zero
+body:	%x = call i32 @bigLimb(i32* %a, i32 %i)
+	%nonzero = icmp ne i32 %x, 0
+	br i1 %nonzero, label %positive, label %next
+next:	%i.next = add i32 %i, 1
+	br label %loop
+positive:	ret i32 1
+zero:	ret i32 0
+}
+
+define i32 @bigCompare(i32* %a, i32* %b) {
+entry:	%d = call i32* @bigSub(i32* %a, i32* %b)
+	%s = call i32 @bigSign(i32* %d)
+	ret i32 %s
+}
+
+define internal i32* @bigAbs(i32* %a) {
+entry:	%s = call i32 @bigSign(i32* %a)
+	%neg = icmp slt i32 %s, 0
+	br i1 %neg, label %negate, label %done
+negate:	%r = call i32* @bigNeg(i32* %a)
+	ret i32* %r
+done:	ret i32* %a
+}
+
+; op is 0 for and, 1 for or and 2 for xor
+define internal i32* @bigBitwise(i32* %a, i32* %b, i32 %op) {
+entry:	%na = load i32, i32* %a
+	%nb = load i32, i32* %b
+	%n = call i32 @bigMax(i32 %na, i32 %nb)
+	%r = call i32* @bigAlloc(i32 %n)
+	%isand = icmp eq i32 %op, 0
+	%isor = icmp eq i32 %op, 1
+	br label %loop
+loop:	%i = phi i32 [0, %entry], [%i.next, %body]
+	%cont = icmp slt i32 %i, %n
+	br i1 %cont, label %body, label %done
+body:	%x = call i32 @bigLimb(i32* %a, i32 %i)
+	%y = call i32 @bigLimb(i32* %b, i32 %i)
+	%and = and i32 %x, %y
+	%or = or i32 %x, %y
+	%xor = xor i32 %x, %y
+	%v0 = select i1 %isor, i32 %or, i32 %xor
+	%v = select i1 %isand, i32 %and, i32 %v0
+	call void @bigSetLimb(i32* %r, i32 %i, i32 %v)
+	%i.next = add i32 %i, 1
+	br label %loop
+done:	%t = call i32* @bigTrim(i32* %r)
+	ret i32* %t
+}
+
+define i32* @bigAnd(i32* %a, i32* %b) {
+entry:	%r = call i32* @bigBitwise(i32* %a, i32* %b, i32 0)
+	ret i32* %r
+}
+
+define i32* @bigOr(i32* %a, i32* %b) {
+entry:	%r = call i32* @bigBitwise(i32* %a, i32* %b, i32 1)
+	ret i32* %r
+}
+
+define i32* @bigXor(i32* %a, i32* %b) {
+entry:	%r = call i32* @bigBitwise(i32* %a, i32* %b, i32 2)
+	ret i32* %r
+}
+
+; number of bits of the absolute value, without leading zeros
+define internal i64 @bigBitLength(i32* %a) {
+entry:	%x = call i32* @bigAbs(i32* %a)
+	%n = load i32, i32* %x
+	br label %loop
+loop:	%i = phi i32 [%n, %entry], [%i.prev, %body]
+	%cont = icmp sgt i32 %i, 0
+	br i1 %cont, label %body, label %zero
+body:	%i.prev = sub i32 %i, 1
+	%limb = call i32 @bigLimb(i32* %x, i32 %i.prev)
+	%empty = icmp eq i32 %limb, 0
+	br i1 %empty, label %loop, label %found
+found:	%lz = call i32 @llvm.ctlz.i32(i32 %limb, i1 true)
+	%top = sub i32 32, %lz
+	%i64 = zext i32 %i.prev to i64
+	%below = mul i64 %i64, 32
+	%top64 = zext i32 %top to i64
+	%bits = add i64 %below, %top64
+	ret i64 %bits
+zero:	ret i64 0
+}
+
+; shift to the left by k bits, or to the right by -k bits if k is negative (rounding towards negative infinity),
+; results of 2^31 bits or more are not supported, as in BigInteger
+define internal i32* @bigShift(i32* %a, i64 %k) {
+entry:	%n = load i32, i32* %a
+	%left = icmp sge i64 %k, 0
+	br i1 %left, label %check, label %shr
+check:	%bits = call i64 @bigBitLength(i32* %a)
+	%total = add i64 %bits, %k
+	%large = icmp sge i64 %total, 2147483648
+	%nonzero = icmp ne i64 %bits, 0
+	%over = and i1 %large, %nonzero
+	br i1 %over, label %error, label %shl
+error:	%err = load i8*, i8** @stderr
+	%msg = getelementptr [20 x i8], [20 x i8]* @bigshift, i32 0, i32 0
+	call i32 @fputs(i8* %msg, i8* %err)
+	call void @exit(i32 1)
+	unreachable
+shl:	%lq64 = lshr i64 %k, 5
+	%lq = trunc i64 %lq64 to i32
+	%lr = and i64 %k, 31
+	%ln0 = add i32 %n, %lq
+	%ln = add i32 %ln0, 1
+	%lres = call i32* @bigAlloc(i32 %ln)
+	br label %shl.loop
+shl.loop:	%li = phi i32 [0, %shl], [%li.next, %shl.body]
+	%lcont = icmp slt i32 %li, %ln
+	br i1 %lcont, label %shl.body, label %shl.done
+shl.body:	%lj = sub i32 %li, %lq
+	%lhi = call i32 @bigLimb(i32* %a, i32 %lj)
+	%lj1 = sub i32 %lj, 1
+	%llo = call i32 @bigLimb(i32* %a, i32 %lj1)
+	%lpair = call i64 @bigPair(i32 %lhi, i32 %llo)
+	%lshifted = shl i64 %lpair, %lr
+	%ltop = lshr i64 %lshifted, 32
+	%llimb = trunc i64 %ltop to i32
+	call void @bigSetLimb(i32* %lres, i32 %li, i32 %llimb)
+	%li.next = add i32 %li, 1
+	br label %shl.loop
+shl.done:	%lt = call i32* @bigTrim(i32* %lres)
+	ret i32* %lt
+shr:	%s = sub i64 0, %k
+	%rq64 = lshr i64 %s, 5
+	%rq = trunc i64 %rq64 to i32
+	%rr = and i64 %s, 31
+	%rn0 = sub i32 %n, %rq
+	%rn = call i32 @bigMax(i32 %rn0, i32 1)
+	%rres = call i32* @bigAlloc(i32 %rn)
+	br label %shr.loop
+shr.loop:	%ri = phi i32 [0, %shr], [%ri.next, %shr.body]
+	%rcont = icmp slt i32 %ri, %rn
+	br i1 %rcont, label %shr.body, label %shr.done
+shr.body:	%rj = add i32 %ri, %rq
+	%rlo = call i32 @bigLimb(i32* %a, i32 %rj)
+	%rj1 = add i32 %rj, 1
+	%rhi = call i32 @bigLimb(i32* %a, i32 %rj1)
+	%rpair = call i64 @bigPair(i32 %rhi, i32 %rlo)
+	%rshifted = ashr i64 %rpair, %rr
+	%rlimb = trunc i64 %rshifted to i32
+	call void @bigSetLimb(i32* %rres, i32 %ri, i32 %rlimb)
+	%ri.next = add i32 %ri, 1
+	br label %shr.loop
+shr.done:	%rt = call i32* @bigTrim(i32* %rres)
+	ret i32* %rt
+}
+
+; the amount of shifts is the lowest 32 bits of the value, negative amounts shift in the other direction
+define i32* @bigShl(i32* %a, i32* %b) {
+entry:	%x = call i32 @bigLimb(i32* %b, i32 0)
+	%k = sext i32 %x to i64
+	%r = call i32* @bigShift(i32* %a, i64 %k)
+	ret i32* %r
+}
+
+define i32* @bigShr(i32* %a, i32* %b) {
+entry:	%x = call i32 @bigLimb(i32* %b, i32 0)
+	%x64 = sext i32 %x to i64
+	%k = sub i64 0, %x64
+	%r = call i32* @bigShift(i32* %a, i64 %k)
+	ret i32* %r
+}
+
+; magnitudes are multiplied limb by limb, the product of n and m limbs fits in n + m limbs
+define i32* @bigMul(i32* %a, i32* %b) {
+entry:	%x = call i32* @bigAbs(i32* %a)
+	%y = call i32* @bigAbs(i32* %b)
+	%nx = load i32, i32* %x
+	%ny = load i32, i32* %y
+	%n = add i32 %nx, %ny
+	%r = call i32* @bigAlloc(i32 %n)
+	br label %outer
+outer:	%i = phi i32 [0, %entry], [%i.next, %outer.done]
+	%ocont = icmp slt i32 %i, %nx
+	br i1 %ocont, label %outer.body, label %sign
+outer.body:	%xi = call i32 @bigLimb(i32* %x, i32 %i)
+	%xi64 = zext i32 %xi to i64
+	br label %inner
+inner:	%j = phi i32 [0, %outer.body], [%j.next, %inner.body]
+	%c = phi i64 [0, %outer.body], [%c.next, %inner.body]
+	%icont = icmp slt i32 %j, %ny
+	br i1 %icont, label %inner.body, label %outer.done
+inner.body:	%k = add i32 %i, %j
+	%rk = call i32 @bigLimb(i32* %r, i32 %k)
+	%rk64 = zext i32 %rk to i64
+	%yj = call i32 @bigLimb(i32* %y, i32 %j)
+	%yj64 = zext i32 %yj to i64
+	%p = mul i64 %xi64, %yj64
+	%t0 = add i64 %p, %rk64
+	%t = add i64 %t0, %c
+	%limb = trunc i64 %t to i32
+	call void @bigSetLimb(i32* %r, i32 %k, i32 %limb)
+	%c.next = lshr i64 %t, 32
+	%j.next = add i32 %j, 1
+	br label %inner
+outer.done:	%ck = add i32 %i, %ny
+	%climb = trunc i64 %c to i32
+	call void @bigSetLimb(i32* %r, i32 %ck, i32 %climb)
+	%i.next = add i32 %i, 1
+	br label %outer
+sign:	%sa = call i32 @bigSign(i32* %a)
+	%sb = call i32 @bigSign(i32* %b)
+	%nega = icmp slt i32 %sa, 0
+	%negb = icmp slt i32 %sb, 0
+	%differ = xor i1 %nega, %negb
+	%tr = call i32* @bigTrim(i32* %r)
+	br i1 %differ, label %negate, label %done
+negate:	%nr = call i32* @bigNeg(i32* %tr)
+	ret i32* %nr
+done:	ret i32* %tr
+}
+
+; magnitudes are divided bit by bit, the quotient is truncated and the remainder has the sign of the dividend
+define internal i32* @bigDivide(i32* %a, i32* %b, i1 %remainder) {
+entry:	%sb = call i32 @bigSign(i32* %b)
+	%zero = icmp eq i32 %sb, 0
+	br i1 %zero, label %error, label %divide
+error:	%err = load i8*, i8** @stderr
+	%msg = getelementptr [18 x i8], [18 x i8]* @bigdivzero, i32 0, i32 0
+	call i32 @fputs(i8* %msg, i8* %err)
+	call void @exit(i32 1)
+	unreachable
+divide:	%x = call i32* @bigAbs(i32* %a)
+	%y = call i32* @bigAbs(i32* %b)
+	%n = load i32, i32* %x
+	%q = call i32* @bigAlloc(i32 %n)
+	%r0 = call i32* @bigFromLong(i64 0)
+	%bits = mul i32 %n, 32
+	br label %loop
+loop:	%bit = phi i32 [%bits, %divide], [%bit.i, %next]
+	%r = phi i32* [%r0, %divide], [%r.next, %next]
+	%cont = icmp sgt i32 %bit, 0
+	br i1 %cont, label %body, label %done
+body:	%bit.i = sub i32 %bit, 1
+	%li = lshr i32 %bit.i, 5
+	%bi = and i32 %bit.i, 31
+	%limb = call i32 @bigLimb(i32* %x, i32 %li)
+	%v0 = lshr i32 %limb, %bi
+	%v = and i32 %v0, 1
+	%v64 = zext i32 %v to i64
+	%vb = call i32* @bigFromLong(i64 %v64)
+	%rs = call i32* @bigShift(i32* %r, i64 1)
+	%r1 = call i32* @bigOr(i32* %rs, i32* %vb)
+	%c = call i32 @bigCompare(i32* %r1, i32* %y)
+	%ge = icmp sge i32 %c, 0
+	br i1 %ge, label %subtract, label %next
+subtract:	%r2 = call i32* @bigSub(i32* %r1, i32* %y)
+	%ql = call i32 @bigLimb(i32* %q, i32 %li)
+	%qb = shl i32 1, %bi
+	%qn = or i32 %ql, %qb
+	call void @bigSetLimb(i32* %q, i32 %li, i32 %qn)
+	br label %next
+next:	%r.next = phi i32* [%r1, %body], [%r2, %subtract]
+	br label %loop
+done:	%sa = call i32 @bigSign(i32* %a)
+	%nega = icmp slt i32 %sa, 0
+	%negb = icmp slt i32 %sb, 0
+	%differ = xor i1 %nega, %negb
+	%qt = call i32* @bigTrim(i32* %q)
+	%result = select i1 %remainder, i32* %r, i32* %qt
+	%negative = select i1 %remainder, i1 %nega, i1 %differ
+	br i1 %negative, label %negate, label %positive
+negate:	%nr = call i32* @bigNeg(i32* %result)
+	ret i32* %nr
+positive:	ret i32* %result
+}
+
+define i32* @bigDiv(i32* %a, i32* %b) {
+entry:	%r = call i32* @bigDivide(i32* %a, i32* %b, i1 0)
+	ret i32* %r
+}
+
+define i32* @bigRem(i32* %a, i32* %b) {
+entry:	%r = call i32* @bigDivide(i32* %a, i32* %b, i1 1)
+	ret i32* %r
+}
+
+; the magnitude is divided by 10^9 in place, the remainders are printed from the last one
+define internal void @bigPrintWith(i32* %a, i32 %end) {
+entry:	%s = call i32 @bigSign(i32* %a)
+	%neg = icmp slt i32 %s, 0
+	br i1 %neg, label %minus, label %copy
+minus:	call i32 @putchar(i32 45)
+	br label %copy
+copy:	%m = call i32* @bigAbs(i32* %a)
+	%z = call i32* @bigFromLong(i64 0)
+	%x = call i32* @bigAdd(i32* %m, i32* %z)
+	%n = load i32, i32* %x
+	%cap = mul i32 %n, 2
+	%chunks = call i32* @bigAlloc(i32 %cap)
+	br label %chunk
+chunk:	%count = phi i32 [0, %copy], [%count.next, %divided]
+	%n1 = sub i32 %n, 1
+	br label %divide
+divide:	%i = phi i32 [%n1, %chunk], [%i.next, %divide.body]
+	%rem = phi i64 [0, %chunk], [%rem.next, %divide.body]
+	%dcont = icmp sge i32 %i, 0
+	br i1 %dcont, label %divide.body, label %divided
+divide.body:	%limb = call i32 @bigLimb(i32* %x, i32 %i)
+	%limb64 = zext i32 %limb to i64
+	%rs = shl i64 %rem, 32
+	%cur = or i64 %rs, %limb64
+	%qt = udiv i64 %cur, 1000000000
+	%q = trunc i64 %qt to i32
+	call void @bigSetLimb(i32* %x, i32 %i, i32 %q)
+	%rem.next = urem i64 %cur, 1000000000
+	%i.next = sub i32 %i, 1
+	br label %divide
+divided:	%r = trunc i64 %rem to i32
+	call void @bigSetLimb(i32* %chunks, i32 %count, i32 %r)
+	%count.next = add i32 %count, 1
+	%left = call i32 @bigSign(i32* %x)
+	%more = icmp ne i32 %left, 0
+	br i1 %more, label %chunk, label %print
+print:	%last = sub i32 %count.next, 1
+	%first = call i32 @bigLimb(i32* %chunks, i32 %last)
+	%u = getelementptr [3 x i8], [3 x i8]* @bigu, i32 0, i32 0
+	call i32 (i8*, ...) @printf(i8* %u, i32 %first)
+	%u9 = getelementptr [5 x i8], [5 x i8]* @bigu9, i32 0, i32 0
+	br label %rest
+rest:	%j = phi i32 [%last, %print], [%j.next, %rest.body]
+	%j.next = sub i32 %j, 1
+	%rcont = icmp sge i32 %j.next, 0
+	br i1 %rcont, label %rest.body, label %done
+rest.body:	%digits = call i32 @bigLimb(i32* %chunks, i32 %j.next)
+	call i32 (i8*, ...) @printf(i8* %u9, i32 %digits)
+	br label %rest
+done:	call i32 @putchar(i32 %end)
+	ret void
+}
+
+define void @printBig(i32* %a) {
+entry:	call void @bigPrintWith(i32* %a, i32 10)
+	ret void
+}
+
+define void @printBigInline(i32* %a) {
+entry:	call void @bigPrintWith(i32* %a, i32 32)
+	ret void
+}
+
+; reads the integer in the same way as scanf("%d"), but without limits of its size
+define i32* @readBig() {
+entry:	br label %skip
+skip:	%c = call i32 @getchar()
+	%space = icmp eq i32 %c, 32
+	%ge9 = icmp sge i32 %c, 9
+	%le13 = icmp sle i32 %c, 13
+	%control = and i1 %ge9, %le13
+	%whitespace = or i1 %space, %control
+	br i1 %whitespace, label %skip, label %sign
+sign:	%minus = icmp eq i32 %c, 45
+	%plus = icmp eq i32 %c, 43
+	%signed = or i1 %minus, %plus
+	br i1 %signed, label %after.sign, label %start
+after.sign:	%c1 = call i32 @getchar()
+	br label %start
+start:	%first = phi i32 [%c, %sign], [%c1, %after.sign]
+	%zero = call i32* @bigFromLong(i64 0)
+	%ten = call i32* @bigFromLong(i64 10)
+	br label %digits
+digits:	%d = phi i32 [%first, %start], [%d.next, %digit]
+	%x = phi i32* [%zero, %start], [%x.next, %digit]
+	%ge0 = icmp sge i32 %d, 48
+	%le9 = icmp sle i32 %d, 57
+	%isdigit = and i1 %ge0, %le9
+	br i1 %isdigit, label %digit, label %done
+digit:	%x10 = call i32* @bigMul(i32* %x, i32* %ten)
+	%v = sub i32 %d, 48
+	%v64 = zext i32 %v to i64
+	%vb = call i32* @bigFromLong(i64 %v64)
+	%x.next = call i32* @bigAdd(i32* %x10, i32* %vb)
+	%d.next = call i32 @getchar()
+	br label %digits
+done:	%in = load i8*, i8** @stdin
+	call i32 @ungetc(i32 %d, i8* %in)
+	br i1 %minus, label %negative, label %positive
+negative:	%nx = call i32* @bigNeg(i32* %x)
+	ret i32* %nx
+positive:	ret i32* %x
+}
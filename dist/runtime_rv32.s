@@ -0,0 +1,157 @@
+# Runtime for Instant programs compiled to RV32IM, running on Linux (or qemu-riscv32 user-mode emulation).
+# Uses only raw system calls, so the programs can be linked without libc (-nostdlib).
+
+    .equ SYS_READ, 63
+    .equ SYS_WRITE, 64
+    .equ SYS_EXIT, 93
+
+    .text
+    .globl _start
+_start:
+    call main
+    li a7, SYS_EXIT
+    ecall
+
+# void printIntInline(int32_t a0): prints a0 in decimal, followed by a space
+    .globl printIntInline
+printIntInline:
+    li a1, 32
+    j printInt_end
+
+# void printInt(int32_t a0): prints a0 in decimal, followed by a newline
+    .globl printInt
+printInt:
+    li a1, 10
+# prints a0, followed by the character in a1
+printInt_end:
+    addi sp, sp, -16
+    addi t0, sp, 15
+    sb a1, 0(t0)
+    li t1, 10
+    mv t2, a0
+printInt_digit:
+    # remainder has the sign of the dividend, which handles -2147483648 without overflow
+    rem t3, t2, t1
+    bgez t3, printInt_positive
+    neg t3, t3
+printInt_positive:
+    addi t3, t3, 48
+    addi t0, t0, -1
+    sb t3, 0(t0)
+    div t2, t2, t1
+    bnez t2, printInt_digit
+    bgez a0, printInt_write
+    li t3, 45
+    addi t0, t0, -1
+    sb t3, 0(t0)
+printInt_write:
+    li a0, 1
+    mv a1, t0
+    addi a2, sp, 16
+    sub a2, a2, t0
+    li a7, SYS_WRITE
+    ecall
+    addi sp, sp, 16
+    ret
+
+# int32_t readInt(): reads the next integer of the standard input in decimal (after whitespace, with an optional sign),
+# returns 0 at the end of the input or if the next character is not a digit (which is left in the input)
+    .globl readInt
+readInt:
+    addi sp, sp, -16
+    sw ra, 12(sp)
+    sw s0, 8(sp)
+    sw s1, 4(sp)
+readInt_skip:
+    call readInt_peek
+    li t0, 32
+    beq a0, t0, readInt_skip_char
+    # '\t' to '\r' are whitespace too
+    addi t0, a0, -9
+    li t1, 5
+    bgeu t0, t1, readInt_sign
+readInt_skip_char:
+    call readInt_consume
+    j readInt_skip
+readInt_sign:
+    li s0, 0
+    li t0, 45
+    beq a0, t0, readInt_negative
+    li t0, 43
+    bne a0, t0, readInt_digits_start
+    j readInt_sign_char
+readInt_negative:
+    li s0, 1
+readInt_sign_char:
+    call readInt_consume
+readInt_digits_start:
+    li s1, 0
+readInt_digit:
+    call readInt_peek
+    addi t0, a0, -48
+    li t1, 10
+    bgeu t0, t1, readInt_done
+    mul s1, s1, t1
+    add s1, s1, t0
+    call readInt_consume
+    j readInt_digit
+readInt_done:
+    mv a0, s1
+    beqz s0, readInt_return
+    neg a0, s1
+readInt_return:
+    lw s1, 4(sp)
+    lw s0, 8(sp)
+    lw ra, 12(sp)
+    addi sp, sp, 16
+    ret
+
+# next character of the standard input (-1 at its end) in a0, it's read from the input only once, until consumed
+readInt_peek:
+    la t0, readInt_lookahead
+    lw a0, 0(t0)
+    li t1, -2
+    bne a0, t1, readInt_peek_done
+    addi sp, sp, -16
+    li a0, 0
+    mv a1, sp
+    li a2, 1
+    li a7, SYS_READ
+    ecall
+    mv t1, a0
+    lbu a0, 0(sp)
+    addi sp, sp, 16
+    bgtz t1, readInt_peek_store
+    li a0, -1
+readInt_peek_store:
+    sw a0, 0(t0)
+readInt_peek_done:
+    ret
+
+readInt_consume:
+    la t0, readInt_lookahead
+    li t1, -2
+    sw t1, 0(t0)
+    ret
+
+# void instantDivByZero(): reports division by zero and exits with code 1
+    .globl instantDivByZero
+instantDivByZero:
+    li a0, 2
+    la a1, div_by_zero_message
+    li a2, 28
+    li a7, SYS_WRITE
+    ecall
+    li a0, 1
+    li a7, SYS_EXIT
+    ecall
+
+    .data
+    .align 2
+# -2 when no character is read ahead
+readInt_lookahead:
+    .word -2
+
+    .section .rodata
+div_by_zero_message:
+    .ascii "Exception: division by zero\n"
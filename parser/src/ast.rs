@@ -1,9 +1,39 @@
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum Opcode {
     Add,
     Sub,
     Mul,
     Div,
+    Rem,
+    And,
+    Or,
+    Xor,
+    /// shifts by the number of bits given by the lowest 5 bits (6 bits for 64-bit integers) of the right operand (as in JVM),
+    /// right shift is arithmetic - unbounded integers are shifted by the lowest 32 bits of the right operand,
+    /// and a negative amount shifts in the opposite direction (as in `java.math.BigInteger`),
+    /// a shift of a nonzero unbounded integer to a result of 2^31 bits or more is a runtime error
+    Shl,
+    Shr,
+    /// comparisons result in 1 if the relation holds, and 0 otherwise
+    Eq,
+    Ne,
+    Lt,
+    Le,
+    Gt,
+    Ge,
+}
+
+impl Opcode {
+    pub fn is_comparison(&self) -> bool {
+        matches!(self, Opcode::Eq | Opcode::Ne | Opcode::Lt | Opcode::Le | Opcode::Gt | Opcode::Ge)
+    }
+}
+
+/// Numeric type of values: integers of the selected width, or 64-bit floating-point numbers (doubles)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Type {
+    Int,
+    Double,
 }
 
 #[derive(Debug)]
@@ -13,17 +43,115 @@ pub enum Expr {
         op: Opcode,
         right: Box<Expr>,
     },
-    Number { val: i32 },
+    /// `if cond then then else otherwise` - the condition holds if it is not 0, only one branch is evaluated
+    If {
+        cond: Box<Expr>,
+        then: Box<Expr>,
+        otherwise: Box<Expr>,
+    },
+    /// unary minus, negative literals are parsed as numbers
+    Neg { expr: Box<Expr> },
+    Number { val: i64 },
+    /// literal which doesn't fit in i64, as decimal digits with an optional minus sign -
+    /// it is only valid with unbounded integers
+    BigNumber { digits: String },
+    /// decimal literal with digits on both sides of the point (`2.5`, `-0.1`), the nearest double
+    Double { val: f64 },
     Variable { var: String },
+    /// `function(args)` - arguments are evaluated from left to right before the call
+    Call { function: String, args: Vec<Box<Expr>> },
+    /// `read()` - the next integer of the standard input, or 0 if there is none
+    Read,
+    /// `int(expr)` or `double(expr)` - explicit conversion, doubles are truncated towards zero
+    /// (saturating at the limits of the integer width, NaN becomes 0)
+    Convert { to: Type, expr: Box<Expr> },
+}
+
+impl Expr {
+    /// number of the literal (digits with an optional minus sign), or `BigNumber` if it doesn't fit in i64
+    pub fn literal(digits: &str) -> Box<Expr> {
+        match digits.parse() {
+            Ok(val) => Box::new(Expr::Number { val }),
+            Err(_) => Box::new(Expr::BigNumber { digits: String::from(digits) }),
+        }
+    }
 }
 
 #[derive(Debug)]
 pub enum Stmt {
+    /// evaluates the expression without printing its value - unless the program is in the explicit print mode,
+    /// the parser turns expression statements into prints of a single value
     Expr { expr: Box<Expr> },
     Decl { var: String, expr: Box<Expr> },
+    /// `{ stmts }` - variables first assigned inside the block are not visible after it
+    Block { stmts: Vec<Box<Stmt>> },
+    /// `while (cond) { body }` - the body is a block, executed as long as the condition is not 0
+    While { cond: Box<Expr>, body: Vec<Box<Stmt>> },
+    /// `return expr` - ends the call of the enclosing function, only allowed in function bodies
+    Return { expr: Box<Expr> },
+    /// `print e1, e2, ...` - all values are evaluated (from left to right) before any of them is printed,
+    /// then they are printed in a single line, separated by spaces
+    Print { exprs: Vec<Box<Expr>> },
+}
+
+/// `fn name(params) { body }` - a function sees only its parameters and variables assigned in its body,
+/// `fn name(params) = expr` is a shorthand for a body consisting of `return expr`,
+/// and a call that doesn't reach any return statement results in 0
+#[derive(Debug)]
+pub struct Function {
+    pub name: String,
+    pub params: Vec<String>,
+    pub body: Vec<Box<Stmt>>,
+}
+
+/// Top-level element of the program, only used while parsing
+#[derive(Debug)]
+pub enum Item {
+    Stmt(Box<Stmt>),
+    Function(Function),
 }
 
 #[derive(Debug)]
 pub struct Prog {
     pub stmts: Vec<Box<Stmt>>,
+    /// functions can be defined anywhere at the top level, and called from any place in the program
+    pub functions: Vec<Function>,
+}
+
+impl Prog {
+    /// splits top-level items into statements and functions, and unless the program is in the explicit print mode,
+    /// makes its expression statements print their values
+    pub fn new(items: Vec<Item>, explicit_print: bool) -> Prog {
+        let mut stmts = Vec::new();
+        let mut functions = Vec::new();
+        for item in items {
+            match item {
+                Item::Stmt(stmt) => stmts.push(stmt),
+                Item::Function(function) => functions.push(function),
+            }
+        }
+        if !explicit_print {
+            print_expression_values(&mut stmts);
+            for function in functions.iter_mut() {
+                print_expression_values(&mut function.body);
+            }
+        }
+        Prog { stmts, functions }
+    }
+}
+
+/// turns expression statements (also nested in blocks and loops) into prints of their values,
+/// which is how they are executed unless the program starts with `pragma explicit_print;`
+pub fn print_expression_values(stmts: &mut [Box<Stmt>]) {
+    for stmt in stmts.iter_mut() {
+        match stmt.as_mut() {
+            Stmt::Expr { .. } => {
+                if let Stmt::Expr { expr } = std::mem::replace(stmt.as_mut(), Stmt::Block { stmts: vec![] }) {
+                    **stmt = Stmt::Print { exprs: vec![expr] };
+                }
+            },
+            Stmt::Block { stmts } | Stmt::While { body: stmts, .. } => print_expression_values(stmts),
+            Stmt::Decl { .. } | Stmt::Return { .. } | Stmt::Print { .. } => {},
+        }
+    }
 }
@@ -1,12 +1,49 @@
 // use lalrpop_util::lalrpop_mod;  // use with new lalrpop behaviour instead of pub mod instant
 
 pub mod ast;
+#[allow(clippy::all, warnings)]
 pub mod instant;
+pub mod trivia;
+
+/// Error of parsing, with tokens converted to strings so that it doesn't borrow the source
+pub type ParseError = lalrpop_util::ParseError<usize, String, &'static str>;
+
+/// parses the program, after removing comments from it
+pub fn parse_program(source: &str) -> Result<ast::Prog, ParseError> {
+    let stripped = trivia::strip_comments(source).map_err(|_| ParseError::UnrecognizedEOF {
+        location: source.len(),
+        expected: vec![String::from("\"*/\"")],
+    })?;
+    instant::ProgParser::new()
+        .parse(&stripped.code)
+        .map_err(|error| error.map_token(|token| String::from(token.1)))
+}
 
 #[cfg(test)]
 mod tests {
+    use super::*;
+
     #[test]
     fn it_works() {
         assert_eq!(2 + 2, 4);
     }
+
+    #[test]
+    fn pragma_words_are_identifiers_after_the_beginning() {
+        let program = parse_program("pragma = 1; explicit_print = 2; fn pragma(explicit_print) = explicit_print").unwrap();
+        assert_eq!(program.stmts.len(), 2);
+        assert_eq!(program.functions[0].name, "pragma");
+        assert_eq!(program.functions[0].params, vec![String::from("explicit_print")]);
+        let program = parse_program("pragma explicit_print; explicit_print = pragma; explicit_print").unwrap();
+        assert!(matches!(*program.stmts[1], ast::Stmt::Expr { .. }));
+        assert!(parse_program("a = 1; pragma explicit_print; a").is_err());
+    }
+
+    #[test]
+    fn rejects_reserved_words_as_identifiers() {
+        for word in ["if", "then", "else", "while", "fn", "return", "print", "read", "int", "double"] {
+            assert!(parse_program(&format!("{} = 1", word)).is_err(), "{} is an identifier", word);
+            assert!(parse_program(&format!("fn f({}) = 1", word)).is_err(), "{} is an identifier", word);
+        }
+    }
 }
@@ -0,0 +1,94 @@
+//! Comments are not a part of the grammar: they are removed from the source before parsing,
+//! and kept as trivia, so that tools which need them (eg. a formatter) can put them back.
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CommentKind {
+    /// `// ...` until the end of the line
+    Line,
+    /// `/* ... */`, block comments are not nested (as in C)
+    Block,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Comment {
+    pub kind: CommentKind,
+    /// byte offsets of the comment in the source, including its delimiters
+    pub start: usize,
+    pub end: usize,
+    pub text: String,
+}
+
+#[derive(Debug)]
+pub struct StrippedSource {
+    /// source with every comment replaced by spaces (newlines are kept),
+    /// so that locations in parsing errors are the same as in the original source
+    pub code: String,
+    pub comments: Vec<Comment>,
+}
+
+/// Error of comment removal: block comment starting at the given offset is not terminated
+#[derive(Debug, PartialEq, Eq)]
+pub struct UnterminatedComment {
+    pub start: usize,
+}
+
+/// replaces every character except newlines with spaces, keeping the length in bytes
+fn blank(text: &str) -> String {
+    text.chars().map(|c| if c == '\n' { String::from("\n") } else { " ".repeat(c.len_utf8()) }).collect()
+}
+
+/// removes comments from the source, in a single pass over it
+pub fn strip_comments(source: &str) -> Result<StrippedSource, UnterminatedComment> {
+    let mut code = String::with_capacity(source.len());
+    let mut comments: Vec<Comment> = vec![];
+    let mut position = 0;
+    while position < source.len() {
+        let rest = &source[position..];
+        let (kind, length) = if rest.starts_with("//") {
+            (CommentKind::Line, rest.find('\n').unwrap_or(rest.len()))
+        } else if let Some(block) = rest.strip_prefix("/*") {
+            match block.find("*/") {
+                Some(end) => (CommentKind::Block, end + 4),
+                None => return Err(UnterminatedComment { start: position }),
+            }
+        } else {
+            let c = rest.chars().next().unwrap();
+            code.push(c);
+            position += c.len_utf8();
+            continue;
+        };
+        let text = &rest[..length];
+        code.push_str(&blank(text));
+        comments.push(Comment { kind, start: position, end: position + length, text: String::from(text) });
+        position += length;
+    }
+    Ok(StrippedSource { code, comments })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn strips_comments_keeping_locations() {
+        let source = "a = 1; // line\n/* block\n comment */ a / 2 /* ż */ / 1";
+        let stripped = strip_comments(source).unwrap();
+        assert_eq!(stripped.code, "a = 1;        \n        \n            a / 2          / 1");
+        assert_eq!(stripped.code.len(), source.len());
+        let texts: Vec<(CommentKind, &str)> = stripped.comments.iter()
+            .map(|comment| (comment.kind, &source[comment.start..comment.end]))
+            .collect();
+        assert_eq!(texts, vec![
+            (CommentKind::Line, "// line"),
+            (CommentKind::Block, "/* block\n comment */"),
+            (CommentKind::Block, "/* ż */"),
+        ]);
+    }
+
+    #[test]
+    fn rejects_unterminated_block_comments() {
+        assert_eq!(strip_comments("1; /* 2; */ 3 /* 4").unwrap_err(), UnterminatedComment { start: 14 });
+        // block comments are not nested
+        assert!(strip_comments("/* /* */ */").is_ok());
+    }
+}
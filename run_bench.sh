@@ -0,0 +1,30 @@
+#!/bin/bash
+set -euo pipefail
+IFS=$'\n\t'
+
+# Compares the interpreter and the JIT compiler (`insc run` and `insc run --jit`) on a large program,
+# generated by repeating e2e_test/test05.ins (the number of repetitions can be passed as an argument).
+# Results are saved to bench_output.txt.
+
+repetitions="${1:-100000}"
+bench_input="$(mktemp -d)/bench.ins"
+
+cargo build --release --package instant --bin insc > /dev/null 2>&1
+
+for _ in $(seq "$repetitions"); do
+  cat e2e_test/test05.ins
+  echo ";"
+  echo "a=1+1-1+1-1+1-1+1-1+1-1+1-1+1-1+1-1+1-1+1-1+1-1+1-1+1;"
+  echo "a*a-a/2*(a+a+a+a+a+a+a+a+a+a);"
+done > "$bench_input"
+echo "0" >> "$bench_input"
+
+{
+  echo "Program: $repetitions repetitions of e2e_test/test05.ins ($(wc -c < "$bench_input") bytes)"
+  echo "Interpreter:"
+  ./target/release/insc run --time "$bench_input" 2>&1 > /dev/null
+  echo "JIT:"
+  ./target/release/insc run --jit --time "$bench_input" 2>&1 > /dev/null
+} | tee bench_output.txt
+
+rm -r "$(dirname "$bench_input")"
@@ -6,12 +6,74 @@ IFS=$'\n\t'
 # uncomment the line below to use llvm tools installed via homebrew on OSX:
 #export PATH="$PATH:$(brew --prefix llvm)/bin"
 
+# RISC-V backend is tested only if the cross-compiler and user-mode emulator are available
+test_riscv=""
+if command -v riscv64-unknown-elf-gcc > /dev/null 2>&1 && command -v qemu-riscv32 > /dev/null 2>&1; then
+  test_riscv="yes"
+fi
+
+# CIL backend is tested only if the assembler and runtime are available
+test_cil=""
+if command -v ilasm > /dev/null 2>&1 && command -v mono > /dev/null 2>&1; then
+  test_cil="yes"
+fi
+
+# optimization flags passed to compilers working on the intermediate representation, eg. OPT_FLAGS=-O2
+IFS=' ' read -r -a opt_flags <<< "${OPT_FLAGS:-}"
+
 for infile in e2e_test/*.ins; do
   echo "$infile"
 
-  ./insc_jvm "$infile" > /dev/null 2>&1
-  ./insc_llvm "$infile" > /dev/null 2>&1
+  # programs read their input from the optional .input file
+  input="${infile%.ins}.input"
+  if [[ ! -f $input ]]; then
+    input=/dev/null
+  fi
+
+  # extra flags passed to all compilers are read from the optional .flags file, eg. --int-width=64
+  test_flags=()
+  if [[ -f "${infile%.ins}.flags" ]]; then
+    IFS=' ' read -r -a test_flags < "${infile%.ins}.flags"
+  fi
+  # RISC-V backend supports only 32-bit integers, and unbounded integers are supported only by JVM, LLVM
+  # and the interpreter
+  test_riscv_program="$test_riscv"
+  test_cil_program="$test_cil"
+  test_c_jit="yes"
+  for flag in "${test_flags[@]}"; do
+    if [[ $flag == "--int-width=64" ]]; then
+      test_riscv_program=""
+    elif [[ $flag == "--int-width=unbounded" ]]; then
+      test_riscv_program=""
+      test_cil_program=""
+      test_c_jit=""
+    fi
+  done
+  # doubles (decimal literals or conversions to them) are also supported only by JVM, LLVM and the interpreter
+  if grep -qE '[0-9]\.[0-9]|double\(' "$infile"; then
+    test_riscv_program=""
+    test_cil_program=""
+    test_c_jit=""
+  fi
+
+  ./insc_jvm "${opt_flags[@]}" "${test_flags[@]}" "$infile" > /dev/null 2>&1
+  ./insc_llvm "${opt_flags[@]}" "${test_flags[@]}" "$infile" > /dev/null 2>&1
+  if [[ -n $test_c_jit ]]; then
+    ./insc_c "${test_flags[@]}" "$infile" > /dev/null 2>&1
+  fi
+  if [[ -n $test_riscv_program ]]; then
+    ./insc_riscv "${opt_flags[@]}" "${test_flags[@]}" "$infile" > /dev/null 2>&1
+  fi
+  if [[ -n $test_cil_program ]]; then
+    ./insc_cil "${opt_flags[@]}" "${test_flags[@]}" "$infile" > /dev/null 2>&1
+  fi
 
+  ./insc run "${test_flags[@]}" "$infile" < "$input" > "${infile%.ins}.interpreterout" 2> /dev/null
+  if [[ -n $test_c_jit ]]; then
+    ./insc run --jit "${opt_flags[@]}" "${test_flags[@]}" "$infile" < "$input" > "${infile%.ins}.jitout" 2> /dev/null
+  fi
+
+  input="$(realpath "$input")"
   pushd e2e_test > /dev/null 2>&1
 
   expected_out="$(basename "$infile" .ins).output"
@@ -19,21 +81,68 @@ for infile in e2e_test/*.ins; do
   jvm_out="$(basename "$infile" .ins).jvmout"
   llvm_in="$(basename "$infile" .ins).bc"
   llvm_out="$(basename "$infile" .ins).llvmout"
+  c_in="$(basename "$infile" .ins).cbin"
+  c_out="$(basename "$infile" .ins).cout"
+  interpreter_out="$(basename "$infile" .ins).interpreterout"
+  jit_out="$(basename "$infile" .ins).jitout"
+  riscv_in="$(basename "$infile" .ins).rv32"
+  riscv_out="$(basename "$infile" .ins).rvout"
+  cil_in="$(basename "$infile" .ins).exe"
+  cil_out="$(basename "$infile" .ins).cilout"
 
-  java "$jvm_in" > "$jvm_out"
+  java "$jvm_in" < "$input" > "$jvm_out"
   if [[ -n $(diff "$expected_out" "$jvm_out") ]]; then
     echo "JVM invalid result:"
     echo diff "$expected_out" "$jvm_out"
     exit 1
   fi
 
-  lli "$llvm_in" > "$llvm_out"
+  lli "$llvm_in" < "$input" > "$llvm_out"
   if [[ -n $(diff "$expected_out" "$llvm_out") ]]; then
     echo "LLVM invalid result:"
     echo diff "$expected_out" "$llvm_out"
     exit 1
   fi
 
+  if [[ -n $(diff "$expected_out" "$interpreter_out") ]]; then
+    echo "Interpreter invalid result:"
+    echo diff "$expected_out" "$interpreter_out"
+    exit 1
+  fi
+
+  if [[ -n $test_c_jit ]]; then
+    if [[ -n $(diff "$expected_out" "$jit_out") ]]; then
+      echo "JIT invalid result:"
+      echo diff "$expected_out" "$jit_out"
+      exit 1
+    fi
+
+    "./$c_in" < "$input" > "$c_out"
+    if [[ -n $(diff "$expected_out" "$c_out") ]]; then
+      echo "C invalid result:"
+      echo diff "$expected_out" "$c_out"
+      exit 1
+    fi
+  fi
+
+  if [[ -n $test_riscv_program ]]; then
+    qemu-riscv32 "$riscv_in" < "$input" > "$riscv_out"
+    if [[ -n $(diff "$expected_out" "$riscv_out") ]]; then
+      echo "RISC-V invalid result:"
+      echo diff "$expected_out" "$riscv_out"
+      exit 1
+    fi
+  fi
+
+  if [[ -n $test_cil_program ]]; then
+    mono "$cil_in" < "$input" > "$cil_out"
+    if [[ -n $(diff "$expected_out" "$cil_out") ]]; then
+      echo "CIL invalid result:"
+      echo diff "$expected_out" "$cil_out"
+      exit 1
+    fi
+  fi
+
   popd > /dev/null 2>&1
 done
 
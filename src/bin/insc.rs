@@ -0,0 +1,133 @@
+use std::{env, fmt::Debug, fmt::Display, io, io::Write, process::exit, time::Instant};
+use instant_parser::ast;
+use instant_compiler::ir;
+use instant_compiler::common::{IntReader, IntWidth};
+use instant_compiler::interpreter::{interpret, Value};
+#[cfg(all(target_arch = "x86_64", unix))]
+use instant_compiler::jit::compile_jit;
+use instant_compiler::opt::OptOptions;
+use instant_utils::{
+    parse_program, lower_program, optimize_program, compile_stack_program, parse_optimization_arg,
+    parse_int_width_arg,
+};
+
+struct RunOptions {
+    input_filename: String,
+    jit: bool,
+    time: bool,
+    int_width: IntWidth,
+    /// optimizations are applied only to the program compiled by JIT
+    optimization: OptOptions,
+}
+
+fn print_usage(program_name: &str) -> ! {
+    println!("Usage: {} run [--jit] [--time] [-O0|-O1|-O2] [--int-width=32|64|unbounded] [input_filename]", program_name);
+    exit(2)
+}
+
+fn parse_run_options() -> RunOptions {
+    let args: Vec<String> = env::args().collect();
+    if args.get(1).map(String::as_str) != Some("run") {
+        print_usage(&args[0]);
+    }
+    let mut options = RunOptions {
+        input_filename: String::new(),
+        jit: false,
+        time: false,
+        int_width: IntWidth::default(),
+        optimization: OptOptions::default(),
+    };
+    for arg in args.iter().skip(2) {
+        match arg.as_str() {
+            "--jit" => options.jit = true,
+            "--time" => options.time = true,
+            _ if parse_optimization_arg(arg, &mut options.optimization) => {},
+            _ if parse_int_width_arg(arg, &mut options.int_width) => {},
+            _ if arg.starts_with("--") => print_usage(&args[0]),
+            _ => options.input_filename = arg.clone(),
+        }
+    }
+    if options.input_filename.is_empty() {
+        print_usage(&args[0]);
+    }
+    options
+}
+
+fn write_output<V: Display>(writer: &mut dyn Write, val: V, newline: bool) {
+    if let Err(e) = write!(writer, "{}{}", val, if newline { "\n" } else { " " }) {
+        eprintln!("Failed to write output: {:?}", e);
+        exit(1);
+    }
+}
+
+fn report_runtime_error<E: Debug>(writer: &mut dyn Write, error: E) -> ! {
+    // values printed before the error have to be written before exiting
+    writer.flush().unwrap_or_default();
+    eprintln!("Runtime error: {:?}", error);
+    exit(1);
+}
+
+#[cfg(all(target_arch = "x86_64", unix))]
+fn run_jit(program: &ir::Program, options: &OptOptions, reader: &mut dyn FnMut() -> i64, writer: &mut dyn Write, time: bool) {
+    let compilation_start = Instant::now();
+    let compiled_program = match compile_jit(&compile_stack_program(program, options)) {
+        Ok(compiled_program) => compiled_program,
+        Err(e) => {
+            println!("Failed to compile: {:?}", e);
+            exit(1);
+        }
+    };
+    if time {
+        eprintln!("JIT compilation time: {:?}", compilation_start.elapsed());
+    }
+    let execution_start = Instant::now();
+    let result = compiled_program.run(reader, &mut |val, newline| write_output(writer, val, newline));
+    if time {
+        eprintln!("Execution time: {:?}", execution_start.elapsed());
+    }
+    if let Err(e) = result {
+        report_runtime_error(writer, e);
+    }
+}
+
+#[cfg(not(all(target_arch = "x86_64", unix)))]
+fn run_jit(
+    _program: &ir::Program, _options: &OptOptions, _reader: &mut dyn FnMut() -> i64, _writer: &mut dyn Write, _time: bool,
+) {
+    println!("JIT compilation is only supported on x86-64 unix systems");
+    exit(1);
+}
+
+/// values are i64 wrapped to the integer width, or BigInt when integers are unbounded
+fn run_interpreter<V: Value + Display>(
+    parsed_ast: &ast::Prog, int_width: IntWidth, reader: &mut dyn FnMut() -> V, writer: &mut dyn Write, time: bool,
+) {
+    let execution_start = Instant::now();
+    let result = interpret(parsed_ast, int_width, reader, &mut |val, newline| write_output(writer, val, newline));
+    if time {
+        eprintln!("Execution time: {:?}", execution_start.elapsed());
+    }
+    if let Err(e) = result {
+        report_runtime_error(writer, e);
+    }
+}
+
+fn main() {
+    let options = parse_run_options();
+    let parsed_ast = parse_program(&options.input_filename);
+    // programs are lowered even when interpreted, to reject invalid programs before they are executed
+    let mut program = lower_program(&parsed_ast, options.int_width);
+    optimize_program(&mut program, &options.optimization);
+
+    let stdin = io::stdin();
+    let mut reader = IntReader::new(stdin.lock());
+    let stdout = io::stdout();
+    let mut writer = io::BufWriter::new(stdout.lock());
+    if options.jit {
+        run_jit(&program, &options.optimization, &mut || reader.read(), &mut writer, options.time);
+    } else if options.int_width == IntWidth::Unbounded {
+        run_interpreter(&parsed_ast, options.int_width, &mut || reader.read_big(), &mut writer, options.time);
+    } else {
+        run_interpreter(&parsed_ast, options.int_width, &mut || reader.read(), &mut writer, options.time);
+    }
+}
@@ -0,0 +1,48 @@
+use std::{fs, process::exit, path::Path, process::Command};
+use instant_parser::ast;
+use instant_compiler::common::IntWidth;
+use instant_compiler::c::{compile_c};
+use instant_utils::{parse_arg, parse_env, parse_program, write_file, check_exit_code};
+
+fn compile_c_file(parsed_ast: &ast::Prog, int_width: IntWidth, output_path: &String) {
+    let compiled_code = match compile_c(parsed_ast, int_width) {
+        Ok(code) => code,
+        Err(e) => {
+            println!("Failed to compile: {:?}", e);
+            exit(1);
+        }
+    };
+    let mut c_file = match fs::File::create(output_path) {
+        Ok(f) => f,
+        Err(e) => {
+            println!("Failed to create output file: {:?}", e);
+            exit(1);
+        }
+    };
+    write_file(&mut c_file, &compiled_code);
+}
+
+fn compile_binary_file(c_compiler: &String, c_source_path: &String, binary_output_path: &String) {
+    let compilation_status = Command::new(c_compiler)
+        .arg("-std=c99")
+        .arg("-o").arg(binary_output_path)
+        .arg(c_source_path)
+        .status();
+    check_exit_code(c_compiler, &compilation_status);
+}
+
+fn main() {
+    let (input_filename, int_width) = parse_arg();
+    let c_compiler = parse_env("C_COMPILER", "cc");
+
+    let c_output_filename = String::from(
+        Path::new(&input_filename).with_extension("c").to_str().unwrap()
+    );
+    let binary_output_filename = String::from(
+        Path::new(&input_filename).with_extension("cbin").to_str().unwrap()
+    );
+
+    let parsed_ast = parse_program(&input_filename);
+    compile_c_file(&parsed_ast, int_width, &c_output_filename);
+    compile_binary_file(&c_compiler, &c_output_filename, &binary_output_filename);
+}
@@ -0,0 +1,59 @@
+use std::{fs, process::exit, path::Path, process::Command};
+use instant_compiler::ir;
+use instant_compiler::opt::OptOptions;
+use instant_compiler::cil::translate;
+use instant_utils::{
+    parse_args, parse_env, parse_program, lower_program, optimize_program, compile_stack_program,
+    write_file, check_exit_code,
+};
+
+fn compile_cil_file(program: &ir::Program, options: &OptOptions, output_path: &String, assembly_name: &str) {
+    let compiled_program = compile_stack_program(program, options);
+    let cil_output = match translate(&compiled_program, assembly_name) {
+        Ok(cil_output) => cil_output,
+        Err(e) => {
+            println!("Failed to compile: {:?}", e);
+            exit(1);
+        }
+    };
+    let mut cil_file = match fs::File::create(output_path) {
+        Ok(f) => f,
+        Err(e) => {
+            println!("Failed to create output file: {:?}", e);
+            exit(1);
+        }
+    };
+    write_file(&mut cil_file, &cil_output);
+}
+
+fn compile_assembly_file(ilasm: &String, cil_source_path: &String, assembly_output_path: &String) {
+    let compilation_status = Command::new(ilasm)
+        .arg(format!("/output:{}", assembly_output_path))
+        .arg(cil_source_path)
+        .status();
+    check_exit_code(ilasm, &compilation_status);
+}
+
+fn main() {
+    let args = parse_args();
+    let input_filename = args.input_filename;
+    let ilasm = parse_env("ILASM", "ilasm");
+
+    let cil_output_filename = String::from(
+        Path::new(&input_filename).with_extension("il").to_str().unwrap()
+    );
+    let assembly_output_filename = String::from(
+        Path::new(&input_filename).with_extension("exe").to_str().unwrap()
+    );
+    let assembly_name = String::from(
+        Path::new(&input_filename)
+            .file_stem().unwrap()
+            .to_str().unwrap()
+    );
+
+    let parsed_ast = parse_program(&input_filename);
+    let mut program = lower_program(&parsed_ast, args.int_width);
+    optimize_program(&mut program, &args.optimization);
+    compile_cil_file(&program, &args.optimization, &cil_output_filename, &assembly_name);
+    compile_assembly_file(&ilasm, &cil_output_filename, &assembly_output_filename);
+}
@@ -1,21 +1,18 @@
 use std::{fs, process::exit, path::Path, process::Command};
-use instant_parser::ast;
-use instant_compiler::stack::{compile_stack};
+use instant_compiler::ir;
+use instant_compiler::opt::OptOptions;
 use instant_compiler::jasmin::translate;
-use instant_utils::{parse_arg, parse_env, parse_program, write_file, check_exit_code};
+use instant_utils::{
+    parse_args, parse_env, parse_program, lower_program, optimize_program, compile_stack_program,
+    write_file, check_exit_code,
+};
 
 
 fn compile_jasmin_file(
-    parsed_ast: &ast::Prog, jasmin_output_dir: &String, java_class_name: &String
+    program: &ir::Program, options: &OptOptions, jasmin_output_dir: &String, java_class_name: &String
 ) {
-    let compiled_program = match compile_stack(&parsed_ast) {
-        Ok(stack_representation) => stack_representation,
-        Err(e) => {
-            println!("Failed to compile: {:?}", e);
-            exit(1);
-        }
-    };
-    let jasmin_output = translate(&compiled_program, &java_class_name);
+    let compiled_program = compile_stack_program(program, options);
+    let jasmin_output = translate(&compiled_program, java_class_name);
     let mut jasmin_file_path = Path::new(jasmin_output_dir).to_path_buf();
     jasmin_file_path.push(
         Path::new(java_class_name).with_extension("j")
@@ -35,15 +32,16 @@ fn compile_jvm_file(
     jasmin_source_path: &String, class_output_dir: &String, jasmin_jar_path: &String
 ) {
     let compilation_status = Command::new("java")
-        .arg("-jar").arg(&jasmin_jar_path)
-        .arg("-d").arg(&class_output_dir)
-        .arg(&jasmin_source_path)
+        .arg("-jar").arg(jasmin_jar_path)
+        .arg("-d").arg(class_output_dir)
+        .arg(jasmin_source_path)
         .status();
     check_exit_code("java", &compilation_status);
 }
 
 fn main() {
-    let input_filename = parse_arg();
+    let args = parse_args();
+    let input_filename = args.input_filename;
     let jasmin_jar_path = parse_env("JASMIN_COMPILER", "jasmin.jar");
 
     let jasmin_output_filename= String::from(
@@ -61,7 +59,9 @@ fn main() {
     );
 
     let parsed_ast = parse_program(&input_filename);
-    compile_jasmin_file(&parsed_ast, &output_dir, &output_class_name);
+    let mut program = lower_program(&parsed_ast, args.int_width);
+    optimize_program(&mut program, &args.optimization);
+    compile_jasmin_file(&program, &args.optimization, &output_dir, &output_class_name);
     compile_jvm_file(
         &jasmin_output_filename,
         &output_dir,
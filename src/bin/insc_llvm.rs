@@ -1,16 +1,12 @@
 use std::{fs, env, process::exit, path::Path, process::Command};
-use instant_parser::ast;
+use instant_compiler::ir;
 use instant_compiler::llvm::{compile_llvm};
-use instant_utils::{parse_arg, parse_env, parse_program, write_file, check_exit_code};
+use instant_utils::{
+    parse_args, parse_env, parse_program, lower_program, optimize_program, write_file, check_exit_code,
+};
 
-fn compile_llvm_file(parsed_ast: &ast::Prog, output_path: &String) {
-    let compiled_code = match compile_llvm(parsed_ast) {
-        Ok(code) => code,
-        Err(e) => {
-            println!("Failed to compile: {:?}", e);
-            exit(1);
-        }
-    };
+fn compile_llvm_file(program: &ir::Program, output_path: &String) {
+    let compiled_code = compile_llvm(program);
     let mut llvm_file = match fs::File::create(output_path) {
         Ok(f) => f,
         Err(e) => {
@@ -46,7 +42,8 @@ fn compile_binary_file(
 }
 
 fn main() {
-    let input_filename = parse_arg();
+    let args = parse_args();
+    let input_filename = args.input_filename;
     let llvm_assembler = parse_env("LLVM_ASSEMBLER", "llvm-as");
     let llvm_linker = parse_env("LLVM_LINKER", "llvm-link");
     let llvm_runtime = parse_env("LLVM_RUNTIME", "runtime.bc");
@@ -59,7 +56,9 @@ fn main() {
     );
 
     let parsed_ast = parse_program(&input_filename);
-    compile_llvm_file(&parsed_ast, &llvm_output_filename);
+    let mut program = lower_program(&parsed_ast, args.int_width);
+    optimize_program(&mut program, &args.optimization);
+    compile_llvm_file(&program, &llvm_output_filename);
     compile_binary_file(
         &llvm_assembler,
         &llvm_linker,
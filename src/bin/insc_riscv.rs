@@ -0,0 +1,66 @@
+use std::{fs, process::exit, path::Path, process::Command};
+use instant_compiler::ir;
+use instant_compiler::opt::OptOptions;
+use instant_compiler::riscv::translate;
+use instant_utils::{
+    parse_args, parse_env, parse_program, lower_program, optimize_program, compile_stack_program,
+    write_file, check_exit_code,
+};
+
+fn compile_assembly_file(program: &ir::Program, options: &OptOptions, output_path: &String) {
+    let compiled_program = compile_stack_program(program, options);
+    let assembly_output = match translate(&compiled_program) {
+        Ok(assembly_output) => assembly_output,
+        Err(e) => {
+            println!("Failed to compile: {:?}", e);
+            exit(1);
+        }
+    };
+    let mut assembly_file = match fs::File::create(output_path) {
+        Ok(f) => f,
+        Err(e) => {
+            println!("Failed to create output file: {:?}", e);
+            exit(1);
+        }
+    };
+    write_file(&mut assembly_file, &assembly_output);
+}
+
+fn compile_binary_file(
+    riscv_compiler: &String, riscv_runtime: &String,
+    assembly_source_path: &String, binary_output_path: &String
+) {
+    let compilation_status = Command::new(riscv_compiler)
+        .arg("-march=rv32im").arg("-mabi=ilp32")
+        .arg("-nostdlib").arg("-static")
+        .arg("-o").arg(binary_output_path)
+        .arg(riscv_runtime)
+        .arg(assembly_source_path)
+        .status();
+    check_exit_code(riscv_compiler, &compilation_status);
+}
+
+fn main() {
+    let args = parse_args();
+    let input_filename = args.input_filename;
+    let riscv_compiler = parse_env("RISCV_COMPILER", "riscv64-unknown-elf-gcc");
+    let riscv_runtime = parse_env("RISCV_RUNTIME", "runtime_rv32.s");
+
+    let assembly_output_filename = String::from(
+        Path::new(&input_filename).with_extension("s").to_str().unwrap()
+    );
+    let binary_output_filename = String::from(
+        Path::new(&input_filename).with_extension("rv32").to_str().unwrap()
+    );
+
+    let parsed_ast = parse_program(&input_filename);
+    let mut program = lower_program(&parsed_ast, args.int_width);
+    optimize_program(&mut program, &args.optimization);
+    compile_assembly_file(&program, &args.optimization, &assembly_output_filename);
+    compile_binary_file(
+        &riscv_compiler,
+        &riscv_runtime,
+        &assembly_output_filename,
+        &binary_output_filename
+    );
+}
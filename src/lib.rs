@@ -1,15 +1,112 @@
 use std::{env, fs, io::Result, io::Write};
-use instant_parser::{ast, instant};
+use instant_parser::ast;
+use instant_compiler::common::IntWidth;
+use instant_compiler::ir;
+use instant_compiler::opt::{OptOptions, PassManager, pass_names};
+use instant_compiler::peephole;
+use instant_compiler::stack::{compile_stack, CompiledProgram};
 use std::process::{exit, ExitStatus};
 
-pub fn parse_arg() -> String {
-    let args: Vec<String> = env::args().collect();
-    match args.get(1) {
-        Some(input_filename) => {
-            String::from(input_filename)
+/// Arguments common for all compilers: `[-O0|-O1|-O2] [--enable-pass=<pass>] [--disable-pass=<pass>]
+/// [--print-after=<pass>] [--verbose] [--int-width=32|64|unbounded] input_filename`
+pub struct CompilerArgs {
+    pub input_filename: String,
+    pub optimization: OptOptions,
+    pub int_width: IntWidth,
+}
+
+fn print_usage(program_name: &str) -> ! {
+    print!("Usage: {} [-O0|-O1|-O2] [--enable-pass=<pass>] [--disable-pass=<pass>]", program_name);
+    println!(" [--print-after=<pass>] [--verbose] [--int-width=32|64|unbounded] [input_filename]");
+    println!("Available passes: {}", pass_names().join(", "));
+    exit(2)
+}
+
+/// updates optimization options if the argument is one of optimization flags, returns false otherwise
+pub fn parse_optimization_arg(arg: &str, options: &mut OptOptions) -> bool {
+    if let Some(level) = arg.strip_prefix("-O") {
+        match level.parse() {
+            Ok(level) => options.level = level,
+            Err(e) => {
+                println!("{}", e);
+                exit(2);
+            }
+        }
+    } else if let Some(name) = arg.strip_prefix("--enable-pass=") {
+        options.enabled.insert(String::from(name));
+    } else if let Some(name) = arg.strip_prefix("--disable-pass=") {
+        options.disabled.insert(String::from(name));
+    } else if let Some(name) = arg.strip_prefix("--print-after=") {
+        options.print_after.insert(String::from(name));
+    } else if arg == "--verbose" {
+        options.verbose = true;
+    } else {
+        return false;
+    }
+    true
+}
+
+/// updates the width of integers if the argument is `--int-width=<bits>` (or `--int-width=unbounded`),
+/// returns false otherwise
+pub fn parse_int_width_arg(arg: &str, int_width: &mut IntWidth) -> bool {
+    match arg.strip_prefix("--int-width=") {
+        Some(bits) => match bits.parse() {
+            Ok(bits) => {
+                *int_width = bits;
+                true
+            },
+            Err(e) => {
+                println!("{}", e);
+                exit(2);
+            }
         },
+        None => false,
+    }
+}
+
+pub fn parse_args() -> CompilerArgs {
+    let args: Vec<String> = env::args().collect();
+    let mut compiler_args = CompilerArgs {
+        input_filename: String::new(),
+        optimization: OptOptions::default(),
+        int_width: IntWidth::default(),
+    };
+    for arg in args.iter().skip(1) {
+        if parse_optimization_arg(arg, &mut compiler_args.optimization)
+            || parse_int_width_arg(arg, &mut compiler_args.int_width) {
+            continue;
+        }
+        if arg.starts_with('-') || !compiler_args.input_filename.is_empty() {
+            print_usage(&args[0]);
+        }
+        compiler_args.input_filename = arg.clone();
+    }
+    if compiler_args.input_filename.is_empty() {
+        print_usage(&args[0]);
+    }
+    compiler_args
+}
+
+/// parses the arguments of compilers which work directly on the abstract syntax tree:
+/// `[--int-width=32|64|unbounded] input_filename`
+pub fn parse_arg() -> (String, IntWidth) {
+    let args: Vec<String> = env::args().collect();
+    let mut int_width = IntWidth::default();
+    let mut input_filename = None;
+    for arg in args.iter().skip(1) {
+        if parse_int_width_arg(arg, &mut int_width) {
+            continue;
+        }
+        if arg.starts_with('-') || input_filename.is_some() {
+            input_filename = None;
+            break;
+        }
+        input_filename = Some(arg.clone());
+    }
+    match input_filename {
+        Some(input_filename) => (input_filename, int_width),
         None => {
-            println!("Usage: {} {}", &args[0], "[input_filename]");
+            println!("Usage: {} [--int-width=32|64|unbounded] [input_filename]", &args[0]);
             exit(2)
         },
     }
@@ -31,8 +128,7 @@ pub fn parse_program(input_path: &String) -> ast::Prog {
         }
     };
 
-    let parser = instant::ProgParser::new();
-    match parser.parse(&source_code) {
+    match instant_parser::parse_program(&source_code) {
         Ok(parsed_program) => {
             parsed_program
         },
@@ -43,7 +139,63 @@ pub fn parse_program(input_path: &String) -> ast::Prog {
     }
 }
 
-pub fn write_file(file: &mut fs::File, compiled_code: &Vec<String>) {
+pub fn lower_program(parsed_program: &ast::Prog, int_width: IntWidth) -> ir::Program {
+    match ir::lower(parsed_program, int_width) {
+        Ok(program) => program,
+        Err(e) => {
+            println!("Failed to compile: {:?}", e);
+            exit(1);
+        }
+    }
+}
+
+/// runs optimization passes selected in the options, dumps requested by `--print-after` are written to stderr
+pub fn optimize_program(program: &mut ir::Program, options: &OptOptions) {
+    let pass_manager = match PassManager::new(options) {
+        Ok(pass_manager) => pass_manager,
+        Err(e) => {
+            println!("Invalid optimization options: {:?}", e);
+            println!("Available passes: {}", pass_names().join(", "));
+            exit(2);
+        }
+    };
+    pass_manager.run(
+        program,
+        &mut |pass_name, program| {
+            eprintln!("; IR after {}", pass_name);
+            eprint!("{}", program);
+        },
+        &mut |pass_name, message| eprintln!("[{}] {}", pass_name, message),
+    );
+}
+
+/// compiles the program to the abstract stack code, and runs the peephole optimizer on it if it is enabled
+pub fn compile_stack_program(program: &ir::Program, options: &OptOptions) -> CompiledProgram {
+    let mut compiled_program = compile_stack(program);
+    if options.stack_pass_enabled("peephole") {
+        let statistics = peephole::optimize_program(&mut compiled_program);
+        if options.verbose {
+            for (rule_name, count) in statistics.iter().filter(|(_, count)| *count > 0) {
+                eprintln!("[peephole] {} applied {} times", rule_name, count);
+            }
+        }
+        if options.print_after.contains("peephole") {
+            for function in compiled_program.functions.iter() {
+                eprintln!("; stack code of {} after peephole", function.name);
+                for instr in function.code.instructions.iter() {
+                    eprintln!("{:?}", instr);
+                }
+            }
+            eprintln!("; stack code after peephole");
+            for instr in compiled_program.main.instructions.iter() {
+                eprintln!("{:?}", instr);
+            }
+        }
+    }
+    compiled_program
+}
+
+pub fn write_file(file: &mut fs::File, compiled_code: &[String]) {
     for line in compiled_code.iter() {
         match writeln!(file, "{}", line) {
             Ok(res) => res,